@@ -0,0 +1,167 @@
+//! Blocking [`Querier`] over [`std::io::Read`] + [`std::io::Seek`].
+//!
+//! It drives the async querier with `AllowStdIo`, so the parsing and searching are the same.
+
+use std::{
+    io::{Error as IoError, Read, Seek},
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+};
+
+use futures_executor::{block_on, block_on_stream};
+use futures_util::{future, io::AllowStdIo};
+
+use crate::{
+    content::ContentCacheConfig,
+    querier::{
+        IpLookupError, LookupError, LookupIpv6WithTranslationOutput, LookupOutput, NewError,
+        Querier as AsyncQuerier,
+    },
+    record_field::{RecordField, RecordFieldContents},
+};
+
+//
+pub struct Querier<S> {
+    pub inner: AsyncQuerier<AllowStdIo<S>>,
+}
+
+impl<S> core::fmt::Debug for Querier<S>
+where
+    AsyncQuerier<AllowStdIo<S>>: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Querier")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+//
+//
+//
+impl<S> Querier<S>
+where
+    S: Read + Seek + Send + 'static,
+{
+    pub fn new<F>(stream_repeater: F, pool_max_size: usize) -> Result<Self, NewError>
+    where
+        F: FnMut() -> Result<S, IoError>,
+    {
+        Self::new_with_content_cache_config(
+            stream_repeater,
+            pool_max_size,
+            ContentCacheConfig::default(),
+        )
+    }
+
+    /// See [`Querier::new_with_content_cache_config`](crate::querier::Querier::new_with_content_cache_config).
+    pub fn new_with_content_cache_config<F>(
+        mut stream_repeater: F,
+        pool_max_size: usize,
+        content_cache_config: ContentCacheConfig,
+    ) -> Result<Self, NewError>
+    where
+        F: FnMut() -> Result<S, IoError>,
+    {
+        let inner = block_on(AsyncQuerier::new_with_content_cache_config(
+            || Box::pin(future::ready(stream_repeater().map(AllowStdIo::new))),
+            pool_max_size,
+            content_cache_config,
+        ))?;
+
+        Ok(Self { inner })
+    }
+
+    pub fn lookup(
+        &self,
+        ip: IpAddr,
+        selected_fields: Option<&[RecordField]>,
+    ) -> Result<Option<(IpAddr, IpAddr, RecordFieldContents)>, LookupError> {
+        block_on(self.inner.lookup(ip, selected_fields))
+    }
+
+    pub fn lookup_ipv4(
+        &self,
+        ip: Ipv4Addr,
+        selected_fields: Option<&[RecordField]>,
+    ) -> Result<Option<(IpAddr, IpAddr, RecordFieldContents)>, LookupError> {
+        block_on(self.inner.lookup_ipv4(ip, selected_fields))
+    }
+
+    pub fn lookup_ipv6(
+        &self,
+        ip: Ipv6Addr,
+        selected_fields: Option<&[RecordField]>,
+    ) -> Result<Option<(IpAddr, IpAddr, RecordFieldContents)>, LookupError> {
+        block_on(self.inner.lookup_ipv6(ip, selected_fields))
+    }
+
+    pub fn lookup_ipv6_with_translation(
+        &self,
+        ip: Ipv6Addr,
+        selected_fields: Option<&[RecordField]>,
+    ) -> Result<LookupIpv6WithTranslationOutput, LookupError> {
+        block_on(self.inner.lookup_ipv6_with_translation(ip, selected_fields))
+    }
+
+    /// See [`Querier::lookup_batch`](crate::querier::Querier::lookup_batch).
+    pub fn lookup_batch(
+        &self,
+        ips: &[IpAddr],
+        selected_fields: Option<&[RecordField]>,
+    ) -> Result<Vec<LookupOutput>, IpLookupError> {
+        block_on(self.inner.lookup_batch(ips, selected_fields))
+    }
+
+    /// See [`Querier::ranges`](crate::querier::Querier::ranges).
+    pub fn ranges(
+        &self,
+        start: Option<IpAddr>,
+        end: Option<IpAddr>,
+        selected_fields: Option<Vec<RecordField>>,
+    ) -> impl Iterator<Item = Result<(IpAddr, IpAddr, RecordFieldContents), IpLookupError>> + '_
+    {
+        block_on_stream(Box::pin(self.inner.ranges(start, end, selected_fields)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::{io::Cursor, sync::Arc};
+
+    use crate::{
+        header::schema::SchemaType,
+        record_field::RecordFieldContent,
+        test_helper::{bin_bytes, MOUNTAIN_VIEW},
+    };
+
+    #[test]
+    fn test_new_and_lookup() -> Result<(), Box<dyn std::error::Error>> {
+        let bytes = bin_bytes(
+            SchemaType::IP2Location,
+            1,
+            &[("8.8.8.0", "8.8.8.255", &MOUNTAIN_VIEW)],
+        )?;
+        let bytes: Arc<[u8]> = bytes.into();
+
+        //
+        let q = Querier::new(|| Ok(Cursor::new(bytes.clone())), 2)?;
+        assert!(!q.inner.header.has_v6());
+
+        let (ip_from, _, record_field_contents) =
+            q.lookup(Ipv4Addr::new(8, 8, 8, 8).into(), None)?.unwrap();
+        assert_eq!(ip_from, Ipv4Addr::new(8, 8, 8, 0));
+        assert!(matches!(
+            &record_field_contents[0],
+            RecordFieldContent::COUNTRY(_, Some(v), _) if v.as_ref() == "US"
+        ));
+        assert!(q
+            .lookup_ipv6(Ipv4Addr::new(8, 8, 8, 8).to_ipv6_mapped(), None)?
+            .is_some());
+
+        assert_eq!(q.ranges(None, None, None).count(), 3);
+
+        Ok(())
+    }
+}
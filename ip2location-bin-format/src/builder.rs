@@ -0,0 +1,579 @@
+use core::cmp::{max, min};
+use std::{
+    io::{Error as IoError, Write},
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+};
+
+use crate::{
+    content::{builder::FillError as ContentFillError, Builder as ContentBuilder},
+    header::{
+        renderer::RenderError as HeaderRenderError, Renderer as HeaderRenderer,
+        Schema as HeaderSchema, HEADER_LEN,
+    },
+    index::{V4Builder as IndexV4Builder, V6Builder as IndexV6Builder, INDEX_LEN},
+    record_field::{RecordFieldContents, RecordFields},
+    records::{
+        builder::AppendError as RecordsAppendError, V4Builder as RecordsV4Builder,
+        V6Builder as RecordsV6Builder,
+    },
+};
+
+//
+// The records querier always reads the ip_from of the next record,
+// so at least 16 bytes must follow the last record.
+const CONTENT_MIN_LEN: usize = 16;
+
+//
+/// Writes a BIN file.
+///
+/// Records must be appended in ascending order without overlapping, the gaps are filled with
+/// empty records, and a last record is appended to the end of each ip version on finish.
+#[derive(Debug)]
+pub struct Builder {
+    header: HeaderSchema,
+    record_fields: RecordFields,
+    index_v4: IndexV4Builder,
+    index_v6: IndexV6Builder,
+    records_v4: RecordsV4Builder,
+    records_v6: RecordsV6Builder,
+    content: ContentBuilder,
+    v4_next: Option<u32>,
+    v6_next: Option<u128>,
+}
+
+impl Builder {
+    /// Only r#type, sub_type, date and license_code of header are used.
+    pub fn new(header: HeaderSchema) -> Result<Self, NewError> {
+        let record_fields = header
+            .record_fields()
+            .ok_or(NewError::RecordFieldsMissing)?;
+
+        Ok(Self {
+            header,
+            record_fields: record_fields.to_owned(),
+            index_v4: IndexV4Builder::new(),
+            index_v6: IndexV6Builder::new(),
+            records_v4: RecordsV4Builder::new(record_fields.to_owned()),
+            records_v6: RecordsV6Builder::new(record_fields),
+            content: ContentBuilder::new(),
+            v4_next: Some(0),
+            v6_next: Some(0),
+        })
+    }
+
+    pub fn record_fields(&self) -> &RecordFields {
+        &self.record_fields
+    }
+
+    /// The IPv4-mapped part of an ipv6 range is appended to the ipv4 records too, like the IPV6 BIN files,
+    /// because the querier looks up IPv4-mapped addresses in the ipv4 records.
+    pub fn append(
+        &mut self,
+        ip_from: IpAddr,
+        ip_to: IpAddr,
+        record_field_contents: RecordFieldContents,
+    ) -> Result<(), AppendError> {
+        match (ip_from, ip_to) {
+            (IpAddr::V4(ip_from), IpAddr::V4(ip_to)) => {
+                self.append_ipv4(ip_from, ip_to, record_field_contents)
+            }
+            (IpAddr::V6(ip_from), IpAddr::V6(ip_to)) => {
+                let mapped_from = Ipv4Addr::UNSPECIFIED.to_ipv6_mapped();
+                let mapped_to = Ipv4Addr::BROADCAST.to_ipv6_mapped();
+                if ip_from <= mapped_to && ip_to >= mapped_from {
+                    let to_ipv4 = |ip: Ipv6Addr| {
+                        Ipv4Addr::from((u128::from(ip) - u128::from(mapped_from)) as u32)
+                    };
+                    self.append_ipv4(
+                        to_ipv4(max(ip_from, mapped_from)),
+                        to_ipv4(min(ip_to, mapped_to)),
+                        record_field_contents.to_owned(),
+                    )?;
+                }
+
+                self.append_ipv6(ip_from, ip_to, record_field_contents)
+            }
+            _ => Err(AppendError::IpRangeInvalid),
+        }
+    }
+
+    pub fn append_ipv4(
+        &mut self,
+        ip_from: Ipv4Addr,
+        ip_to: Ipv4Addr,
+        record_field_contents: RecordFieldContents,
+    ) -> Result<(), AppendError> {
+        if ip_from > ip_to {
+            return Err(AppendError::IpRangeInvalid);
+        }
+
+        let next = self.v4_next.ok_or(AppendError::Unsorted)?;
+        if u32::from(ip_from) < next {
+            return Err(AppendError::Unsorted);
+        }
+        if u32::from(ip_from) > next {
+            self.push_ipv4(next.into(), self.record_fields.to_contents())?;
+        }
+
+        self.push_ipv4(ip_from, record_field_contents)?;
+        self.v4_next = u32::from(ip_to).checked_add(1);
+
+        Ok(())
+    }
+
+    pub fn append_ipv6(
+        &mut self,
+        ip_from: Ipv6Addr,
+        ip_to: Ipv6Addr,
+        record_field_contents: RecordFieldContents,
+    ) -> Result<(), AppendError> {
+        if ip_from > ip_to {
+            return Err(AppendError::IpRangeInvalid);
+        }
+
+        let next = self.v6_next.ok_or(AppendError::Unsorted)?;
+        if u128::from(ip_from) < next {
+            return Err(AppendError::Unsorted);
+        }
+        if u128::from(ip_from) > next {
+            self.push_ipv6(next.into(), self.record_fields.to_contents())?;
+        }
+
+        self.push_ipv6(ip_from, record_field_contents)?;
+        self.v6_next = u128::from(ip_to).checked_add(1);
+
+        Ok(())
+    }
+
+    fn push_ipv4(
+        &mut self,
+        ip_from: Ipv4Addr,
+        mut record_field_contents: RecordFieldContents,
+    ) -> Result<(), AppendError> {
+        self.content
+            .fill(&mut record_field_contents)
+            .map_err(AppendError::ContentFillFailed)?;
+        self.records_v4
+            .append(ip_from, &record_field_contents)
+            .map_err(AppendError::RecordsAppendFailed)?;
+        self.index_v4.append(ip_from);
+
+        Ok(())
+    }
+
+    fn push_ipv6(
+        &mut self,
+        ip_from: Ipv6Addr,
+        mut record_field_contents: RecordFieldContents,
+    ) -> Result<(), AppendError> {
+        self.content
+            .fill(&mut record_field_contents)
+            .map_err(AppendError::ContentFillFailed)?;
+        self.records_v6
+            .append(ip_from, &record_field_contents)
+            .map_err(AppendError::RecordsAppendFailed)?;
+        self.index_v6.append(ip_from);
+
+        Ok(())
+    }
+
+    pub fn finish<W: Write>(mut self, w: &mut W) -> Result<HeaderSchema, FinishError> {
+        //
+        if let Some(next) = self.v4_next {
+            if next < u32::MAX {
+                self.push_ipv4(next.into(), self.record_fields.to_contents())
+                    .map_err(FinishError::AppendFailed)?;
+            }
+        }
+        self.push_ipv4(Ipv4Addr::from(u32::MAX), self.record_fields.to_contents())
+            .map_err(FinishError::AppendFailed)?;
+
+        let has_v6 = self.records_v6.count() > 0;
+        if has_v6 {
+            if let Some(next) = self.v6_next {
+                if next < u128::MAX {
+                    self.push_ipv6(next.into(), self.record_fields.to_contents())
+                        .map_err(FinishError::AppendFailed)?;
+                }
+            }
+            self.push_ipv6(Ipv6Addr::from(u128::MAX), self.record_fields.to_contents())
+                .map_err(FinishError::AppendFailed)?;
+        }
+
+        //
+        let mut header = self.header;
+        header.num_record_fields = self.record_fields.len() as u8;
+        header.v4_records_count = self.records_v4.count();
+        header.v6_records_count = self.records_v6.count();
+
+        let mut cur_position: u64 = HEADER_LEN as u64 + 1;
+
+        header.v4_index_position_start = cur_position as u32;
+        cur_position += INDEX_LEN as u64;
+
+        if has_v6 {
+            header.v6_index_position_start = cur_position as u32;
+            cur_position += INDEX_LEN as u64;
+        } else {
+            header.v6_index_position_start = 1;
+        }
+
+        header.v4_records_position_start = cur_position as u32;
+        cur_position +=
+            self.record_fields.record_bytes_len_for_ipv4() as u64 * header.v4_records_count as u64;
+
+        if has_v6 {
+            header.v6_records_position_start =
+                u32::try_from(cur_position).map_err(|_| FinishError::TooLarge)?;
+            cur_position += self.record_fields.record_bytes_len_for_ipv6() as u64
+                * header.v6_records_count as u64;
+        } else {
+            header.v6_records_position_start = 1;
+        }
+
+        let content_seek_from_start =
+            u32::try_from(cur_position - 1).map_err(|_| FinishError::TooLarge)?;
+
+        let mut content = self.content.finish();
+        if content.len() < CONTENT_MIN_LEN {
+            content.resize(CONTENT_MIN_LEN, Default::default());
+        }
+
+        header.total_size = u32::try_from(content_seek_from_start as u64 + content.len() as u64)
+            .map_err(|_| FinishError::TooLarge)?;
+
+        //
+        HeaderRenderer::new()
+            .render(&header, w)
+            .map_err(FinishError::HeaderRenderFailed)?;
+
+        w.write_all(&self.index_v4.finish())
+            .map_err(FinishError::WriteFailed)?;
+        if has_v6 {
+            w.write_all(&self.index_v6.finish())
+                .map_err(FinishError::WriteFailed)?;
+        }
+
+        w.write_all(&self.records_v4.finish(content_seek_from_start))
+            .map_err(FinishError::WriteFailed)?;
+        if has_v6 {
+            w.write_all(&self.records_v6.finish(content_seek_from_start))
+                .map_err(FinishError::WriteFailed)?;
+        }
+
+        w.write_all(&content).map_err(FinishError::WriteFailed)?;
+
+        Ok(header)
+    }
+}
+
+//
+#[derive(Debug)]
+pub enum NewError {
+    RecordFieldsMissing,
+}
+
+impl core::fmt::Display for NewError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::RecordFieldsMissing => write!(f, "record fields of the header are missing"),
+        }
+    }
+}
+
+impl std::error::Error for NewError {}
+
+//
+#[derive(Debug)]
+pub enum AppendError {
+    IpRangeInvalid,
+    Unsorted,
+    ContentFillFailed(ContentFillError),
+    RecordsAppendFailed(RecordsAppendError),
+}
+
+impl core::fmt::Display for AppendError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::IpRangeInvalid => write!(f, "ip range is invalid"),
+            Self::Unsorted => write!(f, "ip ranges are not sorted"),
+            Self::ContentFillFailed(_) => write!(f, "fill content failed"),
+            Self::RecordsAppendFailed(_) => write!(f, "append record failed"),
+        }
+    }
+}
+
+impl std::error::Error for AppendError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::ContentFillFailed(err) => Some(err),
+            Self::RecordsAppendFailed(err) => Some(err),
+            Self::IpRangeInvalid | Self::Unsorted => None,
+        }
+    }
+}
+
+//
+#[derive(Debug)]
+pub enum FinishError {
+    AppendFailed(AppendError),
+    TooLarge,
+    HeaderRenderFailed(HeaderRenderError),
+    WriteFailed(IoError),
+}
+
+impl core::fmt::Display for FinishError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::AppendFailed(_) => write!(f, "append the last records failed"),
+            Self::TooLarge => write!(f, "BIN is larger than 4 GiB"),
+            Self::HeaderRenderFailed(_) => write!(f, "render header failed"),
+            Self::WriteFailed(_) => write!(f, "write BIN failed"),
+        }
+    }
+}
+
+impl std::error::Error for FinishError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::AppendFailed(err) => Some(err),
+            Self::HeaderRenderFailed(err) => Some(err),
+            Self::WriteFailed(err) => Some(err),
+            Self::TooLarge => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::{net::IpAddr, sync::Arc};
+
+    use futures_util::{future, io::Cursor, TryStreamExt as _};
+
+    use crate::{
+        header::schema::{SchemaSubType, SchemaType},
+        querier::Querier,
+        record_field::{RecordField, RecordFieldContent},
+    };
+
+    fn db5_contents(
+        record_fields: &RecordFields,
+        country: (&str, &str),
+        region: &str,
+        city: &str,
+        latitude: f32,
+        longitude: f32,
+    ) -> RecordFieldContents {
+        let mut record_field_contents = record_fields.to_contents();
+        for record_field_content in record_field_contents.iter_mut() {
+            match record_field_content {
+                RecordFieldContent::COUNTRY(_, v, v_name) => {
+                    *v = Some(country.0.into());
+                    *v_name = Some(country.1.into());
+                }
+                RecordFieldContent::REGION(_, v) => *v = Some(region.into()),
+                RecordFieldContent::CITY(_, v) => *v = Some(city.into()),
+                RecordFieldContent::LATITUDE(v) => *v = latitude,
+                RecordFieldContent::LONGITUDE(v) => *v = longitude,
+                _ => unreachable!(),
+            }
+        }
+        record_field_contents
+    }
+
+    #[tokio::test]
+    async fn test_finish_and_lookup() -> Result<(), Box<dyn std::error::Error>> {
+        let header = HeaderSchema {
+            r#type: SchemaType::IP2Location,
+            sub_type: SchemaSubType(5),
+            date: (22, 11, 1),
+            ..Default::default()
+        };
+
+        let mut builder = Builder::new(header)?;
+        let record_fields = builder.record_fields().to_owned();
+
+        builder.append_ipv4(
+            Ipv4Addr::new(1, 0, 0, 0),
+            Ipv4Addr::new(1, 0, 0, 255),
+            db5_contents(
+                &record_fields,
+                ("US", "United States of America"),
+                "California",
+                "Los Angeles",
+                34.052_86,
+                -118.243_7,
+            ),
+        )?;
+        builder.append_ipv4(
+            Ipv4Addr::new(1, 0, 1, 0),
+            Ipv4Addr::new(1, 0, 3, 255),
+            db5_contents(
+                &record_fields,
+                ("CN", "China"),
+                "Fujian",
+                "Fuzhou",
+                26.061_39,
+                119.306_11,
+            ),
+        )?;
+        builder.append_ipv4(
+            Ipv4Addr::new(8, 8, 8, 0),
+            Ipv4Addr::new(8, 8, 8, 255),
+            db5_contents(
+                &record_fields,
+                ("US", "United States of America"),
+                "California",
+                "Mountain View",
+                37.405_99,
+                -122.078_51,
+            ),
+        )?;
+        assert!(matches!(
+            builder.append_ipv4(
+                Ipv4Addr::new(8, 8, 8, 8),
+                Ipv4Addr::new(8, 8, 8, 8),
+                record_fields.to_contents(),
+            ),
+            Err(AppendError::Unsorted)
+        ));
+        builder.append_ipv6(
+            "2001:4860::".parse()?,
+            "2001:4860:ffff:ffff:ffff:ffff:ffff:ffff".parse()?,
+            db5_contents(
+                &record_fields,
+                ("US", "United States of America"),
+                "California",
+                "Mountain View",
+                37.405_99,
+                -122.078_51,
+            ),
+        )?;
+
+        let mut bytes = vec![];
+        let header = builder.finish(&mut bytes)?;
+        assert_eq!(header.total_size as usize, bytes.len());
+        assert!(header.has_v6());
+
+        //
+        let bytes: Arc<[u8]> = bytes.into();
+        let q = Querier::new(|| Box::pin(future::ok(Cursor::new(bytes.clone()))), 1).await?;
+
+        let (ip_from, ip_to, record_field_contents) = q
+            .lookup(Ipv4Addr::new(1, 0, 2, 3).into(), None)
+            .await?
+            .unwrap();
+        assert_eq!(ip_from, IpAddr::from(Ipv4Addr::new(1, 0, 1, 0)));
+        assert_eq!(ip_to, IpAddr::from(Ipv4Addr::new(1, 0, 4, 0)));
+        match &record_field_contents[..] {
+            [RecordFieldContent::COUNTRY(_, Some(v), Some(v_name)), RecordFieldContent::REGION(_, Some(region)), RecordFieldContent::CITY(_, Some(city)), RecordFieldContent::LATITUDE(latitude), RecordFieldContent::LONGITUDE(longitude)] =>
+            {
+                assert_eq!(v.as_ref(), "CN");
+                assert_eq!(v_name.as_ref(), "China");
+                assert_eq!(region.as_ref(), "Fujian");
+                assert_eq!(city.as_ref(), "Fuzhou");
+                assert_eq!(*latitude, 26.061_39);
+                assert_eq!(*longitude, 119.306_11);
+            }
+            x => panic!("{x:?}"),
+        }
+
+        let (_, _, record_field_contents) = q
+            .lookup(Ipv4Addr::new(8, 8, 8, 8).into(), None)
+            .await?
+            .unwrap();
+        assert!(
+            matches!(&record_field_contents[2], RecordFieldContent::CITY(_, Some(city)) if city.as_ref() == "Mountain View")
+        );
+
+        let (_, _, record_field_contents) = q
+            .lookup("2001:4860:4860::8888".parse::<IpAddr>()?, None)
+            .await?
+            .unwrap();
+        assert!(
+            matches!(&record_field_contents[0], RecordFieldContent::COUNTRY(_, Some(v), _) if v.as_ref() == "US")
+        );
+
+        //
+        let (_, _, record_field_contents) = q
+            .lookup(Ipv4Addr::new(9, 9, 9, 9).into(), None)
+            .await?
+            .unwrap();
+        assert!(matches!(
+            &record_field_contents[0],
+            RecordFieldContent::COUNTRY(_, None, None)
+        ));
+
+        for ip in [
+            IpAddr::from(Ipv4Addr::new(0, 0, 0, 0)),
+            IpAddr::from(Ipv4Addr::new(255, 255, 255, 255)),
+            IpAddr::from(Ipv6Addr::from(u128::MAX)),
+        ] {
+            let ret = q.lookup(ip, None).await?;
+            assert!(ret.is_none());
+        }
+
+        //
+        let (ip_from, ip_to, record_field_contents) = q.read_ipv4(2, None).await?.unwrap();
+        assert_eq!(ip_from, IpAddr::from(Ipv4Addr::new(1, 0, 1, 0)));
+        assert_eq!(ip_to, IpAddr::from(Ipv4Addr::new(1, 0, 4, 0)));
+        assert!(
+            matches!(&record_field_contents[2], RecordFieldContent::CITY(_, Some(city)) if city.as_ref() == "Fuzhou")
+        );
+
+        let (ip_from, ip_to, _) = q
+            .read_ipv4(header.v4_records_count - 2, None)
+            .await?
+            .unwrap();
+        assert_eq!(ip_from, IpAddr::from(Ipv4Addr::new(8, 8, 9, 0)));
+        assert_eq!(ip_to, IpAddr::from(Ipv4Addr::BROADCAST));
+        assert!(q
+            .read_ipv4(header.v4_records_count - 1, None)
+            .await?
+            .is_none());
+
+        let (ip_from, _, _) = q.read_ipv6(1, None).await?.unwrap();
+        assert_eq!(ip_from, "2001:4860::".parse::<IpAddr>()?);
+
+        //
+        let ranges = q.ranges(None, None, None).try_collect::<Vec<_>>().await?;
+        assert_eq!(
+            ranges.len() as u32,
+            header.v4_records_count - 1 + header.v6_records_count - 1
+        );
+
+        let ranges = q
+            .ranges(
+                Some(Ipv4Addr::new(1, 0, 0, 1).into()),
+                Some(Ipv4Addr::new(8, 8, 8, 8).into()),
+                Some(vec![RecordField::COUNTRY]),
+            )
+            .try_collect::<Vec<_>>()
+            .await?;
+        assert_eq!(
+            ranges
+                .iter()
+                .map(|(ip_from, _, _)| *ip_from)
+                .collect::<Vec<_>>(),
+            [
+                Ipv4Addr::new(1, 0, 0, 0),
+                Ipv4Addr::new(1, 0, 1, 0),
+                Ipv4Addr::new(1, 0, 4, 0),
+                Ipv4Addr::new(8, 8, 8, 0),
+            ]
+            .map(IpAddr::from)
+        );
+        assert_eq!(ranges[0].2.len(), 1);
+
+        let ranges = q
+            .ranges(Some("2001:4860::1".parse()?), None, None)
+            .try_collect::<Vec<_>>()
+            .await?;
+        assert_eq!(ranges.len(), 2);
+        assert_eq!(ranges[0].0, "2001:4860::".parse::<IpAddr>()?);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,611 @@
+use std::{
+    io::{Error as IoError, ErrorKind as IoErrorKind},
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+    sync::Arc,
+};
+
+use crate::{
+    content::{
+        querier::{
+            country_name_offset, filter_str, FillError as ContentFillError,
+            COUNTRY_NAME_INDEX_OFFSET,
+        },
+        UNKNOWN_STR,
+    },
+    header::Schema as HeaderSchema,
+    record_field::{RecordField, RecordFieldContent, RecordFieldContents, RecordFields},
+    records::{
+        querier::{record_field_contents_from_bytes, search, Error as RecordsQueryError},
+        PositionRange,
+    },
+};
+
+//
+/// The whole BIN, e.g. `Vec<u8>`, `Arc<[u8]>` or a memory map.
+#[derive(Clone)]
+pub struct Bytes(Arc<dyn AsRef<[u8]> + Send + Sync>);
+
+impl Bytes {
+    pub fn new(bytes: impl AsRef<[u8]> + Send + Sync + 'static) -> Self {
+        Self(Arc::new(bytes))
+    }
+
+    /// Maps the BIN into memory.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified or truncated while the `Bytes` or any of its clones is alive,
+    /// e.g. by `cp new.BIN db.BIN`, otherwise the lookups read changed memory or the process gets `SIGBUS`.
+    /// Replace the file by rename, the mapping keeps the old file.
+    #[cfg(feature = "mmap")]
+    pub unsafe fn open_mmap(path: impl AsRef<std::path::Path>) -> Result<Self, IoError> {
+        let file = std::fs::File::open(path)?;
+        // SAFETY: the caller guarantees that the file is not modified while it is mapped.
+        let mmap = unsafe { memmap2::Mmap::map(&file)? };
+
+        Ok(Self::new(mmap))
+    }
+}
+
+impl AsRef<[u8]> for Bytes {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref().as_ref()
+    }
+}
+
+impl core::fmt::Debug for Bytes {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Bytes").field(&self.as_ref().len()).finish()
+    }
+}
+
+impl From<Vec<u8>> for Bytes {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self::new(bytes)
+    }
+}
+
+impl From<Arc<[u8]>> for Bytes {
+    fn from(bytes: Arc<[u8]>) -> Self {
+        Self::new(bytes)
+    }
+}
+
+//
+/// Queries the records and the content in [`Bytes`] directly, without seek and read.
+#[derive(Debug, Clone)]
+pub struct BytesQuerier {
+    bytes: Bytes,
+    header: HeaderSchema,
+    record_fields: RecordFields,
+    record_field_contents: RecordFieldContents,
+}
+
+impl BytesQuerier {
+    pub fn new(bytes: Bytes, header: HeaderSchema) -> Result<Self, NewError> {
+        let record_fields = header
+            .record_fields()
+            .ok_or(NewError::RecordFieldsMissing)?;
+
+        if (bytes.as_ref().len() as u64) < header.total_size as u64 {
+            return Err(NewError::TotalSizeMissing);
+        }
+
+        let record_field_contents = record_fields.to_contents();
+
+        Ok(Self {
+            bytes,
+            header,
+            record_fields,
+            record_field_contents,
+        })
+    }
+
+    pub fn bytes(&self) -> &[u8] {
+        self.bytes.as_ref()
+    }
+}
+
+//
+#[derive(Debug)]
+pub enum NewError {
+    RecordFieldsMissing,
+    TotalSizeMissing,
+}
+
+impl core::fmt::Display for NewError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::RecordFieldsMissing => write!(f, "record fields of the header are missing"),
+            Self::TotalSizeMissing => {
+                write!(f, "the BIN is shorter than the total size of the header")
+            }
+        }
+    }
+}
+
+impl std::error::Error for NewError {}
+
+//
+//
+//
+impl BytesQuerier {
+    pub fn query_ipv4(
+        &self,
+        ip: Ipv4Addr,
+        position_range: PositionRange,
+    ) -> Result<Option<(IpAddr, IpAddr, RecordFieldContents)>, RecordsQueryError> {
+        Ok(self
+            .query_ipv4_ref(ip, position_range)?
+            .map(|(ip_from, ip_to, x)| (ip_from, ip_to, x.to_contents())))
+    }
+
+    pub fn query_ipv6(
+        &self,
+        ip: Ipv6Addr,
+        position_range: PositionRange,
+    ) -> Result<Option<(IpAddr, IpAddr, RecordFieldContents)>, RecordsQueryError> {
+        Ok(self
+            .query_ipv6_ref(ip, position_range)?
+            .map(|(ip_from, ip_to, x)| (ip_from, ip_to, x.to_contents())))
+    }
+
+    /// Like [`query_ipv4`](Self::query_ipv4), but the contents are borrowed, see [`RecordFieldContentsRef`].
+    pub fn query_ipv4_ref(
+        &self,
+        ip: Ipv4Addr,
+        position_range: PositionRange,
+    ) -> Result<Option<(IpAddr, IpAddr, RecordFieldContentsRef<'_>)>, RecordsQueryError> {
+        self.query(
+            ip.into(),
+            position_range,
+            self.header.v4_records_count,
+            self.header.v4_records_seek_from_start(),
+        )
+    }
+
+    /// Like [`query_ipv6`](Self::query_ipv6), but the contents are borrowed, see [`RecordFieldContentsRef`].
+    pub fn query_ipv6_ref(
+        &self,
+        ip: Ipv6Addr,
+        position_range: PositionRange,
+    ) -> Result<Option<(IpAddr, IpAddr, RecordFieldContentsRef<'_>)>, RecordsQueryError> {
+        debug_assert!(ip.to_ipv4().is_none());
+
+        match self.header.v6_records_seek_from_start() {
+            Some(seek_from_start_base) => self.query(
+                ip.into(),
+                position_range,
+                self.header.v6_records_count,
+                seek_from_start_base,
+            ),
+            None => Ok(None),
+        }
+    }
+
+    fn query(
+        &self,
+        ip: IpAddr,
+        position_range: PositionRange,
+        count: u32,
+        seek_from_start_base: u64,
+    ) -> Result<Option<(IpAddr, IpAddr, RecordFieldContentsRef<'_>)>, RecordsQueryError> {
+        let bytes = self.bytes();
+
+        Ok(search(
+            ip,
+            position_range,
+            count,
+            seek_from_start_base,
+            &self.record_fields,
+            |seek_from_start, len| {
+                bytes
+                    .get(seek_from_start as usize..seek_from_start as usize + len)
+                    .ok_or_else(|| IoError::from(IoErrorKind::UnexpectedEof))
+            },
+        )?
+        .map(|(ip_from, ip_to, record)| {
+            (
+                ip_from,
+                ip_to,
+                RecordFieldContentsRef {
+                    querier: self,
+                    record,
+                    is_ipv6: ip.is_ipv6(),
+                },
+            )
+        }))
+    }
+
+    pub fn fill(
+        &self,
+        record_field_contents: &mut RecordFieldContents,
+    ) -> Result<(), ContentFillError> {
+        for record_field_content in record_field_contents.iter_mut() {
+            match record_field_content {
+                RecordFieldContent::COUNTRY(i, v, v_name) => {
+                    // https://github.com/ip2location/ip2proxy-rust/blob/5bdd3ef61c2e243c1b61eda1475ca23eab2b7240/src/db.rs#L252
+                    let i_name = country_name_offset(*i)?;
+                    *v = filter_str(self.str_at(*i)?);
+                    *v_name = filter_str(self.str_at(i_name)?);
+                }
+                RecordFieldContent::REGION(i, v) => *v = filter_str(self.str_at(*i)?),
+                RecordFieldContent::CITY(i, v) => *v = filter_str(self.str_at(*i)?),
+                RecordFieldContent::ISP(i, v) => *v = filter_str(self.str_at(*i)?),
+                RecordFieldContent::DOMAIN(i, v) => *v = filter_str(self.str_at(*i)?),
+                //
+                RecordFieldContent::LATITUDE(_) => {}
+                RecordFieldContent::LONGITUDE(_) => {}
+                RecordFieldContent::ZIPCODE(i, v) => *v = filter_str(self.str_at(*i)?),
+                RecordFieldContent::TIMEZONE(i, v) => *v = filter_str(self.str_at(*i)?),
+                RecordFieldContent::NETSPEED(i, v) => *v = filter_str(self.str_at(*i)?),
+                RecordFieldContent::IDDCODE(i, v) => *v = filter_str(self.str_at(*i)?),
+                RecordFieldContent::AREACODE(i, v) => *v = filter_str(self.str_at(*i)?),
+                RecordFieldContent::WEATHERSTATIONCODE(i, v) => *v = filter_str(self.str_at(*i)?),
+                RecordFieldContent::WEATHERSTATIONNAME(i, v) => *v = filter_str(self.str_at(*i)?),
+                RecordFieldContent::MCC(i, v) => *v = filter_str(self.str_at(*i)?),
+                RecordFieldContent::MNC(i, v) => *v = filter_str(self.str_at(*i)?),
+                RecordFieldContent::MOBILEBRAND(i, v) => *v = filter_str(self.str_at(*i)?),
+                RecordFieldContent::ELEVATION(i, v) => *v = filter_str(self.str_at(*i)?),
+                RecordFieldContent::ADDRESSTYPE(i, v) => *v = filter_str(self.str_at(*i)?),
+                RecordFieldContent::CATEGORY(i, v) => *v = filter_str(self.str_at(*i)?),
+                RecordFieldContent::DISTRICT(i, v) => *v = filter_str(self.str_at(*i)?),
+                //
+                RecordFieldContent::PROXYTYPE(i, v) => *v = filter_str(self.str_at(*i)?),
+                RecordFieldContent::USAGETYPE(i, v) => *v = filter_str(self.str_at(*i)?),
+                RecordFieldContent::ASN(i, v) => *v = filter_str(self.str_at(*i)?),
+                RecordFieldContent::AS(i, v) => *v = filter_str(self.str_at(*i)?),
+                RecordFieldContent::LASTSEEN(i, v) => *v = filter_str(self.str_at(*i)?),
+                RecordFieldContent::THREAT(i, v) => *v = filter_str(self.str_at(*i)?),
+                RecordFieldContent::RESIDENTIAL(i, v) => *v = filter_str(self.str_at(*i)?),
+                RecordFieldContent::PROVIDER(i, v) => *v = filter_str(self.str_at(*i)?),
+                RecordFieldContent::FRAUDSCORE(i, v) => *v = filter_str(self.str_at(*i)?),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The str in content, a u8 len and then the bytes.
+    pub fn str_at(&self, seek_from_start: u32) -> Result<&str, ContentFillError> {
+        let bytes = self.bytes();
+        let seek_from_start = seek_from_start as usize;
+
+        let offset = seek_from_start as u64;
+        let len = *bytes.get(seek_from_start).ok_or_else(|| {
+            ContentFillError::ReadFailed(offset, IoError::from(IoErrorKind::UnexpectedEof))
+        })? as usize;
+        let s = bytes
+            .get(seek_from_start + 1..seek_from_start + 1 + len)
+            .ok_or(ContentFillError::ReadIncomplete(offset))?;
+
+        core::str::from_utf8(s).map_err(|err| ContentFillError::ToUtf8Failed(offset, err))
+    }
+}
+
+//
+/// A [`RecordFieldContent`] with the strs borrowed from the [`Bytes`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RecordFieldContentRef<'a> {
+    // Common
+    COUNTRY(Option<&'a str>, Option<&'a str>),
+    REGION(Option<&'a str>),
+    CITY(Option<&'a str>),
+    ISP(Option<&'a str>),
+    DOMAIN(Option<&'a str>),
+    // IP2Location
+    LATITUDE(f32),
+    LONGITUDE(f32),
+    ZIPCODE(Option<&'a str>),
+    TIMEZONE(Option<&'a str>),
+    NETSPEED(Option<&'a str>),
+    IDDCODE(Option<&'a str>),
+    AREACODE(Option<&'a str>),
+    WEATHERSTATIONCODE(Option<&'a str>),
+    WEATHERSTATIONNAME(Option<&'a str>),
+    MCC(Option<&'a str>),
+    MNC(Option<&'a str>),
+    MOBILEBRAND(Option<&'a str>),
+    ELEVATION(Option<&'a str>),
+    ADDRESSTYPE(Option<&'a str>),
+    CATEGORY(Option<&'a str>),
+    DISTRICT(Option<&'a str>),
+    // IP2Proxy
+    PROXYTYPE(Option<&'a str>),
+    USAGETYPE(Option<&'a str>),
+    ASN(Option<&'a str>),
+    AS(Option<&'a str>),
+    LASTSEEN(Option<&'a str>),
+    THREAT(Option<&'a str>),
+    RESIDENTIAL(Option<&'a str>),
+    PROVIDER(Option<&'a str>),
+    FRAUDSCORE(Option<&'a str>),
+}
+
+impl RecordFieldContentRef<'_> {
+    pub fn record_field(&self) -> RecordField {
+        match self {
+            Self::COUNTRY(_, _) => RecordField::COUNTRY,
+            Self::REGION(_) => RecordField::REGION,
+            Self::CITY(_) => RecordField::CITY,
+            Self::ISP(_) => RecordField::ISP,
+            Self::DOMAIN(_) => RecordField::DOMAIN,
+            //
+            Self::LATITUDE(_) => RecordField::LATITUDE,
+            Self::LONGITUDE(_) => RecordField::LONGITUDE,
+            Self::ZIPCODE(_) => RecordField::ZIPCODE,
+            Self::TIMEZONE(_) => RecordField::TIMEZONE,
+            Self::NETSPEED(_) => RecordField::NETSPEED,
+            Self::IDDCODE(_) => RecordField::IDDCODE,
+            Self::AREACODE(_) => RecordField::AREACODE,
+            Self::WEATHERSTATIONCODE(_) => RecordField::WEATHERSTATIONCODE,
+            Self::WEATHERSTATIONNAME(_) => RecordField::WEATHERSTATIONNAME,
+            Self::MCC(_) => RecordField::MCC,
+            Self::MNC(_) => RecordField::MNC,
+            Self::MOBILEBRAND(_) => RecordField::MOBILEBRAND,
+            Self::ELEVATION(_) => RecordField::ELEVATION,
+            Self::ADDRESSTYPE(_) => RecordField::ADDRESSTYPE,
+            Self::CATEGORY(_) => RecordField::CATEGORY,
+            Self::DISTRICT(_) => RecordField::DISTRICT,
+            //
+            Self::PROXYTYPE(_) => RecordField::PROXYTYPE,
+            Self::USAGETYPE(_) => RecordField::USAGETYPE,
+            Self::ASN(_) => RecordField::ASN,
+            Self::AS(_) => RecordField::AS,
+            Self::LASTSEEN(_) => RecordField::LASTSEEN,
+            Self::THREAT(_) => RecordField::THREAT,
+            Self::RESIDENTIAL(_) => RecordField::RESIDENTIAL,
+            Self::PROVIDER(_) => RecordField::PROVIDER,
+            Self::FRAUDSCORE(_) => RecordField::FRAUDSCORE,
+        }
+    }
+}
+
+//
+/// A record in the [`Bytes`], the contents are read when iterating, without allocation.
+#[derive(Clone, Copy)]
+pub struct RecordFieldContentsRef<'a> {
+    querier: &'a BytesQuerier,
+    record: &'a [u8],
+    is_ipv6: bool,
+}
+
+impl core::fmt::Debug for RecordFieldContentsRef<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a> RecordFieldContentsRef<'a> {
+    pub fn iter(
+        &self,
+    ) -> impl Iterator<Item = Result<RecordFieldContentRef<'a>, ContentFillError>> + 'a {
+        let this = *self;
+        this.querier
+            .record_field_contents
+            .iter()
+            .enumerate()
+            .map(move |(n, record_field_content)| this.content(n, record_field_content))
+    }
+
+    /// The owned [`RecordFieldContents`], with content offsets and without strs, like the pool path before the fill.
+    pub fn to_contents(&self) -> RecordFieldContents {
+        record_field_contents_from_bytes(
+            &self.querier.record_field_contents,
+            self.record,
+            self.is_ipv6,
+        )
+    }
+
+    fn content(
+        &self,
+        n: usize,
+        record_field_content: &RecordFieldContent,
+    ) -> Result<RecordFieldContentRef<'a>, ContentFillError> {
+        let index = if self.is_ipv6 { 16 + n * 4 } else { 4 + n * 4 };
+        let bytes: [u8; 4] = self.record[index..index + 4].try_into().unwrap();
+
+        let str_at = |offset: u32| -> Result<Option<&'a str>, ContentFillError> {
+            let i = u32::from_ne_bytes(bytes);
+            let i = i
+                .checked_add(offset)
+                .ok_or(ContentFillError::OffsetOverflow(i as u64))?;
+            let s = self.querier.str_at(i)?;
+            Ok(if s == UNKNOWN_STR { None } else { Some(s) })
+        };
+
+        Ok(match record_field_content {
+            RecordFieldContent::COUNTRY(_, _, _) => {
+                // The name first, its offset of a corrupted BIN may overflow.
+                let name = str_at(COUNTRY_NAME_INDEX_OFFSET as u32)?;
+                RecordFieldContentRef::COUNTRY(str_at(0)?, name)
+            }
+            RecordFieldContent::REGION(_, _) => RecordFieldContentRef::REGION(str_at(0)?),
+            RecordFieldContent::CITY(_, _) => RecordFieldContentRef::CITY(str_at(0)?),
+            RecordFieldContent::ISP(_, _) => RecordFieldContentRef::ISP(str_at(0)?),
+            RecordFieldContent::DOMAIN(_, _) => RecordFieldContentRef::DOMAIN(str_at(0)?),
+            //
+            RecordFieldContent::LATITUDE(_) => {
+                RecordFieldContentRef::LATITUDE(f32::from_ne_bytes(bytes))
+            }
+            RecordFieldContent::LONGITUDE(_) => {
+                RecordFieldContentRef::LONGITUDE(f32::from_ne_bytes(bytes))
+            }
+            RecordFieldContent::ZIPCODE(_, _) => RecordFieldContentRef::ZIPCODE(str_at(0)?),
+            RecordFieldContent::TIMEZONE(_, _) => RecordFieldContentRef::TIMEZONE(str_at(0)?),
+            RecordFieldContent::NETSPEED(_, _) => RecordFieldContentRef::NETSPEED(str_at(0)?),
+            RecordFieldContent::IDDCODE(_, _) => RecordFieldContentRef::IDDCODE(str_at(0)?),
+            RecordFieldContent::AREACODE(_, _) => RecordFieldContentRef::AREACODE(str_at(0)?),
+            RecordFieldContent::WEATHERSTATIONCODE(_, _) => {
+                RecordFieldContentRef::WEATHERSTATIONCODE(str_at(0)?)
+            }
+            RecordFieldContent::WEATHERSTATIONNAME(_, _) => {
+                RecordFieldContentRef::WEATHERSTATIONNAME(str_at(0)?)
+            }
+            RecordFieldContent::MCC(_, _) => RecordFieldContentRef::MCC(str_at(0)?),
+            RecordFieldContent::MNC(_, _) => RecordFieldContentRef::MNC(str_at(0)?),
+            RecordFieldContent::MOBILEBRAND(_, _) => RecordFieldContentRef::MOBILEBRAND(str_at(0)?),
+            RecordFieldContent::ELEVATION(_, _) => RecordFieldContentRef::ELEVATION(str_at(0)?),
+            RecordFieldContent::ADDRESSTYPE(_, _) => RecordFieldContentRef::ADDRESSTYPE(str_at(0)?),
+            RecordFieldContent::CATEGORY(_, _) => RecordFieldContentRef::CATEGORY(str_at(0)?),
+            RecordFieldContent::DISTRICT(_, _) => RecordFieldContentRef::DISTRICT(str_at(0)?),
+            //
+            RecordFieldContent::PROXYTYPE(_, _) => RecordFieldContentRef::PROXYTYPE(str_at(0)?),
+            RecordFieldContent::USAGETYPE(_, _) => RecordFieldContentRef::USAGETYPE(str_at(0)?),
+            RecordFieldContent::ASN(_, _) => RecordFieldContentRef::ASN(str_at(0)?),
+            RecordFieldContent::AS(_, _) => RecordFieldContentRef::AS(str_at(0)?),
+            RecordFieldContent::LASTSEEN(_, _) => RecordFieldContentRef::LASTSEEN(str_at(0)?),
+            RecordFieldContent::THREAT(_, _) => RecordFieldContentRef::THREAT(str_at(0)?),
+            RecordFieldContent::RESIDENTIAL(_, _) => RecordFieldContentRef::RESIDENTIAL(str_at(0)?),
+            RecordFieldContent::PROVIDER(_, _) => RecordFieldContentRef::PROVIDER(str_at(0)?),
+            RecordFieldContent::FRAUDSCORE(_, _) => RecordFieldContentRef::FRAUDSCORE(str_at(0)?),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use futures_util::{future, io::Cursor};
+
+    use crate::{
+        header::schema::SchemaType,
+        querier::{LookupError, Querier},
+        test_helper::{bin_bytes, BRISBANE, MOUNTAIN_VIEW},
+    };
+
+    #[tokio::test]
+    async fn test_lookup() -> Result<(), Box<dyn std::error::Error>> {
+        let bytes = bin_bytes(
+            SchemaType::IP2Proxy,
+            4,
+            &[
+                ("1.0.0.0", "1.0.0.255", &BRISBANE),
+                ("8.8.8.0", "8.8.8.255", &MOUNTAIN_VIEW),
+                ("2001:4860::", "2001:4860::ffff", &MOUNTAIN_VIEW),
+            ],
+        )?;
+        let bytes: Arc<[u8]> = bytes.into();
+
+        //
+        let q_bytes = Querier::from_bytes(bytes.clone()).await?;
+        assert!(q_bytes.bytes_querier.is_some());
+        let q = Querier::new(|| Box::pin(future::ok(Cursor::new(bytes.clone()))), 1).await?;
+
+        for ip in [
+            "0.0.0.0",
+            "1.0.0.1",
+            "1.0.1.1",
+            "8.8.8.8",
+            "255.255.255.255",
+            "::ffff:8.8.8.8",
+            "2001:4860::8888",
+            "2001:4860::1:0",
+            "ffff::",
+        ] {
+            let ip: IpAddr = ip.parse()?;
+            assert_eq!(
+                format!("{:?}", q_bytes.lookup(ip, None).await?),
+                format!("{:?}", q.lookup(ip, None).await?)
+            );
+
+            match (q_bytes.lookup_ref(ip)?, q.lookup(ip, None).await?) {
+                (Some(x), Some(y)) => {
+                    assert_eq!((x.0, x.1), (y.0, y.1));
+                    assert_eq!(x.2.iter().count(), y.2.len());
+                }
+                (None, None) => {}
+                x => panic!("{x:?}"),
+            }
+        }
+
+        let (_, _, record_field_contents) = q_bytes
+            .lookup_ref(Ipv6Addr::new(0x2001, 0x4860, 0, 0, 0, 0, 0, 0x8888).into())?
+            .unwrap();
+        let record_field_contents = record_field_contents
+            .iter()
+            .collect::<Result<Vec<_>, _>>()?;
+        assert!(
+            record_field_contents.contains(&RecordFieldContentRef::COUNTRY(
+                Some("US"),
+                Some("United States of America")
+            ))
+        );
+        assert!(record_field_contents.contains(&RecordFieldContentRef::ISP(Some("Google LLC"))));
+        let q_without_bytes_querier = Querier::new(
+            || Box::pin(future::ok(Cursor::new(Bytes::from(bytes.clone())))),
+            1,
+        )
+        .await?;
+        assert!(matches!(
+            q_without_bytes_querier.lookup_ref(Ipv4Addr::new(8, 8, 8, 8).into()),
+            Err(LookupError::BytesQuerierMissing)
+        ));
+
+        let (_, _, record_field_contents) = q_bytes
+            .lookup(Ipv4Addr::new(8, 8, 8, 8).into(), None)
+            .await?
+            .unwrap();
+        assert!(record_field_contents.iter().any(
+            |x| matches!(x, RecordFieldContent::ISP(_, Some(v)) if v.as_ref() == "Google LLC")
+        ));
+
+        //
+        assert!(matches!(
+            BytesQuerier::new(bytes[..bytes.len() - 1].to_vec().into(), q.header),
+            Err(NewError::TotalSizeMissing)
+        ));
+
+        //
+        let err = q_bytes
+            .bytes_querier
+            .as_ref()
+            .unwrap()
+            .str_at(bytes.len() as u32)
+            .unwrap_err();
+        assert!(
+            matches!(err, ContentFillError::ReadFailed(offset, _) if offset == bytes.len() as u64)
+        );
+        assert_eq!(
+            err.to_string(),
+            format!("read content at offset {} failed", bytes.len())
+        );
+        assert!(std::error::Error::source(&err)
+            .and_then(|x| x.downcast_ref::<IoError>())
+            .is_some());
+
+        //
+        let mut bytes_corrupted = bytes.to_vec();
+        let record_fields = q.header.record_fields().unwrap();
+        let record_len = record_fields.records_bytes_len_for_ipv4(1) as usize;
+        let n = record_fields
+            .iter()
+            .skip(1)
+            .position(|x| x == &RecordField::COUNTRY)
+            .unwrap();
+        for position in 0..q.header.v4_records_count as usize {
+            let i =
+                q.header.v4_records_seek_from_start() as usize + record_len * position + 4 + n * 4;
+            bytes_corrupted[i..i + 4].copy_from_slice(&u32::MAX.to_ne_bytes());
+        }
+        let q_corrupted = Querier::from_bytes(bytes_corrupted).await?;
+        let (_, _, record_field_contents) = q_corrupted
+            .lookup_ref(Ipv4Addr::new(1, 0, 0, 1).into())?
+            .unwrap();
+        assert!(record_field_contents.iter().any(|x| matches!(
+            x,
+            Err(ContentFillError::OffsetOverflow(offset)) if offset == u32::MAX as u64
+        )));
+        assert!(matches!(
+            q_corrupted
+                .bytes_querier
+                .as_ref()
+                .unwrap()
+                .fill(&mut record_field_contents.to_contents()),
+            Err(ContentFillError::OffsetOverflow(_))
+        ));
+
+        Ok(())
+    }
+}
@@ -1 +1,154 @@
+use std::collections::HashMap;
 
+use crate::{
+    content::{querier::COUNTRY_NAME_INDEX_OFFSET, UNKNOWN_STR},
+    record_field::{RecordFieldContent, RecordFieldContents},
+};
+
+//
+#[derive(Debug, Default)]
+pub struct Builder {
+    bytes: Vec<u8>,
+    cache: HashMap<Box<str>, u32>,
+    country_cache: HashMap<(Box<str>, Box<str>), u32>,
+}
+
+impl Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Writes the strings of record_field_contents (deduplicated) and sets their content indexes,
+    /// the indexes are relative to the start of content.
+    pub fn fill(
+        &mut self,
+        record_field_contents: &mut RecordFieldContents,
+    ) -> Result<(), FillError> {
+        for record_field_content in record_field_contents.iter_mut() {
+            let (i, v) = match record_field_content {
+                RecordFieldContent::COUNTRY(i, v, v_name) => {
+                    *i = self.push_country(v.as_deref(), v_name.as_deref())?;
+                    continue;
+                }
+                RecordFieldContent::REGION(i, v) => (i, v),
+                RecordFieldContent::CITY(i, v) => (i, v),
+                RecordFieldContent::ISP(i, v) => (i, v),
+                RecordFieldContent::DOMAIN(i, v) => (i, v),
+                //
+                RecordFieldContent::LATITUDE(_) => continue,
+                RecordFieldContent::LONGITUDE(_) => continue,
+                RecordFieldContent::ZIPCODE(i, v) => (i, v),
+                RecordFieldContent::TIMEZONE(i, v) => (i, v),
+                RecordFieldContent::NETSPEED(i, v) => (i, v),
+                RecordFieldContent::IDDCODE(i, v) => (i, v),
+                RecordFieldContent::AREACODE(i, v) => (i, v),
+                RecordFieldContent::WEATHERSTATIONCODE(i, v) => (i, v),
+                RecordFieldContent::WEATHERSTATIONNAME(i, v) => (i, v),
+                RecordFieldContent::MCC(i, v) => (i, v),
+                RecordFieldContent::MNC(i, v) => (i, v),
+                RecordFieldContent::MOBILEBRAND(i, v) => (i, v),
+                RecordFieldContent::ELEVATION(i, v) => (i, v),
+                RecordFieldContent::ADDRESSTYPE(i, v) => (i, v),
+                RecordFieldContent::CATEGORY(i, v) => (i, v),
+                RecordFieldContent::DISTRICT(i, v) => (i, v),
+                //
+                RecordFieldContent::PROXYTYPE(i, v) => (i, v),
+                RecordFieldContent::USAGETYPE(i, v) => (i, v),
+                RecordFieldContent::ASN(i, v) => (i, v),
+                RecordFieldContent::AS(i, v) => (i, v),
+                RecordFieldContent::LASTSEEN(i, v) => (i, v),
+                RecordFieldContent::THREAT(i, v) => (i, v),
+                RecordFieldContent::RESIDENTIAL(i, v) => (i, v),
+                RecordFieldContent::PROVIDER(i, v) => (i, v),
+                RecordFieldContent::FRAUDSCORE(i, v) => (i, v),
+            };
+
+            *i = self.push(v.as_deref())?;
+        }
+
+        Ok(())
+    }
+
+    pub fn finish(self) -> Vec<u8> {
+        self.bytes
+    }
+
+    fn push(&mut self, s: Option<&str>) -> Result<u32, FillError> {
+        let s = s.unwrap_or(UNKNOWN_STR);
+
+        if let Some(i) = self.cache.get(s) {
+            return Ok(*i);
+        }
+
+        let i = self.index()?;
+        self.write_str(s)?;
+        self.cache.insert(s.into(), i);
+
+        Ok(i)
+    }
+
+    fn push_country(&mut self, code: Option<&str>, name: Option<&str>) -> Result<u32, FillError> {
+        let code = code.unwrap_or(UNKNOWN_STR);
+        let name = name.unwrap_or(UNKNOWN_STR);
+
+        if let Some(i) = self.country_cache.get(&(code.into(), name.into())) {
+            return Ok(*i);
+        }
+
+        // https://github.com/ip2location/ip2proxy-rust/blob/5bdd3ef61c2e243c1b61eda1475ca23eab2b7240/src/db.rs#L252
+        // The name is always at 1 + 2, pad the code when it is shorter.
+        if 1 + code.len() > COUNTRY_NAME_INDEX_OFFSET {
+            return Err(FillError::CountryCodeTooLong(code.into()));
+        }
+
+        let i = self.index()?;
+        self.write_str(code)?;
+        self.bytes
+            .resize(i as usize + COUNTRY_NAME_INDEX_OFFSET, Default::default());
+        self.write_str(name)?;
+        self.country_cache.insert((code.into(), name.into()), i);
+
+        Ok(i)
+    }
+
+    fn index(&self) -> Result<u32, FillError> {
+        u32::try_from(self.bytes.len()).map_err(|_| FillError::TooLarge)
+    }
+
+    fn write_str(&mut self, s: &str) -> Result<(), FillError> {
+        let len = u8::try_from(s.len()).map_err(|_| FillError::StrTooLong(s.into()))?;
+
+        self.bytes.push(len);
+        self.bytes.extend_from_slice(s.as_bytes());
+
+        Ok(())
+    }
+}
+
+//
+#[derive(Debug)]
+pub enum FillError {
+    StrTooLong(Box<str>),
+    CountryCodeTooLong(Box<str>),
+    TooLarge,
+}
+
+impl core::fmt::Display for FillError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::StrTooLong(s) => write!(f, "str {s:?} is longer than 255 bytes"),
+            Self::CountryCodeTooLong(s) => write!(f, "country code {s:?} is longer than 3 bytes"),
+            Self::TooLarge => write!(f, "content is larger than 4 GiB"),
+        }
+    }
+}
+
+impl std::error::Error for FillError {}
@@ -0,0 +1,224 @@
+use core::num::NonZeroUsize;
+use std::{collections::BTreeMap, sync::Arc};
+
+use crate::{content::querier::filter_str, record_field::RecordField};
+
+//
+/// The cache of the contents (the strs) in [`Querier`](super::Querier), every pooled querier has its own.
+#[derive(Debug, Clone)]
+pub struct ContentCacheConfig {
+    /// The fields which are not in it are not cached.
+    pub policies: BTreeMap<RecordField, ContentCachePolicy>,
+    /// The capacity of the lru cache, it is shared by the [`ContentCachePolicy::Lru`] fields.
+    pub lru_capacity: NonZeroUsize,
+    /// Reads all distinct strs of the cached fields at open time, they are shared by the pooled queriers.
+    ///
+    /// It reads all the records, it is slow for the large BINs.
+    pub preload: bool,
+    /// The capacity of the [`RangeCache`](crate::range_cache::RangeCache) of the lookup results, none by default.
+    ///
+    /// A miss reads all the fields of the range, so that a hit serves any selected fields,
+    /// it costs the lookups which select a few fields of a large layout.
+    #[cfg(feature = "lru")]
+    pub range_cache_capacity: Option<NonZeroUsize>,
+}
+
+impl Default for ContentCacheConfig {
+    fn default() -> Self {
+        Self {
+            policies: [
+                (RecordField::COUNTRY, ContentCachePolicy::Static),
+                (RecordField::REGION, ContentCachePolicy::Lru),
+                (RecordField::CITY, ContentCachePolicy::Lru),
+                (RecordField::ZIPCODE, ContentCachePolicy::Lru),
+                (RecordField::TIMEZONE, ContentCachePolicy::Lru),
+                (RecordField::NETSPEED, ContentCachePolicy::Static),
+                (RecordField::ADDRESSTYPE, ContentCachePolicy::Static),
+                (RecordField::CATEGORY, ContentCachePolicy::Static),
+                (RecordField::PROXYTYPE, ContentCachePolicy::Static),
+                (RecordField::USAGETYPE, ContentCachePolicy::Static),
+            ]
+            .into_iter()
+            .collect(),
+            lru_capacity: NonZeroUsize::new(10000).expect(""),
+            preload: false,
+            #[cfg(feature = "lru")]
+            range_cache_capacity: None,
+        }
+    }
+}
+
+impl ContentCacheConfig {
+    /// Nothing is cached.
+    pub fn none() -> Self {
+        Self {
+            policies: BTreeMap::default(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_policy(mut self, record_field: RecordField, policy: ContentCachePolicy) -> Self {
+        self.policies.insert(record_field, policy);
+        self
+    }
+
+    #[cfg(feature = "lru")]
+    pub fn with_range_cache_capacity(mut self, capacity: NonZeroUsize) -> Self {
+        self.range_cache_capacity = Some(capacity);
+        self
+    }
+
+    pub fn policy(&self, record_field: RecordField) -> ContentCachePolicy {
+        self.policies
+            .get(&record_field)
+            .copied()
+            .unwrap_or(ContentCachePolicy::None)
+    }
+}
+
+//
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContentCachePolicy {
+    None,
+    /// Unbounded, for the fields with a few distinct strs, e.g. COUNTRY.
+    Static,
+    /// Bounded by [`ContentCacheConfig::lru_capacity`], it needs the `lru` feature, otherwise it is not cached.
+    Lru,
+}
+
+//
+#[derive(Debug)]
+pub(crate) struct Cache {
+    config: ContentCacheConfig,
+    static_cache: BTreeMap<u32, Box<str>>,
+    #[cfg(feature = "lru")]
+    lru_cache: lru::LruCache<u32, Box<str>>,
+    preloaded: Option<Arc<BTreeMap<u32, Box<str>>>>,
+}
+
+impl Cache {
+    pub(crate) fn new(config: ContentCacheConfig) -> Self {
+        Self {
+            #[cfg(feature = "lru")]
+            lru_cache: lru::LruCache::new(config.lru_capacity),
+            config,
+            static_cache: BTreeMap::default(),
+            preloaded: None,
+        }
+    }
+
+    pub(crate) fn set_preloaded(&mut self, preloaded: Arc<BTreeMap<u32, Box<str>>>) {
+        self.preloaded = Some(preloaded);
+    }
+
+    pub(crate) fn into_static_cache(self) -> BTreeMap<u32, Box<str>> {
+        self.static_cache
+    }
+
+    pub(crate) fn get(&mut self, record_field: RecordField, i: u32) -> Option<Option<Box<str>>> {
+        if let Some(s) = self.preloaded.as_ref().and_then(|x| x.get(&i)) {
+            return Some(filter_str(s));
+        }
+
+        match self.config.policy(record_field) {
+            ContentCachePolicy::None => None,
+            ContentCachePolicy::Static => self.static_cache.get(&i).map(filter_str),
+            ContentCachePolicy::Lru => {
+                #[cfg(feature = "lru")]
+                {
+                    self.lru_cache.get(&i).map(filter_str)
+                }
+                #[cfg(not(feature = "lru"))]
+                {
+                    None
+                }
+            }
+        }
+    }
+
+    #[allow(unused_variables)]
+    pub(crate) fn put(&mut self, record_field: RecordField, i: u32, s: &str) {
+        match self.config.policy(record_field) {
+            ContentCachePolicy::None => {}
+            ContentCachePolicy::Static => {
+                self.static_cache.insert(i, s.into());
+            }
+            ContentCachePolicy::Lru => {
+                #[cfg(feature = "lru")]
+                {
+                    self.lru_cache.push(i, s.into());
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use futures_util::{future, io::Cursor};
+
+    use crate::{
+        header::schema::SchemaType,
+        querier::Querier,
+        test_helper::{bin_bytes, BRISBANE, MOUNTAIN_VIEW},
+    };
+
+    #[tokio::test]
+    async fn test_lookup() -> Result<(), Box<dyn std::error::Error>> {
+        let bytes = bin_bytes(
+            SchemaType::IP2Proxy,
+            4,
+            &[
+                ("1.0.0.0", "1.0.0.255", &BRISBANE),
+                ("8.8.8.0", "8.8.8.255", &MOUNTAIN_VIEW),
+                ("2001:4860::", "2001:4860::ffff", &MOUNTAIN_VIEW),
+            ],
+        )?;
+        let bytes: Arc<[u8]> = bytes.into();
+
+        let q = Querier::new(|| Box::pin(future::ok(Cursor::new(bytes.clone()))), 1).await?;
+
+        for content_cache_config in [
+            ContentCacheConfig::none(),
+            ContentCacheConfig::default()
+                .with_policy(RecordField::ISP, ContentCachePolicy::Lru)
+                .with_policy(RecordField::PROXYTYPE, ContentCachePolicy::None),
+            ContentCacheConfig {
+                preload: true,
+                ..ContentCacheConfig::default()
+                    .with_policy(RecordField::ISP, ContentCachePolicy::Static)
+            },
+        ] {
+            let q_cached = Querier::new_with_content_cache_config(
+                || Box::pin(future::ok(Cursor::new(bytes.clone()))),
+                2,
+                content_cache_config.clone(),
+            )
+            .await?;
+
+            assert_eq!(
+                format!("{:?}", *q_cached.content_pool.get().await?).contains("preloaded: Some"),
+                content_cache_config.preload
+            );
+
+            for ip in [
+                "1.0.0.1",
+                "8.8.8.8",
+                "8.8.8.8",
+                "2001:4860::8888",
+                "9.9.9.9",
+            ] {
+                let ip = ip.parse()?;
+                assert_eq!(
+                    format!("{:?}", q_cached.lookup(ip, None).await?),
+                    format!("{:?}", q.lookup(ip, None).await?),
+                    "{ip} {content_cache_config:?}"
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
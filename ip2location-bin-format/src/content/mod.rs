@@ -3,6 +3,9 @@ pub const UNKNOWN_STR: &str = "-";
 
 //
 pub mod builder;
+pub mod cache;
 pub mod querier;
 
+pub use builder::Builder;
+pub use cache::{ContentCacheConfig, ContentCachePolicy};
 pub use querier::Querier;
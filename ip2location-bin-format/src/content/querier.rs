@@ -1,26 +1,35 @@
 use std::{
     collections::BTreeMap,
     io::{Error as IoError, SeekFrom},
+    sync::Arc,
 };
 
 use futures_util::{AsyncRead, AsyncReadExt as _, AsyncSeek, AsyncSeekExt as _};
 
 use crate::{
-    content::UNKNOWN_STR,
-    record_field::{RecordFieldContent, RecordFieldContents},
+    content::{
+        cache::{Cache, ContentCacheConfig},
+        UNKNOWN_STR,
+    },
+    record_field::{RecordField, RecordFieldContent, RecordFieldContents},
 };
 
 //
 pub const COUNTRY_NAME_INDEX_OFFSET: usize = 3;
 
+/// The offset of the country name, after the country code, the offset of a corrupted BIN may overflow.
+pub(crate) fn country_name_offset(offset: u32) -> Result<u32, FillError> {
+    offset
+        .checked_add(COUNTRY_NAME_INDEX_OFFSET as u32)
+        .ok_or(FillError::OffsetOverflow(offset as u64))
+}
+
 //
 #[derive(Debug)]
 pub struct Querier<S> {
     stream: S,
     buf: Vec<u8>,
-    static_cache: BTreeMap<u32, Box<str>>,
-    #[cfg(feature = "lru")]
-    lru_cache: lru::LruCache<u32, Box<str>>,
+    cache: Cache,
 }
 
 //
@@ -28,6 +37,10 @@ pub struct Querier<S> {
 //
 impl<S> Querier<S> {
     pub fn new(stream: S) -> Self {
+        Self::with_cache_config(stream, ContentCacheConfig::default())
+    }
+
+    pub fn with_cache_config(stream: S, cache_config: ContentCacheConfig) -> Self {
         Self {
             stream,
             buf: {
@@ -36,11 +49,18 @@ impl<S> Querier<S> {
                 buf.resize_with(len, Default::default);
                 buf
             },
-            static_cache: BTreeMap::default(),
-            #[cfg(feature = "lru")]
-            lru_cache: lru::LruCache::new(core::num::NonZeroUsize::new(10000).expect("")),
+            cache: Cache::new(cache_config),
         }
     }
+
+    /// The preloaded strs are checked before the caches, see [`ContentCacheConfig::preload`].
+    pub(crate) fn set_preloaded(&mut self, preloaded: Arc<BTreeMap<u32, Box<str>>>) {
+        self.cache.set_preloaded(preloaded);
+    }
+
+    pub(crate) fn into_static_cache(self) -> BTreeMap<u32, Box<str>> {
+        self.cache.into_static_cache()
+    }
 }
 
 //
@@ -50,122 +70,51 @@ impl<S> Querier<S>
 where
     S: AsyncSeek + AsyncRead + Unpin,
 {
+    /// The whole BIN, e.g. for [`Querier::validate`](crate::querier::Querier::validate), the fills seek before reading.
+    pub(crate) async fn read_all(&mut self, capacity: usize) -> Result<Vec<u8>, IoError> {
+        self.stream.seek(SeekFrom::Start(0)).await?;
+
+        let mut bytes = Vec::with_capacity(capacity);
+        self.stream.read_to_end(&mut bytes).await?;
+        Ok(bytes)
+    }
+
     pub async fn fill(
         &mut self,
         record_field_contents: &mut RecordFieldContents,
     ) -> Result<(), FillError> {
         for record_field_content in record_field_contents.iter_mut() {
+            let record_field = record_field_content.record_field();
+
             //
-            let (seek_from_start, s_len_estimatable) = match record_field_content {
+            let seek_from_start = match record_field_content {
                 RecordFieldContent::COUNTRY(i, v, v_name) => {
-                    if let Some(s) = self.static_cache.get(i) {
-                        *v = filter_str(s);
-
-                        if let Some(s) = self
-                            .static_cache
-                            .get(&(*i + COUNTRY_NAME_INDEX_OFFSET as u32))
+                    if let Some(s) = self.cache.get(record_field, *i) {
+                        if let Some(s_name) = self.cache.get(record_field, country_name_offset(*i)?)
                         {
-                            *v_name = filter_str(s);
-
-                            continue;
-                        }
-                    }
-
-                    (*i, 28)
-                }
-                #[allow(unused_variables)]
-                RecordFieldContent::REGION(i, v) => {
-                    #[cfg(feature = "lru")]
-                    {
-                        if let Some(s) = self.lru_cache.get(i) {
-                            *v = filter_str(s);
-
-                            continue;
-                        }
-                    }
-
-                    (*i, 20)
-                }
-                #[allow(unused_variables)]
-                RecordFieldContent::CITY(i, v) => {
-                    #[cfg(feature = "lru")]
-                    {
-                        if let Some(s) = self.lru_cache.get(i) {
-                            *v = filter_str(s);
+                            *v = s;
+                            *v_name = s_name;
 
                             continue;
                         }
                     }
 
-                    (*i, 20)
+                    *i
                 }
-                RecordFieldContent::ISP(i, _) => (*i, 10),
-                RecordFieldContent::DOMAIN(i, _) => (*i, 30),
-                //
-                RecordFieldContent::LATITUDE(_) => continue,
-                RecordFieldContent::LONGITUDE(_) => continue,
-                #[allow(unused_variables)]
-                RecordFieldContent::ZIPCODE(i, v) => {
-                    #[cfg(feature = "lru")]
-                    {
-                        if let Some(s) = self.lru_cache.get(i) {
-                            *v = filter_str(s);
+                _ => match str_mut(record_field_content) {
+                    Some((i, v)) => {
+                        if let Some(s) = self.cache.get(record_field, i) {
+                            *v = s;
 
                             continue;
                         }
-                    }
-
-                    (*i, 8)
-                }
-                #[allow(unused_variables)]
-                RecordFieldContent::TIMEZONE(i, v) => {
-                    #[cfg(feature = "lru")]
-                    {
-                        if let Some(s) = self.lru_cache.get(i) {
-                            *v = filter_str(s);
-
-                            continue;
-                        }
-                    }
-
-                    (*i, 8)
-                }
-                RecordFieldContent::NETSPEED(i, v) => {
-                    if let Some(s) = self.static_cache.get(i) {
-                        *v = filter_str(s);
-
-                        continue;
-                    }
-
-                    // TODO, 10
-                    (*i, 10)
-                }
-                //
-                RecordFieldContent::PROXYTYPE(i, v) => {
-                    if let Some(s) = self.static_cache.get(i) {
-                        *v = filter_str(s);
-
-                        continue;
-                    }
-
-                    (*i, 3)
-                }
-                RecordFieldContent::USAGETYPE(i, v) => {
-                    if let Some(s) = self.static_cache.get(i) {
-                        *v = filter_str(s);
 
-                        continue;
+                        i
                     }
-
-                    (*i, 3)
-                }
-                RecordFieldContent::ASN(i, _) => (*i, 10),
-                RecordFieldContent::AS(i, _) => (*i, 30),
-                RecordFieldContent::LASTSEEN(i, _) => (*i, 6),
-                RecordFieldContent::THREAT(i, _) => (*i, 30),
-                RecordFieldContent::RESIDENTIAL(i, _) => (*i, 30),
-                RecordFieldContent::PROVIDER(i, _) => (*i, 30),
+                    None => continue,
+                },
             };
+            let s_len_estimatable = s_len_estimatable(record_field);
 
             //
             //
@@ -174,7 +123,7 @@ where
             self.stream
                 .seek(SeekFrom::Start(seek_from_start as u64))
                 .await
-                .map_err(FillError::SeekFailed)?;
+                .map_err(|err| FillError::SeekFailed(seek_from_start as u64, err))?;
 
             //
             let mut n_read = 0;
@@ -184,10 +133,10 @@ where
                 .stream
                 .read(&mut self.buf[..s_len_estimatable + 1])
                 .await
-                .map_err(FillError::ReadFailed)?;
+                .map_err(|err| FillError::ReadFailed(seek_from_start as u64, err))?;
             n_read += n;
             if n == 0 {
-                return Err(FillError::Other("read is not completed in first read"));
+                return Err(FillError::ReadIncomplete(seek_from_start as u64));
             }
 
             //
@@ -208,24 +157,24 @@ where
                         .stream
                         .read(&mut self.buf[n_read..])
                         .await
-                        .map_err(FillError::ReadFailed)?;
+                        .map_err(|err| FillError::ReadFailed(seek_from_start as u64, err))?;
                     n_read += n;
 
                     if n == 0 {
-                        return Err(FillError::Other("read is not completed in loop read"));
+                        return Err(FillError::ReadIncomplete(seek_from_start as u64));
                     }
                 }
 
                 let s_len = self.buf[0];
                 let s = core::str::from_utf8(&self.buf[1..1 + s_len as usize])
-                    .map_err(FillError::ToUtf8Failed)?;
+                    .map_err(|err| FillError::ToUtf8Failed(seek_from_start as u64, err))?;
 
                 match record_field_content {
                     RecordFieldContent::COUNTRY(i, v, v_name) => {
                         match n_loop {
                             0 => {
                                 *v = filter_str(s);
-                                self.static_cache.insert(*i, s.into());
+                                self.cache.put(record_field, *i, s);
 
                                 n_loop += 1;
                                 // https://github.com/ip2location/ip2proxy-rust/blob/5bdd3ef61c2e243c1b61eda1475ca23eab2b7240/src/db.rs#L252
@@ -237,83 +186,17 @@ where
                             }
                             1 => {
                                 *v_name = filter_str(s);
-                                self.static_cache
-                                    .insert(*i + COUNTRY_NAME_INDEX_OFFSET as u32, s.into());
+                                self.cache.put(record_field, country_name_offset(*i)?, s);
                             }
                             _ => unreachable!(),
                         }
                     }
-                    #[allow(unused_variables)]
-                    RecordFieldContent::REGION(i, v) => {
-                        *v = filter_str(s);
-                        #[cfg(feature = "lru")]
-                        {
-                            self.lru_cache.push(*i, s.into());
-                        }
-                    }
-                    #[allow(unused_variables)]
-                    RecordFieldContent::CITY(i, v) => {
-                        *v = filter_str(s);
-                        #[cfg(feature = "lru")]
-                        {
-                            self.lru_cache.push(*i, s.into());
-                        }
-                    }
-                    RecordFieldContent::ISP(_, v) => {
-                        *v = filter_str(s);
-                    }
-                    RecordFieldContent::DOMAIN(_, v) => {
-                        *v = filter_str(s);
-                    }
-                    //
-                    RecordFieldContent::LATITUDE(_) => {}
-                    RecordFieldContent::LONGITUDE(_) => {}
-                    #[allow(unused_variables)]
-                    RecordFieldContent::ZIPCODE(i, v) => {
-                        *v = filter_str(s);
-                        #[cfg(feature = "lru")]
-                        {
-                            self.lru_cache.push(*i, s.into());
-                        }
-                    }
-                    #[allow(unused_variables)]
-                    RecordFieldContent::TIMEZONE(i, v) => {
-                        *v = filter_str(s);
-                        #[cfg(feature = "lru")]
-                        {
-                            self.lru_cache.push(*i, s.into());
+                    _ => {
+                        if let Some((i, v)) = str_mut(record_field_content) {
+                            *v = filter_str(s);
+                            self.cache.put(record_field, i, s);
                         }
                     }
-                    RecordFieldContent::NETSPEED(_, v) => {
-                        *v = filter_str(s);
-                    }
-                    //
-                    RecordFieldContent::PROXYTYPE(i, v) => {
-                        *v = filter_str(s);
-                        self.static_cache.insert(*i, s.into());
-                    }
-                    RecordFieldContent::USAGETYPE(i, v) => {
-                        *v = filter_str(s);
-                        self.static_cache.insert(*i, s.into());
-                    }
-                    RecordFieldContent::ASN(_, v) => {
-                        *v = filter_str(s);
-                    }
-                    RecordFieldContent::AS(_, v) => {
-                        *v = filter_str(s);
-                    }
-                    RecordFieldContent::LASTSEEN(_, v) => {
-                        *v = filter_str(s);
-                    }
-                    RecordFieldContent::THREAT(_, v) => {
-                        *v = filter_str(s);
-                    }
-                    RecordFieldContent::RESIDENTIAL(_, v) => {
-                        *v = filter_str(s);
-                    }
-                    RecordFieldContent::PROVIDER(_, v) => {
-                        *v = filter_str(s);
-                    }
                 }
 
                 break;
@@ -324,7 +207,85 @@ where
     }
 }
 
-fn filter_str(s: impl AsRef<str>) -> Option<Box<str>> {
+// The offset and the str of the content, except COUNTRY (two strs) and LATITUDE/LONGITUDE (inline).
+pub(crate) fn str_mut(
+    record_field_content: &mut RecordFieldContent,
+) -> Option<(u32, &mut Option<Box<str>>)> {
+    match record_field_content {
+        RecordFieldContent::COUNTRY(_, _, _) => None,
+        RecordFieldContent::REGION(i, v)
+        | RecordFieldContent::CITY(i, v)
+        | RecordFieldContent::ISP(i, v)
+        | RecordFieldContent::DOMAIN(i, v) => Some((*i, v)),
+        //
+        RecordFieldContent::LATITUDE(_) | RecordFieldContent::LONGITUDE(_) => None,
+        RecordFieldContent::ZIPCODE(i, v)
+        | RecordFieldContent::TIMEZONE(i, v)
+        | RecordFieldContent::NETSPEED(i, v)
+        | RecordFieldContent::IDDCODE(i, v)
+        | RecordFieldContent::AREACODE(i, v)
+        | RecordFieldContent::WEATHERSTATIONCODE(i, v)
+        | RecordFieldContent::WEATHERSTATIONNAME(i, v)
+        | RecordFieldContent::MCC(i, v)
+        | RecordFieldContent::MNC(i, v)
+        | RecordFieldContent::MOBILEBRAND(i, v)
+        | RecordFieldContent::ELEVATION(i, v)
+        | RecordFieldContent::ADDRESSTYPE(i, v)
+        | RecordFieldContent::CATEGORY(i, v)
+        | RecordFieldContent::DISTRICT(i, v) => Some((*i, v)),
+        //
+        RecordFieldContent::PROXYTYPE(i, v)
+        | RecordFieldContent::USAGETYPE(i, v)
+        | RecordFieldContent::ASN(i, v)
+        | RecordFieldContent::AS(i, v)
+        | RecordFieldContent::LASTSEEN(i, v)
+        | RecordFieldContent::THREAT(i, v)
+        | RecordFieldContent::RESIDENTIAL(i, v)
+        | RecordFieldContent::PROVIDER(i, v)
+        | RecordFieldContent::FRAUDSCORE(i, v) => Some((*i, v)),
+    }
+}
+
+fn s_len_estimatable(record_field: RecordField) -> usize {
+    match record_field {
+        RecordField::IP => 0,
+        RecordField::COUNTRY => 28,
+        RecordField::REGION => 20,
+        RecordField::CITY => 20,
+        RecordField::ISP => 10,
+        RecordField::DOMAIN => 30,
+        //
+        RecordField::LATITUDE => 0,
+        RecordField::LONGITUDE => 0,
+        RecordField::ZIPCODE => 8,
+        RecordField::TIMEZONE => 8,
+        // TODO, 10
+        RecordField::NETSPEED => 10,
+        RecordField::IDDCODE => 3,
+        RecordField::AREACODE => 7,
+        RecordField::WEATHERSTATIONCODE => 8,
+        RecordField::WEATHERSTATIONNAME => 15,
+        RecordField::MCC => 3,
+        RecordField::MNC => 10,
+        RecordField::MOBILEBRAND => 10,
+        RecordField::ELEVATION => 3,
+        RecordField::ADDRESSTYPE => 1,
+        RecordField::CATEGORY => 8,
+        RecordField::DISTRICT => 15,
+        //
+        RecordField::PROXYTYPE => 3,
+        RecordField::USAGETYPE => 3,
+        RecordField::ASN => 10,
+        RecordField::AS => 30,
+        RecordField::LASTSEEN => 6,
+        RecordField::THREAT => 30,
+        RecordField::RESIDENTIAL => 30,
+        RecordField::PROVIDER => 30,
+        RecordField::FRAUDSCORE => 2,
+    }
+}
+
+pub(crate) fn filter_str(s: impl AsRef<str>) -> Option<Box<str>> {
     let s = s.as_ref();
     if s == UNKNOWN_STR {
         None
@@ -336,16 +297,36 @@ fn filter_str(s: impl AsRef<str>) -> Option<Box<str>> {
 //
 #[derive(Debug)]
 pub enum FillError {
-    SeekFailed(IoError),
-    ReadFailed(IoError),
-    ToUtf8Failed(core::str::Utf8Error),
-    Other(&'static str),
+    /// The offset is of the str in the BIN.
+    SeekFailed(u64, IoError),
+    ReadFailed(u64, IoError),
+    ToUtf8Failed(u64, core::str::Utf8Error),
+    ReadIncomplete(u64),
+    OffsetOverflow(u64),
 }
 
 impl core::fmt::Display for FillError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{self:?}")
+        match self {
+            Self::SeekFailed(offset, _) => write!(f, "seek to content at offset {offset} failed"),
+            Self::ReadFailed(offset, _) => write!(f, "read content at offset {offset} failed"),
+            Self::ToUtf8Failed(offset, _) => {
+                write!(f, "content at offset {offset} is not valid UTF-8")
+            }
+            Self::ReadIncomplete(offset) => {
+                write!(f, "content at offset {offset} ends before its length")
+            }
+            Self::OffsetOverflow(offset) => write!(f, "content offset {offset} overflows"),
+        }
     }
 }
 
-impl std::error::Error for FillError {}
+impl std::error::Error for FillError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::SeekFailed(_, err) | Self::ReadFailed(_, err) => Some(err),
+            Self::ToUtf8Failed(_, err) => Some(err),
+            Self::ReadIncomplete(_) | Self::OffsetOverflow(_) => None,
+        }
+    }
+}
@@ -0,0 +1,347 @@
+//! The conversions between the LITE/commercial CSV and BIN,
+//! the typed crates only map the columns to the record fields.
+
+use core::cmp::{max, min};
+use std::{
+    io::{Error as IoError, Read, Write},
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+};
+
+use csv::{QuoteStyle, ReaderBuilder, StringRecord, Writer, WriterBuilder};
+use futures_util::{AsyncRead, AsyncSeek};
+use serde::de::DeserializeOwned;
+
+use crate::{
+    builder::{AppendError, FinishError, NewError},
+    content::UNKNOWN_STR,
+    header::{schema::SchemaType, Schema as HeaderSchema},
+    querier::{LookupError, Querier},
+    record_field::{RecordFieldContent, RecordFieldContents, RecordFields},
+    Builder,
+};
+
+//
+/// A row of the CSV.
+pub trait CsvRow: DeserializeOwned {
+    type ToRecordFieldContentsError: std::error::Error + 'static;
+
+    /// The small numbers of IPV6 CSV are ipv4, they are converted by [`csv_to_bin`].
+    fn ip_range(&self) -> (IpAddr, IpAddr);
+
+    fn to_record_field_contents(
+        &self,
+        record_fields: &RecordFields,
+    ) -> Result<RecordFieldContents, Self::ToRecordFieldContentsError>;
+
+    /// The value of the column if the record field content is of it, used by [`bin_to_csv`].
+    fn column_value(column: &str, record_field_content: &RecordFieldContent) -> Option<String>;
+}
+
+//
+/// Converts a CSV (v4 or IPV6) to BIN.
+///
+/// csv_header is the columns of the CSV, only r#type, sub_type, date and license_code of header are used.
+pub fn csv_to_bin<T: CsvRow, R: Read, W: Write>(
+    rdr: R,
+    csv_header: &[&str],
+    header: HeaderSchema,
+    w: &mut W,
+) -> Result<HeaderSchema, CsvToBinError<T::ToRecordFieldContentsError>> {
+    let mut builder = Builder::new(header).map_err(CsvToBinError::BuilderNewFailed)?;
+    let record_fields = builder.record_fields().to_owned();
+
+    //
+    let mut rdr = ReaderBuilder::new().has_headers(false).from_reader(rdr);
+    let csv_header = StringRecord::from(csv_header);
+
+    // The small numbers of IPV6 CSV are deserialized as ipv4.
+    let to_ipv6 = |ip: IpAddr| -> IpAddr {
+        match ip {
+            IpAddr::V4(ip) => Ipv6Addr::from(u32::from(ip) as u128).into(),
+            IpAddr::V6(ip) => ip.into(),
+        }
+    };
+
+    let mut is_v6 = false;
+    for record in rdr.records() {
+        let record = record.map_err(CsvToBinError::CsvParseFailed)?;
+
+        let row: T = record
+            .deserialize(Some(&csv_header))
+            .map_err(CsvToBinError::RecordDeFailed)?;
+
+        let record_field_contents = row
+            .to_record_field_contents(&record_fields)
+            .map_err(CsvToBinError::ToRecordFieldContentsFailed)?;
+
+        let (ip_from, ip_to) = match row.ip_range() {
+            (IpAddr::V4(ip_from), IpAddr::V4(ip_to)) if !is_v6 => (ip_from.into(), ip_to.into()),
+            (ip_from, ip_to) => {
+                is_v6 = true;
+                (to_ipv6(ip_from), to_ipv6(ip_to))
+            }
+        };
+
+        builder
+            .append(ip_from, ip_to, record_field_contents)
+            .map_err(CsvToBinError::BuilderAppendFailed)?;
+    }
+
+    builder
+        .finish(w)
+        .map_err(CsvToBinError::BuilderFinishFailed)
+}
+
+//
+#[derive(Debug)]
+pub enum CsvToBinError<E> {
+    BuilderNewFailed(NewError),
+    CsvParseFailed(csv::Error),
+    RecordDeFailed(csv::Error),
+    ToRecordFieldContentsFailed(E),
+    BuilderAppendFailed(AppendError),
+    BuilderFinishFailed(FinishError),
+}
+
+impl<E> core::fmt::Display for CsvToBinError<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::BuilderNewFailed(_) => write!(f, "create builder failed"),
+            Self::CsvParseFailed(_) => write!(f, "parse CSV failed"),
+            Self::RecordDeFailed(_) => write!(f, "deserialize record failed"),
+            Self::ToRecordFieldContentsFailed(_) => {
+                write!(f, "convert to record field contents failed")
+            }
+            Self::BuilderAppendFailed(_) => write!(f, "append to builder failed"),
+            Self::BuilderFinishFailed(_) => write!(f, "finish builder failed"),
+        }
+    }
+}
+
+impl<E> std::error::Error for CsvToBinError<E>
+where
+    E: std::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::BuilderNewFailed(err) => Some(err),
+            Self::CsvParseFailed(err) | Self::RecordDeFailed(err) => Some(err),
+            Self::ToRecordFieldContentsFailed(err) => Some(err),
+            Self::BuilderAppendFailed(err) => Some(err),
+            Self::BuilderFinishFailed(err) => Some(err),
+        }
+    }
+}
+
+//
+/// Exports BIN to CSV, in the numbering of the BIN, i.e. the v4 CSV or the IPV6 CSV.
+///
+/// csv_header is the columns of the CSV.
+/// The ipv4 records of the IPV6 BIN are exported as the IPv4-mapped ranges in the order of the ipv6 records,
+/// and the ranges whose country is unknown are skipped for IP2Proxy, its CSV only has the proxies.
+pub async fn bin_to_csv<T, S, W>(
+    querier: &Querier<S>,
+    csv_header: &[&str],
+    w: W,
+) -> Result<(), BinToCsvError>
+where
+    T: CsvRow,
+    S: AsyncSeek + AsyncRead + Unpin,
+    W: Write,
+{
+    let mut wtr = RowWriter::<T, W> {
+        wtr: WriterBuilder::new()
+            .has_headers(false)
+            .quote_style(QuoteStyle::Always)
+            .from_writer(w),
+        csv_header,
+        is_unknown_skipped: querier.header.r#type == SchemaType::IP2Proxy,
+        phantom: core::marker::PhantomData,
+    };
+
+    if !querier.header.has_v6() {
+        wtr.write_ipv4_rows(querier, 0).await?;
+
+        return wtr.wtr.flush().map_err(BinToCsvError::FlushFailed);
+    }
+
+    let mapped_from = u128::from(Ipv4Addr::UNSPECIFIED.to_ipv6_mapped());
+    let mapped_to = u128::from(Ipv4Addr::BROADCAST.to_ipv6_mapped());
+
+    for position in 0..querier.header.v6_records_count {
+        let (ip_from, ip_to, record_field_contents) = match querier
+            .read_ipv6(position, None)
+            .await
+            .map_err(BinToCsvError::ReadFailed)?
+        {
+            Some(x) => x,
+            None => break,
+        };
+        let (ip_from, ip_to) = (ip_to_number(ip_from), ip_to_number(ip_to_inclusive(ip_to)));
+
+        // The IPv4-mapped part of the range is exported from the ipv4 records.
+        if ip_from < mapped_from {
+            wtr.write_row(ip_from, min(ip_to, mapped_from - 1), &record_field_contents)?;
+        }
+        if ip_from <= mapped_from && mapped_from <= ip_to {
+            wtr.write_ipv4_rows(querier, mapped_from).await?;
+        }
+        if ip_to > mapped_to {
+            wtr.write_row(max(ip_from, mapped_to + 1), ip_to, &record_field_contents)?;
+        }
+    }
+
+    wtr.wtr.flush().map_err(BinToCsvError::FlushFailed)
+}
+
+/// Formats latitude and longitude from the shortest representation of the f32,
+/// e.g. `-118.2437` is `-118.243700` like the CSV.
+pub fn coordinate_to_string(v: f32) -> String {
+    let v = v
+        .to_string()
+        .parse::<f64>()
+        .unwrap_or_else(|_| f64::from(v));
+    format!("{v:.6}")
+}
+
+struct RowWriter<'a, T, W: Write> {
+    wtr: Writer<W>,
+    csv_header: &'a [&'a str],
+    is_unknown_skipped: bool,
+    phantom: core::marker::PhantomData<T>,
+}
+
+impl<T, W> RowWriter<'_, T, W>
+where
+    T: CsvRow,
+    W: Write,
+{
+    /// The ip numbers of the ipv4 records are added to offset.
+    async fn write_ipv4_rows<S>(
+        &mut self,
+        querier: &Querier<S>,
+        offset: u128,
+    ) -> Result<(), BinToCsvError>
+    where
+        S: AsyncSeek + AsyncRead + Unpin,
+    {
+        for position in 0..querier.header.v4_records_count {
+            let (ip_from, ip_to, record_field_contents) = match querier
+                .read_ipv4(position, None)
+                .await
+                .map_err(BinToCsvError::ReadFailed)?
+            {
+                Some(x) => x,
+                None => break,
+            };
+
+            self.write_row(
+                offset + ip_to_number(ip_from),
+                offset + ip_to_number(ip_to_inclusive(ip_to)),
+                &record_field_contents,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn write_row(
+        &mut self,
+        ip_from: u128,
+        ip_to: u128,
+        record_field_contents: &RecordFieldContents,
+    ) -> Result<(), BinToCsvError> {
+        if self.is_unknown_skipped
+            && record_field_contents
+                .iter()
+                .any(|x| matches!(x, RecordFieldContent::COUNTRY(_, None, _)))
+        {
+            return Ok(());
+        }
+
+        let mut row = Vec::with_capacity(self.csv_header.len());
+        for column in self.csv_header {
+            let value = match *column {
+                "ip_from" => Some(ip_from.to_string()),
+                "ip_to" => Some(ip_to.to_string()),
+                column => record_field_contents.iter().find_map(|x| match x {
+                    RecordFieldContent::COUNTRY(_, v, _) if column == "country_code" => {
+                        Some(v.as_deref().unwrap_or(UNKNOWN_STR).to_owned())
+                    }
+                    RecordFieldContent::COUNTRY(_, _, v) if column == "country_name" => {
+                        Some(v.as_deref().unwrap_or(UNKNOWN_STR).to_owned())
+                    }
+                    x => T::column_value(column, x),
+                }),
+            };
+
+            row.push(value.ok_or_else(|| BinToCsvError::ColumnUnknown((*column).into()))?);
+        }
+
+        self.wtr
+            .write_record(&row)
+            .map_err(BinToCsvError::CsvWriteFailed)
+    }
+}
+
+fn ip_to_number(ip: IpAddr) -> u128 {
+    match ip {
+        IpAddr::V4(ip) => u32::from(ip).into(),
+        IpAddr::V6(ip) => ip.into(),
+    }
+}
+
+/// The ip_to of the records is the ip_from of the next record,
+/// except the last range that ends at the max ip.
+fn ip_to_inclusive(ip_to: IpAddr) -> IpAddr {
+    match ip_to {
+        IpAddr::V4(ip) if ip == Ipv4Addr::BROADCAST => ip.into(),
+        IpAddr::V4(ip) => Ipv4Addr::from(u32::from(ip).saturating_sub(1)).into(),
+        IpAddr::V6(ip) if u128::from(ip) == u128::MAX => ip.into(),
+        IpAddr::V6(ip) => Ipv6Addr::from(u128::from(ip).saturating_sub(1)).into(),
+    }
+}
+
+//
+#[derive(Debug)]
+pub enum BinToCsvError {
+    ReadFailed(LookupError),
+    ColumnUnknown(Box<str>),
+    CsvWriteFailed(csv::Error),
+    FlushFailed(IoError),
+}
+
+impl core::fmt::Display for BinToCsvError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::ReadFailed(_) => write!(f, "read BIN failed"),
+            Self::ColumnUnknown(column) => write!(f, "column {column} is unknown"),
+            Self::CsvWriteFailed(_) => write!(f, "write CSV failed"),
+            Self::FlushFailed(_) => write!(f, "flush CSV failed"),
+        }
+    }
+}
+
+impl std::error::Error for BinToCsvError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::ReadFailed(err) => Some(err),
+            Self::CsvWriteFailed(err) => Some(err),
+            Self::FlushFailed(err) => Some(err),
+            Self::ColumnUnknown(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coordinate_to_string() {
+        assert_eq!(coordinate_to_string(-118.2437), "-118.243700");
+        assert_eq!(coordinate_to_string(34.05286), "34.052860");
+        assert_eq!(coordinate_to_string(119.30611), "119.306110");
+        assert_eq!(coordinate_to_string(0.0), "0.000000");
+    }
+}
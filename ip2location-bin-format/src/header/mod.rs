@@ -7,4 +7,5 @@ pub mod renderer;
 pub mod schema;
 
 pub use parser::Parser;
+pub use renderer::Renderer;
 pub use schema::Schema;
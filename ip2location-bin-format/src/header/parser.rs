@@ -11,8 +11,9 @@ pub struct Parser {
     buf: [u8; 4],
 }
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
 enum State {
+    #[default]
     Idle,
     SubTypeParsed,
     NumRecordFieldsParsed,
@@ -28,12 +29,6 @@ enum State {
     TotalSizeParsed,
 }
 
-impl Default for State {
-    fn default() -> Self {
-        Self::Idle
-    }
-}
-
 impl Parser {
     pub fn new() -> Self {
         Self::default()
@@ -289,11 +284,26 @@ pub enum ParseError {
 
 impl core::fmt::Display for ParseError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{self:?}")
+        match self {
+            Self::ReadFailed(_) => write!(f, "read header failed"),
+            Self::YearOrMonthOrDayValueInvalid(year, month, day) => {
+                write!(f, "date {year}-{month}-{day} is invalid")
+            }
+            Self::TypeValueInvalid(value) => write!(f, "type {value} is invalid"),
+            Self::VerifyFailed(_) => write!(f, "verify header failed"),
+        }
     }
 }
 
-impl std::error::Error for ParseError {}
+impl std::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::ReadFailed(err) => Some(err),
+            Self::VerifyFailed(err) => Some(err),
+            Self::YearOrMonthOrDayValueInvalid(_, _, _) | Self::TypeValueInvalid(_) => None,
+        }
+    }
+}
 
 impl From<IoError> for ParseError {
     fn from(err: IoError) -> Self {
@@ -1 +1,77 @@
+use std::io::{Error as IoError, Write};
 
+use super::{
+    schema::{Schema, VerifyError},
+    HEADER_LEN,
+};
+
+//
+#[derive(Debug, Default)]
+pub struct Renderer {
+    buf: Vec<u8>,
+}
+
+impl Renderer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn render<W: Write>(&mut self, schema: &Schema, w: &mut W) -> Result<usize, RenderError> {
+        schema.verify().map_err(RenderError::VerifyFailed)?;
+
+        self.buf.clear();
+
+        self.buf.push(schema.sub_type.0);
+        self.buf.push(schema.num_record_fields);
+        self.buf.push(schema.date.0);
+        self.buf.push(schema.date.1);
+        self.buf.push(schema.date.2);
+        self.buf
+            .extend_from_slice(&schema.v4_records_count.to_ne_bytes());
+        self.buf
+            .extend_from_slice(&schema.v4_records_position_start.to_ne_bytes());
+        self.buf
+            .extend_from_slice(&schema.v6_records_count.to_ne_bytes());
+        self.buf
+            .extend_from_slice(&schema.v6_records_position_start.to_ne_bytes());
+        self.buf
+            .extend_from_slice(&schema.v4_index_position_start.to_ne_bytes());
+        self.buf
+            .extend_from_slice(&schema.v6_index_position_start.to_ne_bytes());
+        self.buf.push(schema.r#type as u8);
+        self.buf.push(schema.license_code);
+        self.buf.extend_from_slice(&schema.total_size.to_ne_bytes());
+
+        // Reserved
+        self.buf.resize(HEADER_LEN as usize, 0);
+
+        w.write_all(&self.buf).map_err(RenderError::WriteFailed)?;
+
+        Ok(self.buf.len())
+    }
+}
+
+//
+#[derive(Debug)]
+pub enum RenderError {
+    WriteFailed(IoError),
+    VerifyFailed(VerifyError),
+}
+
+impl core::fmt::Display for RenderError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::WriteFailed(_) => write!(f, "write header failed"),
+            Self::VerifyFailed(_) => write!(f, "verify header failed"),
+        }
+    }
+}
+
+impl std::error::Error for RenderError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::WriteFailed(err) => Some(err),
+            Self::VerifyFailed(err) => Some(err),
+        }
+    }
+}
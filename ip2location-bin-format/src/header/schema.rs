@@ -68,14 +68,18 @@ impl Schema {
         //
         if !self.has_v6() {
             if self.v6_index_position_start != 1 {
-                return Err(VerifyError::Other(
-                    "v6_index_position_start should eq 1 when v6_records_count is 0".into(),
+                return Err(VerifyError::XPositionStartInvalid(
+                    "v6_index_position_start",
+                    self.v6_index_position_start,
+                    1,
                 ));
             }
 
             if self.v6_records_position_start != 1 {
-                return Err(VerifyError::Other(
-                    "v6_records_position_start should eq 1 when v6_records_count is 0".into(),
+                return Err(VerifyError::XPositionStartInvalid(
+                    "v6_records_position_start",
+                    self.v6_records_position_start,
+                    1,
                 ));
             }
         }
@@ -86,11 +90,9 @@ impl Schema {
 
         if self.v4_index_position_start != cur_position {
             return Err(VerifyError::XPositionStartInvalid(
-                format!(
-                    "v4_index_position_start mismatch {} {}",
-                    self.v4_index_position_start, cur_position
-                )
-                .into(),
+                "v4_index_position_start",
+                self.v4_index_position_start,
+                cur_position,
             ));
         }
         cur_position += INDEX_LEN;
@@ -98,11 +100,9 @@ impl Schema {
         if self.has_v6() {
             if self.v6_index_position_start != cur_position {
                 return Err(VerifyError::XPositionStartInvalid(
-                    format!(
-                        "v6_index_position_start mismatch {} {}",
-                        self.v6_index_position_start, cur_position
-                    )
-                    .into(),
+                    "v6_index_position_start",
+                    self.v6_index_position_start,
+                    cur_position,
                 ));
             }
             cur_position += INDEX_LEN;
@@ -110,11 +110,9 @@ impl Schema {
 
         if self.v4_records_position_start != cur_position {
             return Err(VerifyError::XPositionStartInvalid(
-                format!(
-                    "v4_records_position_start mismatch {} {}",
-                    self.v4_records_position_start, cur_position
-                )
-                .into(),
+                "v4_records_position_start",
+                self.v4_records_position_start,
+                cur_position,
             ));
         }
         cur_position += record_fields.records_bytes_len_for_ipv4(self.v4_records_count);
@@ -122,11 +120,9 @@ impl Schema {
         if self.has_v6() {
             if self.v6_records_position_start != cur_position {
                 return Err(VerifyError::XPositionStartInvalid(
-                    format!(
-                        "v6_records_position_start mismatch {} {}",
-                        self.v6_records_position_start, cur_position
-                    )
-                    .into(),
+                    "v6_records_position_start",
+                    self.v6_records_position_start,
+                    cur_position,
                 ));
             }
             cur_position += record_fields.records_bytes_len_for_ipv6(self.v6_records_count);
@@ -145,14 +141,25 @@ impl Schema {
 pub enum VerifyError {
     SubTypeInvalid(SchemaSubType),
     NumRecordFieldsMismatch(u8),
-    XPositionStartInvalid(Box<str>),
+    /// The name of the position, the value in the header and the expected value.
+    XPositionStartInvalid(&'static str, u32, u32),
     TotalSizeTooSmall(u32),
-    Other(Box<str>),
 }
 
 impl core::fmt::Display for VerifyError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{self:?}")
+        match self {
+            Self::SubTypeInvalid(sub_type) => write!(f, "sub type {} is invalid", sub_type.0),
+            Self::NumRecordFieldsMismatch(n) => {
+                write!(f, "num record fields {n} mismatches the sub type")
+            }
+            Self::XPositionStartInvalid(name, value, expected) => {
+                write!(f, "{name} is {value}, expected {expected}")
+            }
+            Self::TotalSizeTooSmall(total_size) => {
+                write!(f, "total size {total_size} is smaller than the records")
+            }
+        }
     }
 }
 
@@ -168,19 +175,14 @@ pub struct SchemaSubType(pub u8);
 //
 //
 #[repr(u8)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub enum SchemaType {
+    #[default]
     None,
     IP2Location,
     IP2Proxy,
 }
 
-impl Default for SchemaType {
-    fn default() -> Self {
-        Self::None
-    }
-}
-
 impl TryFrom<u8> for SchemaType {
     type Error = ();
 
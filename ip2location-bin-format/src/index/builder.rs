@@ -1 +1,130 @@
+use std::net::{Ipv4Addr, Ipv6Addr};
 
+use crate::index::{INDEX_ELEMENT_LEN, INDEX_LEN};
+
+//
+const INDEX_PREFIXES_COUNT: u32 = INDEX_LEN / (INDEX_ELEMENT_LEN + INDEX_ELEMENT_LEN);
+
+//
+#[derive(Debug, Default)]
+pub struct V4Builder {
+    ip_froms: Vec<u32>,
+}
+
+impl V4Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// ip_from must be appended in ascending order, one per record.
+    pub fn append(&mut self, ip_from: Ipv4Addr) {
+        self.ip_froms.push(ip_from.into());
+    }
+
+    pub fn finish(self) -> Vec<u8> {
+        // https://github.com/ip2location/ip2proxy-rust/blob/5bdd3ef61c2e243c1b61eda1475ca23eab2b7240/src/db.rs#L190
+        build(
+            &self.ip_froms,
+            |prefix| prefix << 16,
+            |prefix| (prefix << 16) | 0xffff,
+        )
+    }
+}
+
+//
+#[derive(Debug, Default)]
+pub struct V6Builder {
+    ip_froms: Vec<u128>,
+}
+
+impl V6Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// ip_from must be appended in ascending order, one per record.
+    pub fn append(&mut self, ip_from: Ipv6Addr) {
+        self.ip_froms.push(ip_from.into());
+    }
+
+    pub fn finish(self) -> Vec<u8> {
+        // https://github.com/ip2location/ip2proxy-rust/blob/5bdd3ef61c2e243c1b61eda1475ca23eab2b7240/src/db.rs#L217-L218
+        build(
+            &self.ip_froms,
+            |prefix| (prefix as u128) << 112,
+            |prefix| ((prefix as u128) << 112) | (u128::MAX >> 16),
+        )
+    }
+}
+
+//
+fn build<T: Ord + Copy>(
+    ip_froms: &[T],
+    prefix_first: impl Fn(u32) -> T,
+    prefix_last: impl Fn(u32) -> T,
+) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(INDEX_LEN as usize);
+
+    for prefix in 0..INDEX_PREFIXES_COUNT {
+        // The record which contains the first / last ip of the prefix.
+        let start = ip_froms
+            .partition_point(|x| *x <= prefix_first(prefix))
+            .saturating_sub(1) as u32;
+        let end = ip_froms
+            .partition_point(|x| *x <= prefix_last(prefix))
+            .saturating_sub(1) as u32;
+
+        bytes.extend_from_slice(&start.to_ne_bytes());
+        bytes.extend_from_slice(&end.to_ne_bytes());
+    }
+
+    debug_assert_eq!(bytes.len(), INDEX_LEN as usize);
+
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::index::{V4Querier, V6Querier};
+
+    #[test]
+    fn test_finish() {
+        let mut builder = V4Builder::new();
+        for ip in [
+            Ipv4Addr::new(0, 0, 0, 0),
+            Ipv4Addr::new(1, 0, 0, 0),
+            Ipv4Addr::new(1, 0, 1, 0),
+            Ipv4Addr::new(1, 1, 0, 0),
+            Ipv4Addr::new(255, 255, 255, 255),
+        ] {
+            builder.append(ip);
+        }
+        let querier = V4Querier::from(builder.finish());
+
+        let position_range = querier.query(Ipv4Addr::new(0, 1, 2, 3));
+        assert_eq!((position_range.start, position_range.end), (0, 0));
+        let position_range = querier.query(Ipv4Addr::new(1, 0, 2, 3));
+        assert_eq!((position_range.start, position_range.end), (1, 2));
+        let position_range = querier.query(Ipv4Addr::new(1, 1, 2, 3));
+        assert_eq!((position_range.start, position_range.end), (3, 3));
+        let position_range = querier.query(Ipv4Addr::new(255, 255, 0, 0));
+        assert_eq!((position_range.start, position_range.end), (3, 4));
+
+        let mut builder = V6Builder::new();
+        for ip in [
+            Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0, 0),
+            Ipv6Addr::new(0x2001, 0x0db8, 0, 0, 0, 0, 0, 0),
+            Ipv6Addr::new(0x2001, 0x0db9, 0, 0, 0, 0, 0, 0),
+        ] {
+            builder.append(ip);
+        }
+        let querier = V6Querier::from(builder.finish());
+
+        let position_range = querier.query(Ipv6Addr::new(0x2001, 0x0db8, 1, 0, 0, 0, 0, 0));
+        assert_eq!((position_range.start, position_range.end), (0, 2));
+        let position_range = querier.query(Ipv6Addr::new(0xffff, 0, 0, 0, 0, 0, 0, 1));
+        assert_eq!((position_range.start, position_range.end), (2, 2));
+    }
+}
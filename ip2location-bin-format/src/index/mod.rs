@@ -8,6 +8,7 @@ pub const INDEX_ELEMENT_LEN: u32 = 4;
 pub mod builder;
 pub mod querier;
 
+pub use builder::{V4Builder, V6Builder};
 pub use querier::{V4Querier, V6Querier};
 
 //
@@ -42,7 +42,9 @@ pub enum BuildError {
 
 impl core::fmt::Display for BuildError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{self:?}")
+        match self {
+            Self::LenMismatch => write!(f, "index length mismatch"),
+        }
     }
 }
 
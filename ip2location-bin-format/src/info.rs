@@ -0,0 +1,136 @@
+#[cfg(feature = "chrono")]
+use chrono::NaiveDate;
+
+use crate::{header::Schema as HeaderSchema, record_field::RecordFields};
+
+//
+/// What is loaded, from the header of the BIN.
+#[derive(Debug, Clone)]
+pub struct DatabaseInfo {
+    pub product: Product,
+    /// The N of DBN or PXN.
+    pub sub_type: u8,
+    /// The release date as (year, month, day), e.g. of the monthly LITE update.
+    pub date: (u16, u8, u8),
+    /// The [`date`](Self::date), `None` if it is invalid, the header parser rejects it with the `chrono` feature.
+    #[cfg(feature = "chrono")]
+    pub release_date: Option<NaiveDate>,
+    /// The IPV6 BIN, which covers ipv4 too.
+    pub has_ipv6: bool,
+    /// Including the last record of each ip version, which only ends the previous range.
+    pub v4_records_count: u32,
+    pub v6_records_count: u32,
+    pub record_fields: RecordFields,
+    /// Whether a LITE edition of the DBN or PXN is published, not the edition of this BIN.
+    ///
+    /// The LITE and commercial BINs have the same header, so the edition of the file cannot be determined.
+    pub lite_edition_exists: bool,
+    pub license_code: u8,
+}
+
+impl DatabaseInfo {
+    /// `None` if the record fields of the header are invalid, the header parser rejects them.
+    pub fn from_header(header: &HeaderSchema) -> Option<Self> {
+        let product = if header.r#type.is_ip2proxy() {
+            Product::IP2Proxy
+        } else {
+            Product::IP2Location
+        };
+
+        Some(Self {
+            product,
+            sub_type: header.sub_type.0,
+            date: (2000 + header.date.0 as u16, header.date.1, header.date.2),
+            #[cfg(feature = "chrono")]
+            release_date: NaiveDate::from_ymd_opt(
+                2000 + header.date.0 as i32,
+                header.date.1 as u32,
+                header.date.2 as u32,
+            ),
+            has_ipv6: header.has_v6(),
+            v4_records_count: header.v4_records_count,
+            v6_records_count: header.v6_records_count,
+            record_fields: header.record_fields()?,
+            lite_edition_exists: product.lite_edition_exists(header.sub_type.0),
+            license_code: header.license_code,
+        })
+    }
+
+    /// e.g. `DB11` or `PX11`.
+    pub fn code(&self) -> String {
+        match self.product {
+            Product::IP2Location => format!("DB{}", self.sub_type),
+            Product::IP2Proxy => format!("PX{}", self.sub_type),
+        }
+    }
+}
+
+//
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Product {
+    IP2Location,
+    IP2Proxy,
+}
+
+impl Product {
+    // https://lite.ip2location.com/database and https://lite.ip2location.com/ip2proxy-lite
+    fn lite_edition_exists(&self, sub_type: u8) -> bool {
+        match self {
+            Self::IP2Location => matches!(sub_type, 1 | 3 | 5 | 9 | 11),
+            Self::IP2Proxy => (1..=12).contains(&sub_type),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{
+        header::schema::{SchemaSubType, SchemaType},
+        querier::Querier,
+        record_field::RecordField,
+        test_helper::{bin_bytes, MOUNTAIN_VIEW},
+    };
+
+    #[tokio::test]
+    async fn test_from_header() -> Result<(), Box<dyn std::error::Error>> {
+        let q = Querier::from_bytes(bin_bytes(
+            SchemaType::IP2Location,
+            26,
+            &[("8.8.8.0", "8.8.8.255", &MOUNTAIN_VIEW)],
+        )?)
+        .await?;
+        let header = q.header;
+
+        let info = q.info();
+        assert_eq!(info.product, Product::IP2Location);
+        assert_eq!(info.code(), "DB26");
+        assert_eq!(info.date, (2022, 11, 1));
+        #[cfg(feature = "chrono")]
+        assert_eq!(info.release_date, NaiveDate::from_ymd_opt(2022, 11, 1));
+        assert!(!info.has_ipv6);
+        assert!(info.record_fields.contains(&RecordField::AS));
+        assert!(!info.lite_edition_exists);
+
+        let info = DatabaseInfo::from_header(&HeaderSchema {
+            r#type: SchemaType::IP2Proxy,
+            sub_type: SchemaSubType(11),
+            ..header
+        })
+        .unwrap();
+        assert_eq!(info.code(), "PX11");
+        assert!(info.lite_edition_exists);
+
+        let info = DatabaseInfo::from_header(&HeaderSchema {
+            date: (22, 2, 30),
+            ..header
+        })
+        .unwrap();
+        assert_eq!(info.date, (2022, 2, 30));
+        #[cfg(feature = "chrono")]
+        assert_eq!(info.release_date, None);
+
+        Ok(())
+    }
+}
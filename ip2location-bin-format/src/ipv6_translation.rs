@@ -0,0 +1,175 @@
+//! The IPv6 addresses which embed an IPv4, they are looked up in the IPv4 records.
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+//
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Ipv6TranslationMode {
+    /// Only `::ffff:a.b.c.d` (and `::a.b.c.d`).
+    #[default]
+    Ipv4MappedOnly,
+    /// Also 6to4 (`2002::/16`) and Teredo (`2001:0::/32`), like the official libraries.
+    All,
+}
+
+//
+/// The translation which is applied to the IPv6 address of the lookup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ipv6Translation {
+    None,
+    Ipv4Mapped,
+    SixToFour,
+    Teredo,
+}
+
+impl Ipv6Translation {
+    pub fn is_none(&self) -> bool {
+        matches!(self, Self::None)
+    }
+}
+
+//
+pub fn translate(ip: Ipv6Addr, mode: Ipv6TranslationMode) -> Option<(Ipv4Addr, Ipv6Translation)> {
+    if let Some(ip) = ip.to_ipv4() {
+        return Some((ip, Ipv6Translation::Ipv4Mapped));
+    }
+
+    match mode {
+        Ipv6TranslationMode::Ipv4MappedOnly => None,
+        Ipv6TranslationMode::All => {
+            let segments = ip.segments();
+            match segments {
+                // 2002:AABB:CCDD::/48, the IPv4 is AA.BB.CC.DD
+                [0x2002, a, b, ..] => Some((
+                    Ipv4Addr::from((u32::from(a) << 16) | u32::from(b)),
+                    Ipv6Translation::SixToFour,
+                )),
+                // 2001:0:server:flags:port:client, the client IPv4 is obfuscated by XOR
+                [0x2001, 0, .., a, b] => Some((
+                    Ipv4Addr::from(!((u32::from(a) << 16) | u32::from(b))),
+                    Ipv6Translation::Teredo,
+                )),
+                _ => None,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::{net::IpAddr, sync::Arc};
+
+    use futures_util::{future, io::Cursor};
+
+    use crate::{
+        bytes_querier::RecordFieldContentRef,
+        header::schema::SchemaType,
+        querier::Querier,
+        record_field::RecordFieldContent,
+        test_helper::{bin_bytes, MOUNTAIN_VIEW, TOKYO},
+    };
+
+    #[test]
+    fn test_translate() {
+        for (ip, mode, expected) in [
+            (
+                "::ffff:8.8.8.8",
+                Ipv6TranslationMode::Ipv4MappedOnly,
+                Some(("8.8.8.8", Ipv6Translation::Ipv4Mapped)),
+            ),
+            ("2002:808:808::1", Ipv6TranslationMode::Ipv4MappedOnly, None),
+            (
+                "2002:808:808::1",
+                Ipv6TranslationMode::All,
+                Some(("8.8.8.8", Ipv6Translation::SixToFour)),
+            ),
+            (
+                "2001:0:4136:e378:8000:63bf:f7f7:f7f7",
+                Ipv6TranslationMode::All,
+                Some(("8.8.8.8", Ipv6Translation::Teredo)),
+            ),
+            ("2001:4860::8888", Ipv6TranslationMode::All, None),
+        ] {
+            assert_eq!(
+                translate(ip.parse().unwrap(), mode),
+                expected.map(|(ip, translation)| (ip.parse().unwrap(), translation)),
+                "{ip} {mode:?}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_lookup_ipv6_with_translation() -> Result<(), Box<dyn std::error::Error>> {
+        let bytes = bin_bytes(
+            SchemaType::IP2Location,
+            1,
+            &[
+                ("8.8.8.0", "8.8.8.255", &MOUNTAIN_VIEW),
+                ("2001::", "2002:ffff::", &TOKYO),
+            ],
+        )?;
+        let bytes: Arc<[u8]> = bytes.into();
+
+        let mut q = Querier::new(|| Box::pin(future::ok(Cursor::new(bytes.clone()))), 1).await?;
+        let mut q_bytes = Querier::from_bytes(bytes.clone()).await?;
+
+        let country_code =
+            |x: Option<(IpAddr, IpAddr, crate::record_field::RecordFieldContents)>| {
+                x.and_then(|(_, _, record_field_contents)| {
+                    record_field_contents.iter().find_map(|x| match x {
+                        RecordFieldContent::COUNTRY(_, v, _) => v.clone(),
+                        _ => None,
+                    })
+                })
+            };
+
+        for (ip, mode, country, translation) in [
+            (
+                "::ffff:8.8.8.8",
+                Ipv6TranslationMode::Ipv4MappedOnly,
+                "US",
+                Ipv6Translation::Ipv4Mapped,
+            ),
+            (
+                "2002:808:808::1",
+                Ipv6TranslationMode::Ipv4MappedOnly,
+                "JP",
+                Ipv6Translation::None,
+            ),
+            (
+                "2002:808:808::1",
+                Ipv6TranslationMode::All,
+                "US",
+                Ipv6Translation::SixToFour,
+            ),
+            (
+                "2001:0:4136:e378:8000:63bf:f7f7:f7f7",
+                Ipv6TranslationMode::All,
+                "US",
+                Ipv6Translation::Teredo,
+            ),
+        ] {
+            let ip: Ipv6Addr = ip.parse()?;
+            q.ipv6_translation_mode = mode;
+            q_bytes.ipv6_translation_mode = mode;
+
+            let (x, x_translation) = q.lookup_ipv6_with_translation(ip, None).await?;
+            assert_eq!(country_code(x).as_deref(), Some(country), "{ip} {mode:?}");
+            assert_eq!(x_translation, translation, "{ip} {mode:?}");
+
+            let (x, _) = q_bytes.lookup_ipv6_with_translation(ip, None).await?;
+            assert_eq!(country_code(x).as_deref(), Some(country), "{ip} {mode:?}");
+
+            let (_, _, x) = q_bytes.lookup_ref(ip.into())?.unwrap();
+            let x = x.iter().find_map(|x| match x {
+                Ok(RecordFieldContentRef::COUNTRY(v, _)) => v,
+                _ => None,
+            });
+            assert_eq!(x, Some(country), "{ip} {mode:?}");
+        }
+
+        Ok(())
+    }
+}
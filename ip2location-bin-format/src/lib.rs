@@ -1,11 +1,28 @@
 //
+#[cfg(feature = "blocking")]
+pub mod blocking;
+pub mod builder;
+pub mod bytes_querier;
 pub mod content;
+#[cfg(feature = "csv")]
+pub mod csv_format;
 pub mod header;
 pub mod index;
+pub mod info;
+pub mod ipv6_translation;
 pub mod querier;
+#[cfg(feature = "lru")]
+pub mod range_cache;
+pub mod read_at_querier;
 pub mod record_field;
 pub mod records;
+pub mod reloadable;
+pub mod validation;
+#[cfg(feature = "zip")]
+pub mod zip_archive;
 
+pub use builder::Builder;
+pub use bytes_querier::Bytes;
 pub use record_field::{RecordField, RecordFields};
 
 //
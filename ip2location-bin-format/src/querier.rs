@@ -1,14 +1,28 @@
 use core::{cmp::max, future::Future, ops::ControlFlow, pin::Pin};
 use std::{
+    collections::BTreeMap,
     io::{Cursor, Error as IoError, SeekFrom},
     net::{IpAddr, Ipv4Addr, Ipv6Addr},
+    sync::Arc,
 };
 
 use deadpool::unmanaged::{Pool, PoolError};
-use futures_util::{AsyncRead, AsyncReadExt as _, AsyncSeek, AsyncSeekExt as _};
+use futures_util::{
+    future,
+    io::{AllowStdIo, Cursor as AsyncCursor},
+    stream, AsyncRead, AsyncReadExt as _, AsyncSeek, AsyncSeekExt as _, Stream,
+};
 
+#[cfg(feature = "lru")]
+use crate::range_cache::{RangeCache, RangeCacheStats};
 use crate::{
-    content::{querier::FillError as ContentFillError, Querier as ContentQuerier},
+    bytes_querier::{
+        Bytes, BytesQuerier, NewError as BytesQuerierNewError, RecordFieldContentsRef,
+    },
+    content::{
+        querier::FillError as ContentFillError, ContentCacheConfig, ContentCachePolicy,
+        Querier as ContentQuerier,
+    },
     header::{
         parser::ParseError as HeaderParseError, Parser as HeaderParser, Schema as HeaderSchema,
         HEADER_LEN,
@@ -17,6 +31,9 @@ use crate::{
         querier::BuildError as IndexBuildError, V4Querier as IndexV4Querier,
         V6Querier as IndexV6Querier, INDEX_LEN,
     },
+    info::DatabaseInfo,
+    ipv6_translation::{translate as ipv6_translate, Ipv6Translation, Ipv6TranslationMode},
+    read_at_querier::{NewError as ReadAtQuerierNewError, ReadAtQuerier},
     record_field::{RecordField, RecordFieldContents},
     records::{
         querier::v4_querier::NewError as RecordsV4QuerierNewError,
@@ -24,16 +41,27 @@ use crate::{
         querier::Error as RecordsQueryError, V4Querier as RecordsV4Querier,
         V6Querier as RecordsV6Querier,
     },
+    validation::{validate, ValidateError, ValidationFinding},
 };
 
 //
 pub struct Querier<S> {
     pub header: HeaderSchema,
+    /// Built from the header at open time.
+    pub info: DatabaseInfo,
     pub index_v4: IndexV4Querier,
     pub index_v6: Option<IndexV6Querier>,
     pub records_v4_pool: Pool<RecordsV4Querier<S>>,
     pub records_v6_pool: Option<Pool<RecordsV6Querier<S>>>,
     pub content_pool: Pool<ContentQuerier<S>>,
+    /// The lookups use it instead of the pools, see [`Querier::from_bytes`].
+    pub bytes_querier: Option<BytesQuerier>,
+    pub read_at_querier: Option<ReadAtQuerier>,
+    /// The IPv6 addresses which embed an IPv4 are looked up in the IPv4 records, default is [`Ipv6TranslationMode::Ipv4MappedOnly`].
+    pub ipv6_translation_mode: Ipv6TranslationMode,
+    /// The lookups check it before the searches, see [`ContentCacheConfig::range_cache_capacity`].
+    #[cfg(feature = "lru")]
+    pub range_cache: Option<RangeCache>,
 }
 
 impl<S> core::fmt::Debug for Querier<S>
@@ -43,14 +71,40 @@ where
     Pool<ContentQuerier<S>>: core::fmt::Debug,
 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        f.debug_struct("Querier")
-            .field("header", &self.header)
+        let mut f = f.debug_struct("Querier");
+        f.field("header", &self.header)
+            .field("info", &self.info)
             .field("index_v4", &self.index_v4)
             .field("index_v6", &self.index_v6)
             .field("records_v4_pool", &self.records_v4_pool)
             .field("records_v6_pool", &self.records_v6_pool)
             .field("content_pool", &self.content_pool)
-            .finish()
+            .field("bytes_querier", &self.bytes_querier)
+            .field("read_at_querier", &self.read_at_querier)
+            .field("ipv6_translation_mode", &self.ipv6_translation_mode);
+        #[cfg(feature = "lru")]
+        f.field("range_cache", &self.range_cache);
+        f.finish()
+    }
+}
+
+impl<S> Querier<S> {
+    /// The product, DBN or PXN, release date, coverage and record fields of the BIN.
+    pub fn info(&self) -> &DatabaseInfo {
+        &self.info
+    }
+
+    /// Like [`ContentCacheConfig::range_cache_capacity`], e.g. for [`from_bytes`](Querier::from_bytes).
+    #[cfg(feature = "lru")]
+    pub fn with_range_cache(mut self, capacity: core::num::NonZeroUsize) -> Self {
+        self.range_cache = Some(RangeCache::new(capacity));
+        self
+    }
+
+    /// None if there is no range cache.
+    #[cfg(feature = "lru")]
+    pub fn range_cache_stats(&self) -> Option<RangeCacheStats> {
+        self.range_cache.as_ref().map(RangeCache::stats)
     }
 }
 
@@ -61,7 +115,24 @@ impl<S> Querier<S>
 where
     S: AsyncSeek + AsyncRead + Unpin,
 {
-    pub async fn new<F>(mut stream_repeater: F, pool_max_size: usize) -> Result<Self, NewError>
+    pub async fn new<F>(stream_repeater: F, pool_max_size: usize) -> Result<Self, NewError>
+    where
+        F: FnMut() -> Pin<Box<dyn Future<Output = Result<S, IoError>> + Send + 'static>>,
+    {
+        Self::new_with_content_cache_config(
+            stream_repeater,
+            pool_max_size,
+            ContentCacheConfig::default(),
+        )
+        .await
+    }
+
+    /// Like [`new`](Self::new), the pooled content queriers use the content_cache_config.
+    pub async fn new_with_content_cache_config<F>(
+        mut stream_repeater: F,
+        pool_max_size: usize,
+        content_cache_config: ContentCacheConfig,
+    ) -> Result<Self, NewError>
     where
         F: FnMut() -> Pin<Box<dyn Future<Output = Result<S, IoError>> + Send + 'static>>,
     {
@@ -252,6 +323,14 @@ where
             }
         };
 
+        let content_preloaded = if content_cache_config.preload {
+            Some(Arc::new(
+                Self::preload_content(&mut stream_repeater, header, &content_cache_config).await?,
+            ))
+        } else {
+            None
+        };
+
         let content_pool = {
             let mut pool_objs = vec![];
 
@@ -271,7 +350,11 @@ where
                 }
 
                 //
-                let pool_obj = ContentQuerier::new(stream);
+                let mut pool_obj =
+                    ContentQuerier::with_cache_config(stream, content_cache_config.clone());
+                if let Some(content_preloaded) = content_preloaded.as_ref() {
+                    pool_obj.set_preloaded(content_preloaded.clone());
+                }
 
                 pool_objs.push(pool_obj);
             }
@@ -282,13 +365,233 @@ where
         //
         Ok(Self {
             header,
+            info: DatabaseInfo::from_header(&header).ok_or(NewError::DatabaseInfoInvalid)?,
             index_v4,
             index_v6,
             records_v4_pool,
             records_v6_pool,
             content_pool,
+            bytes_querier: None,
+            read_at_querier: None,
+            ipv6_translation_mode: Default::default(),
+            #[cfg(feature = "lru")]
+            range_cache: content_cache_config
+                .range_cache_capacity
+                .map(RangeCache::new),
         })
     }
+
+    // All distinct strs of the cached fields, by the offset.
+    async fn preload_content<F>(
+        stream_repeater: &mut F,
+        header: HeaderSchema,
+        content_cache_config: &ContentCacheConfig,
+    ) -> Result<BTreeMap<u32, Box<str>>, NewError>
+    where
+        F: FnMut() -> Pin<Box<dyn Future<Output = Result<S, IoError>> + Send + 'static>>,
+    {
+        let selected_fields = content_cache_config
+            .policies
+            .iter()
+            .filter(|(_, policy)| **policy != ContentCachePolicy::None)
+            .map(|(record_field, _)| *record_field)
+            .collect::<Vec<_>>();
+
+        let mut content = ContentQuerier::with_cache_config(
+            stream_repeater().await.map_err(NewError::OpenFailed)?,
+            ContentCacheConfig {
+                policies: selected_fields
+                    .iter()
+                    .map(|record_field| (*record_field, ContentCachePolicy::Static))
+                    .collect(),
+                preload: false,
+                ..content_cache_config.clone()
+            },
+        );
+
+        let mut records_v4 = RecordsV4Querier::new(
+            stream_repeater().await.map_err(NewError::OpenFailed)?,
+            header,
+        )
+        .map_err(NewError::RecordsV4QuerierNewFailed)?;
+        let mut position = 0;
+        while let Some((_, _, mut record_field_contents)) = records_v4
+            .read(position)
+            .await
+            .map_err(NewError::ContentPreloadReadFailed)?
+        {
+            record_field_contents.select(&selected_fields);
+            content
+                .fill(&mut record_field_contents)
+                .await
+                .map_err(NewError::ContentPreloadFillFailed)?;
+            position += 1;
+        }
+
+        if header.has_v6() {
+            let mut records_v6 = RecordsV6Querier::new(
+                stream_repeater().await.map_err(NewError::OpenFailed)?,
+                header,
+            )
+            .map_err(NewError::RecordsV6QuerierNewFailed)?;
+            let mut position = 0;
+            while let Some((_, _, mut record_field_contents)) = records_v6
+                .read(position)
+                .await
+                .map_err(NewError::ContentPreloadReadFailed)?
+            {
+                record_field_contents.select(&selected_fields);
+                content
+                    .fill(&mut record_field_contents)
+                    .await
+                    .map_err(NewError::ContentPreloadFillFailed)?;
+                position += 1;
+            }
+        }
+
+        Ok(content.into_static_cache())
+    }
+}
+
+impl Querier<AsyncCursor<Bytes>> {
+    /// The whole BIN is in memory (or memory-mapped), the lookups have no seek and read, and no pool.
+    pub async fn from_bytes(bytes: impl Into<Bytes>) -> Result<Self, NewError> {
+        let bytes = bytes.into();
+
+        let mut this = {
+            let bytes = bytes.clone();
+            Self::new(
+                move || Box::pin(future::ok(AsyncCursor::new(bytes.clone()))),
+                1,
+            )
+            .await?
+        };
+
+        this.bytes_querier =
+            Some(BytesQuerier::new(bytes, this.header).map_err(NewError::BytesQuerierNewFailed)?);
+
+        Ok(this)
+    }
+
+    /// Like [`lookup`](Self::lookup), but the contents are borrowed from the bytes, see [`RecordFieldContentsRef`].
+    ///
+    /// It needs the [`bytes_querier`](Self::bytes_querier), it is set by [`from_bytes`](Self::from_bytes).
+    pub fn lookup_ref(
+        &self,
+        ip: IpAddr,
+    ) -> Result<Option<(IpAddr, IpAddr, RecordFieldContentsRef<'_>)>, LookupError> {
+        let bytes_querier = self
+            .bytes_querier
+            .as_ref()
+            .ok_or(LookupError::BytesQuerierMissing)?;
+
+        match ip {
+            IpAddr::V4(ip) => {
+                let position_range = self.index_v4.query(ip);
+
+                if position_range.end == 0 {
+                    return Ok(None);
+                }
+
+                bytes_querier
+                    .query_ipv4_ref(ip, position_range)
+                    .map_err(LookupError::RecordsQueryFailed)
+            }
+            IpAddr::V6(ip) => {
+                match ipv6_translate(ip, self.ipv6_translation_mode) {
+                    Some((ip, Ipv6Translation::Ipv4Mapped)) => {
+                        return self.lookup_ref(ip.into()).map(|x| {
+                            x.map(|(ip_from, ip_to, record_field_contents)| {
+                                (
+                                    to_ipv6_mapped(ip_from),
+                                    to_ipv6_mapped(ip_to),
+                                    record_field_contents,
+                                )
+                            })
+                        });
+                    }
+                    Some((ip, _)) => return self.lookup_ref(ip.into()),
+                    None => {}
+                }
+
+                let position_range = self
+                    .index_v6
+                    .as_ref()
+                    .map(|x| x.query(ip))
+                    .unwrap_or_default();
+
+                if position_range.end == 0 {
+                    return Ok(None);
+                }
+
+                bytes_querier
+                    .query_ipv6_ref(ip, position_range)
+                    .map_err(LookupError::RecordsQueryFailed)
+            }
+        }
+    }
+}
+
+fn to_ipv6_mapped(ip: IpAddr) -> IpAddr {
+    match ip {
+        IpAddr::V4(ip) => ip.to_ipv6_mapped().into(),
+        IpAddr::V6(ip) => ip.into(),
+    }
+}
+
+#[cfg(any(unix, windows))]
+impl Querier<AllowStdIo<std::fs::File>> {
+    /// The lookups are positional reads on one shared file handle, so they are concurrent without the pool.
+    ///
+    /// The reads are blocking, like the page faults of a memory map.
+    /// The pool (of size 1) is only for [`read_ipv4`](Self::read_ipv4), [`read_ipv6`](Self::read_ipv6) and [`ranges`](Self::ranges).
+    pub async fn open_read_at(path: impl AsRef<std::path::Path>) -> Result<Self, NewError> {
+        Self::open_read_at_with_content_cache_config(path, ContentCacheConfig::default()).await
+    }
+
+    /// Like [`open_read_at`](Self::open_read_at), the lookups use the content_cache_config.
+    ///
+    /// The preloaded strs are only for the lookups, not for the pool.
+    pub async fn open_read_at_with_content_cache_config(
+        path: impl AsRef<std::path::Path>,
+        content_cache_config: ContentCacheConfig,
+    ) -> Result<Self, NewError> {
+        let path = path.as_ref().to_owned();
+        let mut stream_repeater = move || -> Pin<
+            Box<dyn Future<Output = Result<AllowStdIo<std::fs::File>, IoError>> + Send + 'static>,
+        > {
+            Box::pin(future::ready(
+                std::fs::File::open(&path).map(AllowStdIo::new),
+            ))
+        };
+
+        let mut this = Self::new_with_content_cache_config(
+            &mut stream_repeater,
+            1,
+            ContentCacheConfig {
+                preload: false,
+                ..content_cache_config.clone()
+            },
+        )
+        .await?;
+
+        let file = stream_repeater()
+            .await
+            .map_err(NewError::OpenFailed)?
+            .into_inner();
+        let mut read_at_querier =
+            ReadAtQuerier::new(file, this.header, content_cache_config.clone())
+                .map_err(NewError::ReadAtQuerierNewFailed)?;
+        if content_cache_config.preload {
+            read_at_querier.set_preloaded(Arc::new(
+                Self::preload_content(&mut stream_repeater, this.header, &content_cache_config)
+                    .await?,
+            ));
+        }
+        this.read_at_querier = Some(read_at_querier);
+
+        Ok(this)
+    }
 }
 
 //
@@ -304,15 +607,64 @@ pub enum NewError {
     IndexV6BuildFailed(IndexBuildError),
     RecordsV4QuerierNewFailed(RecordsV4QuerierNewError),
     RecordsV6QuerierNewFailed(RecordsV6QuerierNewError),
+    BytesQuerierNewFailed(BytesQuerierNewError),
+    ReadAtQuerierNewFailed(ReadAtQuerierNewError),
+    ContentPreloadReadFailed(RecordsQueryError),
+    ContentPreloadFillFailed(ContentFillError),
+    DatabaseInfoInvalid,
 }
 
 impl core::fmt::Display for NewError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{self:?}")
+        match self {
+            Self::OpenFailed(_) => write!(f, "open BIN failed"),
+            Self::SeekFailed(_) => write!(f, "seek BIN failed"),
+            Self::ReadFailed(_) => write!(f, "read BIN failed"),
+            Self::ReadOtherError(s) => write!(f, "read BIN failed, {s}"),
+            Self::HeaderParseFailed(_) => write!(f, "parse header failed"),
+            Self::TotalSizeMissing => {
+                write!(f, "the BIN is shorter than the total size of the header")
+            }
+            Self::IndexV4BuildFailed(_) => write!(f, "build ipv4 index failed"),
+            Self::IndexV6BuildFailed(_) => write!(f, "build ipv6 index failed"),
+            Self::RecordsV4QuerierNewFailed(_) => write!(f, "create ipv4 records querier failed"),
+            Self::RecordsV6QuerierNewFailed(_) => write!(f, "create ipv6 records querier failed"),
+            Self::BytesQuerierNewFailed(_) => write!(f, "create bytes querier failed"),
+            Self::ReadAtQuerierNewFailed(_) => write!(f, "create read_at querier failed"),
+            Self::ContentPreloadReadFailed(_) => {
+                write!(f, "read records for content preload failed")
+            }
+            Self::ContentPreloadFillFailed(_) => {
+                write!(f, "fill content for content preload failed")
+            }
+            Self::DatabaseInfoInvalid => {
+                write!(f, "the record fields of the header are invalid")
+            }
+        }
     }
 }
 
-impl std::error::Error for NewError {}
+impl std::error::Error for NewError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::OpenFailed(err) | Self::SeekFailed(err) | Self::ReadFailed(err) => Some(err),
+            Self::HeaderParseFailed(err) => Some(err),
+            Self::IndexV4BuildFailed(err) | Self::IndexV6BuildFailed(err) => Some(err),
+            Self::RecordsV4QuerierNewFailed(err) => Some(err),
+            Self::RecordsV6QuerierNewFailed(err) => Some(err),
+            Self::BytesQuerierNewFailed(err) => Some(err),
+            Self::ReadAtQuerierNewFailed(err) => Some(err),
+            Self::ContentPreloadReadFailed(err) => Some(err),
+            Self::ContentPreloadFillFailed(err) => Some(err),
+            Self::ReadOtherError(_) | Self::TotalSizeMissing | Self::DatabaseInfoInvalid => None,
+        }
+    }
+}
+
+//
+pub type LookupOutput = Option<(IpAddr, IpAddr, RecordFieldContents)>;
+
+pub type LookupIpv6WithTranslationOutput = (LookupOutput, Ipv6Translation);
 
 //
 //
@@ -321,6 +673,28 @@ impl<S> Querier<S>
 where
     S: AsyncSeek + AsyncRead + Unpin,
 {
+    /// See [`validate`](crate::validation::validate).
+    ///
+    /// Without the [`bytes_querier`](Self::bytes_querier) the whole BIN is read into memory through a pooled stream.
+    pub async fn validate(&self) -> Result<Vec<ValidationFinding>, ValidateError> {
+        if let Some(bytes_querier) = self.bytes_querier.as_ref() {
+            return validate(bytes_querier.bytes());
+        }
+
+        let mut content = self
+            .content_pool
+            .get()
+            .await
+            .map_err(ValidateError::PoolGetFailed)?;
+
+        let bytes = content
+            .read_all(self.header.total_size as usize)
+            .await
+            .map_err(ValidateError::ReadFailed)?;
+
+        validate(&bytes)
+    }
+
     pub async fn lookup(
         &self,
         ip: IpAddr,
@@ -337,12 +711,95 @@ where
         ip: Ipv4Addr,
         selected_fields: Option<&[RecordField]>,
     ) -> Result<Option<(IpAddr, IpAddr, RecordFieldContents)>, LookupError> {
+        self.lookup_records(ip.into(), selected_fields).await
+    }
+
+    async fn lookup_records(
+        &self,
+        ip: IpAddr,
+        selected_fields: Option<&[RecordField]>,
+    ) -> Result<LookupOutput, LookupError> {
+        #[cfg(feature = "lru")]
+        if let Some(range_cache) = self.range_cache.as_ref() {
+            let x = match range_cache.get(ip) {
+                Some(x) => Some(x),
+                None => {
+                    let x = match ip {
+                        IpAddr::V4(ip) => self.lookup_ipv4_records(ip, None).await?,
+                        IpAddr::V6(ip) => self.lookup_ipv6_records(ip, None).await?,
+                    };
+                    if let Some((ip_from, ip_to, record_field_contents)) = x.as_ref() {
+                        range_cache.put(*ip_from, *ip_to, record_field_contents.clone());
+                    }
+                    x
+                }
+            };
+
+            return Ok(x.map(|(ip_from, ip_to, mut record_field_contents)| {
+                if let Some(selected_fields) = selected_fields {
+                    record_field_contents.select(selected_fields);
+                }
+                (ip_from, ip_to, record_field_contents)
+            }));
+        }
+
+        match ip {
+            IpAddr::V4(ip) => self.lookup_ipv4_records(ip, selected_fields).await,
+            IpAddr::V6(ip) => self.lookup_ipv6_records(ip, selected_fields).await,
+        }
+    }
+
+    async fn lookup_ipv4_records(
+        &self,
+        ip: Ipv4Addr,
+        selected_fields: Option<&[RecordField]>,
+    ) -> Result<LookupOutput, LookupError> {
         let position_range = self.index_v4.query(ip);
 
         if position_range.end == 0 {
             return Ok(None);
         }
 
+        if let Some(bytes_querier) = self.bytes_querier.as_ref() {
+            let (ip_from, ip_to, mut record_field_contents) = match bytes_querier
+                .query_ipv4(ip, position_range)
+                .map_err(LookupError::RecordsQueryFailed)?
+            {
+                Some(x) => x,
+                None => return Ok(None),
+            };
+
+            if let Some(selected_fields) = selected_fields {
+                record_field_contents.select(selected_fields);
+            }
+
+            bytes_querier
+                .fill(&mut record_field_contents)
+                .map_err(LookupError::ContentFillFailed)?;
+
+            return Ok(Some((ip_from, ip_to, record_field_contents)));
+        }
+
+        if let Some(read_at_querier) = self.read_at_querier.as_ref() {
+            let (ip_from, ip_to, mut record_field_contents) = match read_at_querier
+                .query_ipv4(ip, position_range)
+                .map_err(LookupError::RecordsQueryFailed)?
+            {
+                Some(x) => x,
+                None => return Ok(None),
+            };
+
+            if let Some(selected_fields) = selected_fields {
+                record_field_contents.select(selected_fields);
+            }
+
+            read_at_querier
+                .fill(&mut record_field_contents)
+                .map_err(LookupError::ContentFillFailed)?;
+
+            return Ok(Some((ip_from, ip_to, record_field_contents)));
+        }
+
         //
         let mut records_v4 = self
             .records_v4_pool
@@ -382,30 +839,193 @@ where
         ip: Ipv6Addr,
         selected_fields: Option<&[RecordField]>,
     ) -> Result<Option<(IpAddr, IpAddr, RecordFieldContents)>, LookupError> {
-        if let Some(ip) = ip.to_ipv4() {
-            return self.lookup_ipv4(ip, selected_fields).await.map(|x| {
-                x.map(|(ip_from, ip_to, record_field_contents)| {
-                    (
-                        match ip_from {
-                            IpAddr::V4(ip) => ip.to_ipv6_mapped().into(),
-                            IpAddr::V6(ip) => {
-                                debug_assert!(false, "unreachable");
-                                ip.into()
-                            }
-                        },
-                        match ip_to {
-                            IpAddr::V4(ip) => ip.to_ipv6_mapped().into(),
-                            IpAddr::V6(ip) => {
-                                debug_assert!(false, "unreachable");
-                                ip.into()
+        self.lookup_ipv6_with_translation(ip, selected_fields)
+            .await
+            .map(|(x, _)| x)
+    }
+
+    /// Like [`lookup_ipv6`](Self::lookup_ipv6), and returns the translation applied by [`ipv6_translation_mode`](Self::ipv6_translation_mode).
+    ///
+    /// With [`Ipv6Translation::Ipv4Mapped`] the range is IPv4-mapped, with [`Ipv6Translation::SixToFour`] and [`Ipv6Translation::Teredo`] the range is the IPv4 one.
+    pub async fn lookup_ipv6_with_translation(
+        &self,
+        ip: Ipv6Addr,
+        selected_fields: Option<&[RecordField]>,
+    ) -> Result<LookupIpv6WithTranslationOutput, LookupError> {
+        match ipv6_translate(ip, self.ipv6_translation_mode) {
+            Some((ip, translation @ Ipv6Translation::Ipv4Mapped)) => {
+                let x = self.lookup_ipv4(ip, selected_fields).await?;
+                Ok((
+                    x.map(|(ip_from, ip_to, record_field_contents)| {
+                        (
+                            to_ipv6_mapped(ip_from),
+                            to_ipv6_mapped(ip_to),
+                            record_field_contents,
+                        )
+                    }),
+                    translation,
+                ))
+            }
+            Some((ip, translation)) => {
+                Ok((self.lookup_ipv4(ip, selected_fields).await?, translation))
+            }
+            None => Ok((
+                self.lookup_records(ip.into(), selected_fields).await?,
+                Ipv6Translation::None,
+            )),
+        }
+    }
+
+    /// Looks up the ips, the results are in the same order as the ips.
+    ///
+    /// The ips are sorted, the pooled queriers are got once for the batch,
+    /// and an ip in the range of the previous result skips the search.
+    /// With the range cache, the other ips are looked up one by one through it.
+    pub async fn lookup_batch(
+        &self,
+        ips: &[IpAddr],
+        selected_fields: Option<&[RecordField]>,
+    ) -> Result<Vec<LookupOutput>, IpLookupError> {
+        let mut keys = ips
+            .iter()
+            .enumerate()
+            .map(|(i, ip)| match ip {
+                IpAddr::V4(ip) => (IpAddr::V4(*ip), Ipv6Translation::None, i),
+                IpAddr::V6(ip) => match ipv6_translate(*ip, self.ipv6_translation_mode) {
+                    Some((ip, translation)) => (IpAddr::V4(ip), translation, i),
+                    None => (IpAddr::V6(*ip), Ipv6Translation::None, i),
+                },
+            })
+            .collect::<Vec<_>>();
+        keys.sort_unstable_by_key(|(ip, _, _)| *ip);
+
+        // Without the pools, or with the range cache, it is per ip.
+        let is_per_ip = self.bytes_querier.is_some() || self.read_at_querier.is_some();
+        #[cfg(feature = "lru")]
+        let is_per_ip = is_per_ip || self.range_cache.is_some();
+
+        let mut records_v4 = None;
+        let mut records_v6 = None;
+        let mut content = None;
+
+        let mut previous: Option<(IpAddr, IpAddr, RecordFieldContents)> = None;
+        let mut results = vec![None; ips.len()];
+
+        for (ip, translation, i) in keys {
+            let x = async {
+                Ok(
+                    match previous
+                        .as_ref()
+                        .filter(|(ip_from, ip_to, _)| ip >= *ip_from && ip < *ip_to)
+                    {
+                        Some(x) => Some(x.clone()),
+                        None if is_per_ip => self.lookup_records(ip, selected_fields).await?,
+                        None => {
+                            let mut x = match ip {
+                                IpAddr::V4(ip) => {
+                                    let position_range = self.index_v4.query(ip);
+
+                                    if position_range.end == 0 {
+                                        None
+                                    } else {
+                                        let records_v4 = match records_v4.as_mut() {
+                                            Some(x) => x,
+                                            None => records_v4.insert(
+                                                self.records_v4_pool
+                                                    .get()
+                                                    .await
+                                                    .map_err(LookupError::PoolGetFailed)?,
+                                            ),
+                                        };
+
+                                        records_v4
+                                            .query(ip, position_range)
+                                            .await
+                                            .map_err(LookupError::RecordsQueryFailed)?
+                                    }
+                                }
+                                IpAddr::V6(ip) => {
+                                    let position_range = self
+                                        .index_v6
+                                        .as_ref()
+                                        .map(|x| x.query(ip))
+                                        .unwrap_or_default();
+
+                                    match self.records_v6_pool.as_ref() {
+                                        Some(records_v6_pool) if position_range.end != 0 => {
+                                            let records_v6 = match records_v6.as_mut() {
+                                                Some(x) => x,
+                                                None => records_v6.insert(
+                                                    records_v6_pool
+                                                        .get()
+                                                        .await
+                                                        .map_err(LookupError::PoolGetFailed)?,
+                                                ),
+                                            };
+
+                                            records_v6
+                                                .query(ip, position_range)
+                                                .await
+                                                .map_err(LookupError::RecordsQueryFailed)?
+                                        }
+                                        _ => None,
+                                    }
+                                }
+                            };
+
+                            if let Some((_, _, record_field_contents)) = x.as_mut() {
+                                if let Some(selected_fields) = selected_fields {
+                                    record_field_contents.select(selected_fields);
+                                }
+
+                                let content = match content.as_mut() {
+                                    Some(x) => x,
+                                    None => content.insert(
+                                        self.content_pool
+                                            .get()
+                                            .await
+                                            .map_err(LookupError::PoolGetFailed)?,
+                                    ),
+                                };
+
+                                content
+                                    .fill(record_field_contents)
+                                    .await
+                                    .map_err(LookupError::ContentFillFailed)?;
                             }
-                        },
+
+                            x
+                        }
+                    },
+                )
+            }
+            .await
+            .map_err(|err| IpLookupError::LookupFailed(ips[i], err))?;
+
+            if x.is_some() {
+                previous = x.clone();
+            }
+
+            results[i] = match translation {
+                Ipv6Translation::Ipv4Mapped => x.map(|(ip_from, ip_to, record_field_contents)| {
+                    (
+                        to_ipv6_mapped(ip_from),
+                        to_ipv6_mapped(ip_to),
                         record_field_contents,
                     )
-                })
-            });
+                }),
+                _ => x,
+            };
         }
 
+        Ok(results)
+    }
+
+    async fn lookup_ipv6_records(
+        &self,
+        ip: Ipv6Addr,
+        selected_fields: Option<&[RecordField]>,
+    ) -> Result<Option<(IpAddr, IpAddr, RecordFieldContents)>, LookupError> {
         let position_range = self
             .index_v6
             .as_ref()
@@ -416,6 +1036,46 @@ where
             return Ok(None);
         }
 
+        if let Some(bytes_querier) = self.bytes_querier.as_ref() {
+            let (ip_from, ip_to, mut record_field_contents) = match bytes_querier
+                .query_ipv6(ip, position_range)
+                .map_err(LookupError::RecordsQueryFailed)?
+            {
+                Some(x) => x,
+                None => return Ok(None),
+            };
+
+            if let Some(selected_fields) = selected_fields {
+                record_field_contents.select(selected_fields);
+            }
+
+            bytes_querier
+                .fill(&mut record_field_contents)
+                .map_err(LookupError::ContentFillFailed)?;
+
+            return Ok(Some((ip_from, ip_to, record_field_contents)));
+        }
+
+        if let Some(read_at_querier) = self.read_at_querier.as_ref() {
+            let (ip_from, ip_to, mut record_field_contents) = match read_at_querier
+                .query_ipv6(ip, position_range)
+                .map_err(LookupError::RecordsQueryFailed)?
+            {
+                Some(x) => x,
+                None => return Ok(None),
+            };
+
+            if let Some(selected_fields) = selected_fields {
+                record_field_contents.select(selected_fields);
+            }
+
+            read_at_querier
+                .fill(&mut record_field_contents)
+                .map_err(LookupError::ContentFillFailed)?;
+
+            return Ok(Some((ip_from, ip_to, record_field_contents)));
+        }
+
         let (ip_from, ip_to, mut record_field_contents) = match self.records_v6_pool.as_ref() {
             Some(records_v6_pool) => {
                 //
@@ -456,21 +1116,263 @@ where
     }
 }
 
+//
+//
+//
+impl<S> Querier<S>
+where
+    S: AsyncSeek + AsyncRead + Unpin,
+{
+    /// Reads the ipv4 record at position (`0..header.v4_records_count`), in the order of the records.
+    ///
+    /// ip_to is the ip_from of the next record (exclusive), like [`lookup`](Self::lookup).
+    pub async fn read_ipv4(
+        &self,
+        position: u32,
+        selected_fields: Option<&[RecordField]>,
+    ) -> Result<Option<(IpAddr, IpAddr, RecordFieldContents)>, LookupError> {
+        //
+        let mut records_v4 = self
+            .records_v4_pool
+            .get()
+            .await
+            .map_err(LookupError::PoolGetFailed)?;
+        let (ip_from, ip_to, mut record_field_contents) = match records_v4
+            .read(position)
+            .await
+            .map_err(LookupError::RecordsQueryFailed)?
+        {
+            Some(x) => x,
+            None => return Ok(None),
+        };
+
+        self.fill(&mut record_field_contents, selected_fields)
+            .await?;
+
+        Ok(Some((ip_from, ip_to, record_field_contents)))
+    }
+
+    /// Reads the ipv6 record at position (`0..header.v6_records_count`), in the order of the records.
+    ///
+    /// ip_to is the ip_from of the next record (exclusive), like [`lookup`](Self::lookup).
+    pub async fn read_ipv6(
+        &self,
+        position: u32,
+        selected_fields: Option<&[RecordField]>,
+    ) -> Result<Option<(IpAddr, IpAddr, RecordFieldContents)>, LookupError> {
+        let (ip_from, ip_to, mut record_field_contents) = match self.records_v6_pool.as_ref() {
+            Some(records_v6_pool) => {
+                //
+                let mut records_v6 = records_v6_pool
+                    .get()
+                    .await
+                    .map_err(LookupError::PoolGetFailed)?;
+
+                match records_v6
+                    .read(position)
+                    .await
+                    .map_err(LookupError::RecordsQueryFailed)?
+                {
+                    Some(x) => x,
+                    None => return Ok(None),
+                }
+            }
+            None => return Ok(None),
+        };
+
+        self.fill(&mut record_field_contents, selected_fields)
+            .await?;
+
+        Ok(Some((ip_from, ip_to, record_field_contents)))
+    }
+
+    /// All ranges of the ipv4 records and then the ipv6 records, ip_to is exclusive like [`lookup`](Self::lookup).
+    ///
+    /// start and end restrict the ranges to the ones which contain or are between them,
+    /// e.g. `Some(Ipv4Addr::UNSPECIFIED.into()), Some(Ipv4Addr::BROADCAST.into())` is the ipv4 records only.
+    ///
+    /// The ip of the error is the ip_from of the range which failed, that is the ip_to of the previous range.
+    pub fn ranges(
+        &self,
+        start: Option<IpAddr>,
+        end: Option<IpAddr>,
+        selected_fields: Option<Vec<RecordField>>,
+    ) -> impl Stream<Item = Result<(IpAddr, IpAddr, RecordFieldContents), IpLookupError>> + '_ {
+        let position_v4 = match start {
+            Some(IpAddr::V4(ip)) => Some(self.index_v4.query(ip).start),
+            Some(IpAddr::V6(_)) => None,
+            None => Some(0),
+        };
+        let position_v6 = match (start, end) {
+            (_, Some(IpAddr::V4(_))) => None,
+            (Some(IpAddr::V6(ip)), _) => Some(
+                self.index_v6
+                    .as_ref()
+                    .map(|x| x.query(ip).start)
+                    .unwrap_or_default(),
+            ),
+            _ => Some(0),
+        };
+
+        // The ip_from of the first range which is read, before the ip_to of a previous range is known.
+        let ip_first = move |is_ipv6: bool| match start {
+            Some(start) if start.is_ipv6() == is_ipv6 => start,
+            _ if is_ipv6 => Ipv6Addr::UNSPECIFIED.into(),
+            _ => Ipv4Addr::UNSPECIFIED.into(),
+        };
+
+        stream::try_unfold(
+            (position_v4, position_v6, selected_fields, None::<IpAddr>),
+            move |(mut position_v4, mut position_v6, selected_fields, mut ip_next)| async move {
+                loop {
+                    let (ip_from, ip_to, record_field_contents) =
+                        if let Some(position) = position_v4 {
+                            match self
+                                .read_ipv4(position, selected_fields.as_deref())
+                                .await
+                                .map_err(|err| {
+                                    IpLookupError::LookupFailed(
+                                        ip_next
+                                            .filter(|x| x.is_ipv4())
+                                            .unwrap_or_else(|| ip_first(false)),
+                                        err,
+                                    )
+                                })? {
+                                Some(x) => {
+                                    position_v4 = Some(position + 1);
+                                    x
+                                }
+                                None => {
+                                    position_v4 = None;
+                                    continue;
+                                }
+                            }
+                        } else if let Some(position) = position_v6 {
+                            match self
+                                .read_ipv6(position, selected_fields.as_deref())
+                                .await
+                                .map_err(|err| {
+                                    IpLookupError::LookupFailed(
+                                        ip_next
+                                            .filter(|x| x.is_ipv6())
+                                            .unwrap_or_else(|| ip_first(true)),
+                                        err,
+                                    )
+                                })? {
+                                Some(x) => {
+                                    position_v6 = Some(position + 1);
+                                    x
+                                }
+                                None => return Ok(None),
+                            }
+                        } else {
+                            return Ok(None);
+                        };
+                    ip_next = Some(ip_to);
+
+                    match start {
+                        Some(start) if start.is_ipv4() == ip_to.is_ipv4() && ip_to <= start => {
+                            continue;
+                        }
+                        _ => {}
+                    }
+                    match end {
+                        Some(end) if end.is_ipv4() == ip_from.is_ipv4() && ip_from > end => {
+                            if ip_from.is_ipv4() {
+                                position_v4 = None;
+                                continue;
+                            } else {
+                                return Ok(None);
+                            }
+                        }
+                        _ => {}
+                    }
+
+                    return Ok(Some((
+                        (ip_from, ip_to, record_field_contents),
+                        (position_v4, position_v6, selected_fields, ip_next),
+                    )));
+                }
+            },
+        )
+    }
+
+    async fn fill(
+        &self,
+        record_field_contents: &mut RecordFieldContents,
+        selected_fields: Option<&[RecordField]>,
+    ) -> Result<(), LookupError> {
+        if let Some(selected_fields) = selected_fields {
+            record_field_contents.select(selected_fields);
+        }
+
+        //
+        let mut content = self
+            .content_pool
+            .get()
+            .await
+            .map_err(LookupError::PoolGetFailed)?;
+
+        content
+            .fill(record_field_contents)
+            .await
+            .map_err(LookupError::ContentFillFailed)
+    }
+}
+
 //
 #[derive(Debug)]
 pub enum LookupError {
     PoolGetFailed(PoolError),
     RecordsQueryFailed(RecordsQueryError),
     ContentFillFailed(ContentFillError),
+    BytesQuerierMissing,
 }
 
 impl core::fmt::Display for LookupError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{self:?}")
+        match self {
+            Self::PoolGetFailed(_) => write!(f, "get stream from pool failed"),
+            Self::RecordsQueryFailed(_) => write!(f, "query records failed"),
+            Self::ContentFillFailed(_) => write!(f, "fill content failed"),
+            Self::BytesQuerierMissing => write!(f, "the querier is not created from bytes"),
+        }
+    }
+}
+
+impl std::error::Error for LookupError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::PoolGetFailed(err) => Some(err),
+            Self::RecordsQueryFailed(err) => Some(err),
+            Self::ContentFillFailed(err) => Some(err),
+            Self::BytesQuerierMissing => None,
+        }
+    }
+}
+
+//
+/// The [`LookupError`] of an ip in [`lookup_batch`](Querier::lookup_batch) and [`ranges`](Querier::ranges).
+#[derive(Debug)]
+pub enum IpLookupError {
+    LookupFailed(IpAddr, LookupError),
+}
+
+impl core::fmt::Display for IpLookupError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::LookupFailed(ip, _) => write!(f, "lookup {ip} failed"),
+        }
     }
 }
 
-impl std::error::Error for LookupError {}
+impl std::error::Error for IpLookupError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::LookupFailed(_, err) => Some(err),
+        }
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -573,4 +1475,81 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_lookup_batch() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::{
+            header::schema::SchemaType,
+            test_helper::{bin_bytes, BRISBANE, MOUNTAIN_VIEW},
+        };
+        use futures_util::StreamExt as _;
+
+        let bytes = bin_bytes(
+            SchemaType::IP2Location,
+            1,
+            &[
+                ("1.0.0.0", "1.0.0.255", &BRISBANE),
+                ("8.8.8.0", "8.8.8.255", &MOUNTAIN_VIEW),
+                ("2001:4860::", "2001:4860::ffff", &MOUNTAIN_VIEW),
+            ],
+        )?;
+        let bytes: std::sync::Arc<[u8]> = bytes.into();
+
+        let ips = [
+            "8.8.8.8",
+            "2001:4860::8888",
+            "1.0.0.1",
+            "::ffff:8.8.8.9",
+            "8.8.8.8",
+            "1.0.0.2",
+            "9.9.9.9",
+            "2001:4860::8844",
+        ]
+        .iter()
+        .map(|x| x.parse())
+        .collect::<Result<Vec<IpAddr>, _>>()?;
+
+        let q = Querier::new(|| Box::pin(future::ok(AsyncCursor::new(bytes.clone()))), 1).await?;
+        let q_bytes = Querier::from_bytes(bytes.clone()).await?;
+
+        let expected = {
+            let mut v = vec![];
+            for ip in ips.iter() {
+                v.push(q.lookup(*ip, None).await?);
+            }
+            format!("{v:?}")
+        };
+        assert_eq!(format!("{:?}", q.lookup_batch(&ips, None).await?), expected);
+        assert_eq!(
+            format!("{:?}", q_bytes.lookup_batch(&ips, None).await?),
+            expected
+        );
+
+        // The COUNTRY offsets of the ipv4 records overflow.
+        let mut bytes_corrupted = bytes.to_vec();
+        let record_len = q
+            .header
+            .record_fields()
+            .unwrap()
+            .records_bytes_len_for_ipv4(1) as usize;
+        for position in 0..q.header.v4_records_count as usize {
+            let i = q.header.v4_records_seek_from_start() as usize + record_len * position + 4;
+            bytes_corrupted[i..i + 4].copy_from_slice(&u32::MAX.to_ne_bytes());
+        }
+        let q_corrupted = Querier::from_bytes(bytes_corrupted).await?;
+        match q_corrupted.lookup_batch(&ips, None).await {
+            Err(IpLookupError::LookupFailed(ip, LookupError::ContentFillFailed(_))) => {
+                assert_eq!(ip, "1.0.0.1".parse::<IpAddr>()?)
+            }
+            x => panic!("{x:?}"),
+        }
+        match Box::pin(q_corrupted.ranges(None, None, None)).next().await {
+            Some(Err(IpLookupError::LookupFailed(ip, LookupError::ContentFillFailed(_)))) => {
+                assert_eq!(ip, IpAddr::from(Ipv4Addr::UNSPECIFIED))
+            }
+            x => panic!("{x:?}"),
+        }
+
+        Ok(())
+    }
 }
@@ -0,0 +1,206 @@
+//! The lookup results cached by range, a hit skips the index and records searches.
+
+use core::num::NonZeroUsize;
+use std::{
+    collections::BTreeMap,
+    net::IpAddr,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex, MutexGuard,
+    },
+};
+
+use crate::record_field::RecordFieldContents;
+
+//
+pub struct RangeCache {
+    inner: Mutex<Inner>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+struct Inner {
+    // ip_from => ip_to, for the search of the range which contains the ip
+    ranges: BTreeMap<IpAddr, IpAddr>,
+    // ip_from => (ip_to, record_field_contents)
+    lru_cache: lru::LruCache<IpAddr, (IpAddr, RecordFieldContents)>,
+}
+
+impl core::fmt::Debug for RangeCache {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("RangeCache")
+            .field("stats", &self.stats())
+            .finish()
+    }
+}
+
+//
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RangeCacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub len: usize,
+    pub capacity: usize,
+}
+
+//
+impl RangeCache {
+    pub fn new(capacity: NonZeroUsize) -> Self {
+        Self {
+            inner: Mutex::new(Inner {
+                ranges: BTreeMap::new(),
+                lru_cache: lru::LruCache::new(capacity),
+            }),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    pub fn stats(&self) -> RangeCacheStats {
+        let inner = self.lock();
+        RangeCacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            len: inner.lru_cache.len(),
+            capacity: inner.lru_cache.cap().get(),
+        }
+    }
+
+    pub fn clear(&self) {
+        let mut inner = self.lock();
+        inner.ranges.clear();
+        inner.lru_cache.clear();
+    }
+
+    /// The contents are the all fields, the caller selects.
+    pub(crate) fn get(&self, ip: IpAddr) -> Option<(IpAddr, IpAddr, RecordFieldContents)> {
+        let mut inner = self.lock();
+
+        let ip_from = match inner.ranges.range(..=ip).next_back() {
+            Some((ip_from, ip_to)) if ip < *ip_to => Some(*ip_from),
+            _ => None,
+        };
+
+        match ip_from.and_then(|ip_from| {
+            inner
+                .lru_cache
+                .get(&ip_from)
+                .map(|(ip_to, record_field_contents)| {
+                    (ip_from, *ip_to, record_field_contents.clone())
+                })
+        }) {
+            Some(x) => {
+                self.hits.fetch_add(1, Ordering::Relaxed);
+                Some(x)
+            }
+            None => {
+                self.misses.fetch_add(1, Ordering::Relaxed);
+                None
+            }
+        }
+    }
+
+    pub(crate) fn put(
+        &self,
+        ip_from: IpAddr,
+        ip_to: IpAddr,
+        record_field_contents: RecordFieldContents,
+    ) {
+        let mut inner = self.lock();
+
+        if let Some((ip_from_evicted, _)) = inner
+            .lru_cache
+            .push(ip_from, (ip_to, record_field_contents))
+        {
+            if ip_from_evicted != ip_from {
+                inner.ranges.remove(&ip_from_evicted);
+            }
+        }
+        inner.ranges.insert(ip_from, ip_to);
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Inner> {
+        // At worst a range is missing after a panic, it is a miss.
+        self.inner.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::Arc;
+
+    use futures_util::{future, io::Cursor};
+
+    use crate::{
+        content::ContentCacheConfig,
+        header::schema::SchemaType,
+        querier::Querier,
+        record_field::RecordField,
+        test_helper::{bin_bytes, BRISBANE, MOUNTAIN_VIEW},
+    };
+
+    #[tokio::test]
+    async fn test_lookup() -> Result<(), Box<dyn std::error::Error>> {
+        let bytes = bin_bytes(
+            SchemaType::IP2Location,
+            3,
+            &[
+                ("1.0.0.0", "1.0.0.255", &BRISBANE),
+                ("8.8.8.0", "8.8.8.255", &MOUNTAIN_VIEW),
+            ],
+        )?;
+        let bytes: Arc<[u8]> = bytes.into();
+
+        let q = Querier::new(|| Box::pin(future::ok(Cursor::new(bytes.clone()))), 1).await?;
+        let q_cached = Querier::new_with_content_cache_config(
+            || Box::pin(future::ok(Cursor::new(bytes.clone()))),
+            1,
+            ContentCacheConfig::default().with_range_cache_capacity(NonZeroUsize::new(1).unwrap()),
+        )
+        .await?;
+        assert_eq!(q.range_cache_stats(), None);
+
+        for (ip, selected_fields) in [
+            ("8.8.8.8", None),
+            ("8.8.8.9", None),
+            ("8.8.8.10", Some(&[RecordField::CITY][..])),
+            ("1.0.0.1", None),
+            ("8.8.8.11", None),
+        ] {
+            let ip: IpAddr = ip.parse()?;
+            assert_eq!(
+                format!("{:?}", q_cached.lookup(ip, selected_fields).await?),
+                format!("{:?}", q.lookup(ip, selected_fields).await?),
+                "{ip}"
+            );
+        }
+
+        assert_eq!(
+            q_cached.range_cache_stats(),
+            Some(RangeCacheStats {
+                hits: 2,
+                misses: 3,
+                len: 1,
+                capacity: 1,
+            })
+        );
+        let range_cache = q_cached.range_cache.as_ref().unwrap();
+        range_cache.clear();
+        assert_eq!(range_cache.stats().len, 0);
+
+        //
+        let q_bytes = Querier::from_bytes(bytes.to_vec())
+            .await?
+            .with_range_cache(NonZeroUsize::new(2).unwrap());
+        q_bytes.lookup("8.8.8.8".parse()?, None).await?;
+        q_bytes.lookup("8.8.8.9".parse()?, None).await?;
+        assert_eq!(
+            q_bytes.range_cache_stats().map(|x| (x.hits, x.misses)),
+            Some((1, 1))
+        );
+
+        Ok(())
+    }
+}
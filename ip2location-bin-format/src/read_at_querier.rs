@@ -0,0 +1,384 @@
+use core::ops::ControlFlow;
+use std::{
+    collections::BTreeMap,
+    io::Error as IoError,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use crate::{
+    content::{
+        cache::{Cache, ContentCacheConfig},
+        querier::{country_name_offset, filter_str, str_mut, FillError as ContentFillError},
+    },
+    header::Schema as HeaderSchema,
+    record_field::{
+        RecordField, RecordFieldContent, RecordFieldContents, RecordFields,
+        RECORD_FIELD_LEN_WITHOUT_IP,
+    },
+    records::{
+        querier::{
+            record_bytes_len_with_double_ip, record_field_contents_from_bytes,
+            record_seek_from_start, Error as RecordsQueryError, Search,
+        },
+        PositionRange,
+    },
+};
+
+// The ipv6 record of all the fields, with the ip_from of the next record.
+const RECORD_BYTES_LEN_MAX: usize = 16 + 32 * RECORD_FIELD_LEN_WITHOUT_IP as usize + 16;
+
+//
+/// Positional read, like pread. It does not change the stream position, so one handle can be shared.
+pub trait ReadAt {
+    fn read_exact_at(&self, buf: &mut [u8], offset: u64) -> Result<(), IoError>;
+}
+
+#[cfg(unix)]
+impl ReadAt for std::fs::File {
+    fn read_exact_at(&self, buf: &mut [u8], offset: u64) -> Result<(), IoError> {
+        std::os::unix::fs::FileExt::read_exact_at(self, buf, offset)
+    }
+}
+
+#[cfg(windows)]
+impl ReadAt for std::fs::File {
+    fn read_exact_at(&self, mut buf: &mut [u8], mut offset: u64) -> Result<(), IoError> {
+        use std::io::ErrorKind as IoErrorKind;
+
+        // seek_read moves the file cursor, but the lookups never use it.
+        while !buf.is_empty() {
+            match std::os::windows::fs::FileExt::seek_read(self, buf, offset) {
+                Ok(0) => return Err(IoError::from(IoErrorKind::UnexpectedEof)),
+                Ok(n) => {
+                    buf = &mut buf[n..];
+                    offset += n as u64;
+                }
+                Err(err) if err.kind() == IoErrorKind::Interrupted => {}
+                Err(err) => return Err(err),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+//
+/// Queries the records and the content by [`ReadAt`] on one shared handle, without the pool.
+#[derive(Clone)]
+pub struct ReadAtQuerier {
+    stream: Arc<dyn ReadAt + Send + Sync>,
+    header: HeaderSchema,
+    record_fields: RecordFields,
+    record_field_contents: RecordFieldContents,
+    cache: Arc<Mutex<Cache>>,
+}
+
+impl core::fmt::Debug for ReadAtQuerier {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ReadAtQuerier")
+            .field("header", &self.header)
+            .field("record_fields", &self.record_fields)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ReadAtQuerier {
+    pub fn new(
+        stream: impl ReadAt + Send + Sync + 'static,
+        header: HeaderSchema,
+        content_cache_config: ContentCacheConfig,
+    ) -> Result<Self, NewError> {
+        let record_fields = header
+            .record_fields()
+            .ok_or(NewError::RecordFieldsMissing)?;
+
+        let record_field_contents = record_fields.to_contents();
+
+        Ok(Self {
+            stream: Arc::new(stream),
+            header,
+            record_fields,
+            record_field_contents,
+            cache: Arc::new(Mutex::new(Cache::new(content_cache_config))),
+        })
+    }
+
+    /// The preloaded strs are checked before the cache, see [`ContentCacheConfig::preload`].
+    pub(crate) fn set_preloaded(&mut self, preloaded: Arc<BTreeMap<u32, Box<str>>>) {
+        self.lock_cache().set_preloaded(preloaded);
+    }
+
+    fn lock_cache(&self) -> MutexGuard<'_, Cache> {
+        // The cache is consistent after a panic, it is only a cache.
+        self.cache.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+//
+#[derive(Debug)]
+pub enum NewError {
+    RecordFieldsMissing,
+}
+
+impl core::fmt::Display for NewError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::RecordFieldsMissing => write!(f, "record fields of the header are missing"),
+        }
+    }
+}
+
+impl std::error::Error for NewError {}
+
+//
+//
+//
+impl ReadAtQuerier {
+    pub fn query_ipv4(
+        &self,
+        ip: Ipv4Addr,
+        position_range: PositionRange,
+    ) -> Result<Option<(IpAddr, IpAddr, RecordFieldContents)>, RecordsQueryError> {
+        self.query(
+            ip.into(),
+            position_range,
+            self.header.v4_records_count,
+            self.header.v4_records_seek_from_start(),
+        )
+    }
+
+    pub fn query_ipv6(
+        &self,
+        ip: Ipv6Addr,
+        position_range: PositionRange,
+    ) -> Result<Option<(IpAddr, IpAddr, RecordFieldContents)>, RecordsQueryError> {
+        debug_assert!(ip.to_ipv4().is_none());
+
+        match self.header.v6_records_seek_from_start() {
+            Some(seek_from_start_base) => self.query(
+                ip.into(),
+                position_range,
+                self.header.v6_records_count,
+                seek_from_start_base,
+            ),
+            None => Ok(None),
+        }
+    }
+
+    fn query(
+        &self,
+        ip: IpAddr,
+        position_range: PositionRange,
+        count: u32,
+        seek_from_start_base: u64,
+    ) -> Result<Option<(IpAddr, IpAddr, RecordFieldContents)>, RecordsQueryError> {
+        // The record fields are of the known schemas, so they fit.
+        let mut buf = [0; RECORD_BYTES_LEN_MAX];
+        let buf = &mut buf[..record_bytes_len_with_double_ip(&self.record_fields, ip.is_ipv6())];
+
+        let mut search = Search::new(ip, position_range, count);
+        while let Some(position) = search.position() {
+            let seek_from_start = record_seek_from_start(
+                seek_from_start_base,
+                &self.record_fields,
+                position,
+                ip.is_ipv6(),
+            );
+            self.stream
+                .read_exact_at(buf, seek_from_start)
+                .map_err(|err| RecordsQueryError::ReadFailed(seek_from_start, err))?;
+
+            if let ControlFlow::Break(range) = search.step(buf)? {
+                return Ok(range.map(|(ip_from, ip_to)| {
+                    (
+                        ip_from,
+                        ip_to,
+                        record_field_contents_from_bytes(
+                            &self.record_field_contents,
+                            buf,
+                            ip.is_ipv6(),
+                        ),
+                    )
+                }));
+            }
+        }
+
+        Ok(None)
+    }
+
+    pub fn fill(
+        &self,
+        record_field_contents: &mut RecordFieldContents,
+    ) -> Result<(), ContentFillError> {
+        let mut buf = [0; 256];
+
+        for record_field_content in record_field_contents.iter_mut() {
+            let record_field = record_field_content.record_field();
+
+            match record_field_content {
+                RecordFieldContent::COUNTRY(i, v, v_name) => {
+                    // https://github.com/ip2location/ip2proxy-rust/blob/5bdd3ef61c2e243c1b61eda1475ca23eab2b7240/src/db.rs#L252
+                    let i_name = country_name_offset(*i)?;
+                    *v = self.str_cached(record_field, *i, &mut buf)?;
+                    *v_name = self.str_cached(record_field, i_name, &mut buf)?;
+                }
+                _ => {
+                    if let Some((i, v)) = str_mut(record_field_content) {
+                        *v = self.str_cached(record_field, i, &mut buf)?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    // The lock is not held by the read, so the concurrent lookups do not wait for it.
+    fn str_cached(
+        &self,
+        record_field: RecordField,
+        seek_from_start: u32,
+        buf: &mut [u8; 256],
+    ) -> Result<Option<Box<str>>, ContentFillError> {
+        if let Some(s) = self.lock_cache().get(record_field, seek_from_start) {
+            return Ok(s);
+        }
+
+        let s = self.str_at(seek_from_start, buf)?;
+        self.lock_cache().put(record_field, seek_from_start, s);
+
+        Ok(filter_str(s))
+    }
+
+    /// The str in content, a u8 len and then the bytes.
+    fn str_at<'a>(
+        &self,
+        seek_from_start: u32,
+        buf: &'a mut [u8; 256],
+    ) -> Result<&'a str, ContentFillError> {
+        self.stream
+            .read_exact_at(&mut buf[..1], seek_from_start as u64)
+            .map_err(|err| ContentFillError::ReadFailed(seek_from_start as u64, err))?;
+        let len = buf[0] as usize;
+
+        let s = &mut buf[1..1 + len];
+        self.stream
+            .read_exact_at(s, seek_from_start as u64 + 1)
+            .map_err(|err| ContentFillError::ReadFailed(seek_from_start as u64, err))?;
+
+        core::str::from_utf8(s)
+            .map_err(|err| ContentFillError::ToUtf8Failed(seek_from_start as u64, err))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use futures_util::{future, io::Cursor};
+
+    use crate::{
+        content::cache::ContentCachePolicy,
+        header::schema::SchemaType,
+        querier::Querier,
+        test_helper::{bin_bytes, BRISBANE, MOUNTAIN_VIEW},
+    };
+
+    #[tokio::test]
+    async fn test_lookup() -> Result<(), Box<dyn std::error::Error>> {
+        let bytes = bin_bytes(
+            SchemaType::IP2Location,
+            3,
+            &[
+                ("1.0.0.0", "1.0.0.255", &BRISBANE),
+                ("8.8.8.0", "8.8.8.255", &MOUNTAIN_VIEW),
+                ("2001:4860::", "2001:4860::ffff", &MOUNTAIN_VIEW),
+            ],
+        )?;
+        let bytes: Arc<[u8]> = bytes.into();
+
+        let path = std::env::temp_dir().join(format!(
+            "ip2location_bin_format_read_at_{}.BIN",
+            std::process::id()
+        ));
+        std::fs::write(&path, &bytes)?;
+
+        //
+        let q_read_at = Querier::open_read_at(&path).await?;
+        assert!(q_read_at.read_at_querier.is_some());
+        let q = Querier::new(|| Box::pin(future::ok(Cursor::new(bytes.clone()))), 1).await?;
+
+        let ips = [
+            "0.0.0.0",
+            "1.0.0.1",
+            "1.0.1.1",
+            "8.8.8.8",
+            "255.255.255.255",
+            "::ffff:8.8.8.8",
+            "2001:4860::8888",
+            "2001:4860::1:0",
+            "ffff::",
+        ]
+        .iter()
+        .map(|x| x.parse::<IpAddr>())
+        .collect::<Result<Vec<_>, _>>()?;
+
+        let mut expected = vec![];
+        for ip in ips.iter() {
+            let ret = format!("{:?}", q.lookup(*ip, None).await?);
+            assert_eq!(format!("{:?}", q_read_at.lookup(*ip, None).await?), ret);
+            expected.push(ret);
+        }
+
+        // More concurrent lookups than the pool size.
+        let rets = future::join_all(
+            ips.iter()
+                .cycle()
+                .take(ips.len() * 8)
+                .map(|ip| q_read_at.lookup(*ip, None)),
+        )
+        .await;
+        for (ret, expected) in rets.into_iter().zip(expected.iter().cycle()) {
+            assert_eq!(&format!("{:?}", ret?), expected);
+        }
+
+        for content_cache_config in [
+            ContentCacheConfig::none(),
+            ContentCacheConfig {
+                preload: true,
+                ..ContentCacheConfig::default()
+                    .with_policy(RecordField::CITY, ContentCachePolicy::Static)
+            },
+        ] {
+            let q_cached =
+                Querier::open_read_at_with_content_cache_config(&path, content_cache_config)
+                    .await?;
+            for _ in 0..2 {
+                for (ip, expected) in ips.iter().zip(expected.iter()) {
+                    assert_eq!(
+                        &format!("{:?}", q_cached.lookup(*ip, None).await?),
+                        expected
+                    );
+                }
+            }
+        }
+
+        //
+        let read_at_querier = q_read_at.read_at_querier.as_ref().unwrap();
+        let mut record_field_contents = read_at_querier.record_field_contents.to_owned();
+        for record_field_content in record_field_contents.iter_mut() {
+            if let RecordFieldContent::COUNTRY(i, _, _) = record_field_content {
+                *i = u32::MAX;
+            }
+        }
+        assert!(matches!(
+            read_at_querier.fill(&mut record_field_contents),
+            Err(ContentFillError::OffsetOverflow(offset)) if offset == u32::MAX as u64
+        ));
+
+        std::fs::remove_file(&path)?;
+
+        Ok(())
+    }
+}
@@ -9,35 +9,39 @@ use crate::header::schema::{SchemaSubType, SchemaType};
 pub const RECORD_FIELD_LEN_WITHOUT_IP: u32 = 4;
 
 //
-// TODO,  15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25
-pub const RECORD_FIELDS_DBN_LIST: &[u8] = &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14];
+pub const RECORD_FIELDS_DBN_LIST: &[u8] = &[
+    1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26,
+];
 
 /*
-https://github.com/ip2location/ip2location-go/blob/v9.2.0/ip2location.go#L123-L143
-
-var country_position            = [26]uint8{0, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,  2, 2,   2,  2,  2,  2,  2,  2,  2,  2,  2,  2,  2}
-var region_position             = [26]uint8{0, 0, 0, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,  3, 3,   3,  3,  3,  3,  3,  3,  3,  3,  3,  3,  3}
-var city_position               = [26]uint8{0, 0, 0, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,  4, 4,   4,  4,  4,  4,  4,  4,  4,  4,  4,  4,  4}
-var isp_position                = [26]uint8{0, 0, 3, 0, 5, 0, 7, 5, 7, 0, 8, 0, 9,  0, 9,   0,  9,  0,  9,  7,  9,  0,  9,  7,  9,  9}
-var latitude_position           = [26]uint8{0, 0, 0, 0, 0, 5, 5, 0, 5, 5, 5, 5, 5,  5, 5,   5,  5,  5,  5,  5,  5,  5,  5,  5,  5,  5}
-var longitude_position          = [26]uint8{0, 0, 0, 0, 0, 6, 6, 0, 6, 6, 6, 6, 6,  6, 6,   6,  6,  6,  6,  6,  6,  6,  6,  6,  6,  6}
-var domain_position             = [26]uint8{0, 0, 0, 0, 0, 0, 0, 6, 8, 0, 9, 0, 10, 0, 10,  0, 10,  0, 10,  8, 10,  0, 10,  8, 10, 10}
-var zipcode_position            = [26]uint8{0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 7, 7, 7,  0,  7,  7,  7,  0,  7,  0,  7,  7,  7,  0,  7,  7}
-var timezone_position           = [26]uint8{0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 8,  7,  8,  8,  8,  7,  8,  0,  8,  8,  8,  0,  8,  8}
-var netspeed_position           = [26]uint8{0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,  8, 11,  0, 11,  8, 11,  0, 11,  0, 11,  0, 11, 11}
-var iddcode_position            = [26]uint8{0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,  0,  0,  9, 12,  0, 12,  0, 12,  9, 12,  0, 12, 12}
-var areacode_position           = [26]uint8{0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,  0,  0, 10, 13,  0, 13,  0, 13, 10, 13,  0, 13, 13}
-var weatherstationcode_position = [26]uint8{0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,  0,  0,  0,  0,  9, 14,  0, 14,  0, 14,  0, 14, 14}
-var weatherstationname_position = [26]uint8{0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,  0,  0,  0,  0, 10, 15,  0, 15,  0, 15,  0, 15, 15}
-var mcc_position                = [26]uint8{0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,  0,  0,  0,  0,  0,  0,  9, 16,  0, 16,  9, 16, 16}
-var mnc_position                = [26]uint8{0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,  0,  0,  0,  0,  0,  0, 10, 17,  0, 17, 10, 17, 17}
-var mobilebrand_position        = [26]uint8{0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,  0,  0,  0,  0,  0,  0, 11, 18,  0, 18, 11, 18, 18}
-var elevation_position          = [26]uint8{0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,  0,  0,  0,  0,  0,  0,  0,  0, 11, 19,  0, 19, 19}
-var usagetype_position          = [26]uint8{0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,  0,  0,  0,  0,  0,  0,  0,  0,  0,  0, 12, 20, 20}
-var addresstype_position        = [26]uint8{0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,  0,  0,  0,  0,  0,  0,  0,  0,  0,  0,  0,  0, 21}
-var category_position           = [26]uint8{0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,  0,  0,  0,  0,  0,  0,  0,  0,  0,  0,  0,  0, 22}
-
-                                               1  2  3  4  5  6  7  8  9  10 11 12  13  14  15  16  17  18  19  20  21  22  23  24 25
+https://github.com/ip2location/ip2location-go/blob/master/ip2location.go
+
+var country_position            = [27]uint8{0, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,  2, 2,   2,  2,  2,  2,  2,  2,  2,  2,  2,  2,  2,  2}
+var region_position             = [27]uint8{0, 0, 0, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,  3, 3,   3,  3,  3,  3,  3,  3,  3,  3,  3,  3,  3,  3}
+var city_position               = [27]uint8{0, 0, 0, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,  4, 4,   4,  4,  4,  4,  4,  4,  4,  4,  4,  4,  4,  4}
+var isp_position                = [27]uint8{0, 0, 3, 0, 5, 0, 7, 5, 7, 0, 8, 0, 9,  0, 9,   0,  9,  0,  9,  7,  9,  0,  9,  7,  9,  9,  9}
+var latitude_position           = [27]uint8{0, 0, 0, 0, 0, 5, 5, 0, 5, 5, 5, 5, 5,  5, 5,   5,  5,  5,  5,  5,  5,  5,  5,  5,  5,  5,  5}
+var longitude_position          = [27]uint8{0, 0, 0, 0, 0, 6, 6, 0, 6, 6, 6, 6, 6,  6, 6,   6,  6,  6,  6,  6,  6,  6,  6,  6,  6,  6,  6}
+var domain_position             = [27]uint8{0, 0, 0, 0, 0, 0, 0, 6, 8, 0, 9, 0, 10, 0, 10,  0, 10,  0, 10,  8, 10,  0, 10,  8, 10, 10, 10}
+var zipcode_position            = [27]uint8{0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 7, 7, 7,  0,  7,  7,  7,  0,  7,  0,  7,  7,  7,  0,  7,  7,  7}
+var timezone_position           = [27]uint8{0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 8,  7,  8,  8,  8,  7,  8,  0,  8,  8,  8,  0,  8,  8,  8}
+var netspeed_position           = [27]uint8{0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,  8, 11,  0, 11,  8, 11,  0, 11,  0, 11,  0, 11, 11, 11}
+var iddcode_position            = [27]uint8{0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,  0,  0,  9, 12,  0, 12,  0, 12,  9, 12,  0, 12, 12, 12}
+var areacode_position           = [27]uint8{0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,  0,  0, 10, 13,  0, 13,  0, 13, 10, 13,  0, 13, 13, 13}
+var weatherstationcode_position = [27]uint8{0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,  0,  0,  0,  0,  9, 14,  0, 14,  0, 14,  0, 14, 14, 14}
+var weatherstationname_position = [27]uint8{0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,  0,  0,  0,  0, 10, 15,  0, 15,  0, 15,  0, 15, 15, 15}
+var mcc_position                = [27]uint8{0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,  0,  0,  0,  0,  0,  0,  9, 16,  0, 16,  9, 16, 16, 16}
+var mnc_position                = [27]uint8{0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,  0,  0,  0,  0,  0,  0, 10, 17,  0, 17, 10, 17, 17, 17}
+var mobilebrand_position        = [27]uint8{0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,  0,  0,  0,  0,  0,  0, 11, 18,  0, 18, 11, 18, 18, 18}
+var elevation_position          = [27]uint8{0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,  0,  0,  0,  0,  0,  0,  0,  0, 11, 19,  0, 19, 19, 19}
+var usagetype_position          = [27]uint8{0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,  0,  0,  0,  0,  0,  0,  0,  0,  0,  0, 12, 20, 20, 20}
+var addresstype_position        = [27]uint8{0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,  0,  0,  0,  0,  0,  0,  0,  0,  0,  0,  0,  0, 21, 21}
+var category_position           = [27]uint8{0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,  0,  0,  0,  0,  0,  0,  0,  0,  0,  0,  0,  0, 22, 22}
+var district_position           = [27]uint8{0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,  0,  0,  0,  0,  0,  0,  0,  0,  0,  0,  0,  0,  0, 23}
+var asn_position                = [27]uint8{0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,  0,  0,  0,  0,  0,  0,  0,  0,  0,  0,  0,  0,  0, 24}
+var as_position                 = [27]uint8{0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,  0,  0,  0,  0,  0,  0,  0,  0,  0,  0,  0,  0,  0, 25}
+
+                                               1  2  3  4  5  6  7  8  9  10 11 12  13  14  15  16  17  18  19  20  21  22  23  24  25  26
 */
 
 pub const RECORD_FIELDS_DB1: &[RecordField] = &[RecordField::IP, RecordField::COUNTRY];
@@ -157,28 +161,240 @@ pub const RECORD_FIELDS_DB14: &[RecordField] = &[
     RecordField::NETSPEED,
 ];
 
-// TODO, RECORD_FIELDS_DB15 - RECORD_FIELDS_DB25
+pub const RECORD_FIELDS_DB15: &[RecordField] = &[
+    RecordField::IP,
+    RecordField::COUNTRY,
+    RecordField::REGION,
+    RecordField::CITY,
+    RecordField::LATITUDE,
+    RecordField::LONGITUDE,
+    RecordField::ZIPCODE,
+    RecordField::TIMEZONE,
+    RecordField::IDDCODE,
+    RecordField::AREACODE,
+];
+pub const RECORD_FIELDS_DB16: &[RecordField] = &[
+    RecordField::IP,
+    RecordField::COUNTRY,
+    RecordField::REGION,
+    RecordField::CITY,
+    RecordField::LATITUDE,
+    RecordField::LONGITUDE,
+    RecordField::ZIPCODE,
+    RecordField::TIMEZONE,
+    RecordField::ISP,
+    RecordField::DOMAIN,
+    RecordField::NETSPEED,
+    RecordField::IDDCODE,
+    RecordField::AREACODE,
+];
+pub const RECORD_FIELDS_DB17: &[RecordField] = &[
+    RecordField::IP,
+    RecordField::COUNTRY,
+    RecordField::REGION,
+    RecordField::CITY,
+    RecordField::LATITUDE,
+    RecordField::LONGITUDE,
+    RecordField::TIMEZONE,
+    RecordField::NETSPEED,
+    RecordField::WEATHERSTATIONCODE,
+    RecordField::WEATHERSTATIONNAME,
+];
+pub const RECORD_FIELDS_DB18: &[RecordField] = &[
+    RecordField::IP,
+    RecordField::COUNTRY,
+    RecordField::REGION,
+    RecordField::CITY,
+    RecordField::LATITUDE,
+    RecordField::LONGITUDE,
+    RecordField::ZIPCODE,
+    RecordField::TIMEZONE,
+    RecordField::ISP,
+    RecordField::DOMAIN,
+    RecordField::NETSPEED,
+    RecordField::IDDCODE,
+    RecordField::AREACODE,
+    RecordField::WEATHERSTATIONCODE,
+    RecordField::WEATHERSTATIONNAME,
+];
+pub const RECORD_FIELDS_DB19: &[RecordField] = &[
+    RecordField::IP,
+    RecordField::COUNTRY,
+    RecordField::REGION,
+    RecordField::CITY,
+    RecordField::LATITUDE,
+    RecordField::LONGITUDE,
+    RecordField::ISP,
+    RecordField::DOMAIN,
+    RecordField::MCC,
+    RecordField::MNC,
+    RecordField::MOBILEBRAND,
+];
+pub const RECORD_FIELDS_DB20: &[RecordField] = &[
+    RecordField::IP,
+    RecordField::COUNTRY,
+    RecordField::REGION,
+    RecordField::CITY,
+    RecordField::LATITUDE,
+    RecordField::LONGITUDE,
+    RecordField::ZIPCODE,
+    RecordField::TIMEZONE,
+    RecordField::ISP,
+    RecordField::DOMAIN,
+    RecordField::NETSPEED,
+    RecordField::IDDCODE,
+    RecordField::AREACODE,
+    RecordField::WEATHERSTATIONCODE,
+    RecordField::WEATHERSTATIONNAME,
+    RecordField::MCC,
+    RecordField::MNC,
+    RecordField::MOBILEBRAND,
+];
+pub const RECORD_FIELDS_DB21: &[RecordField] = &[
+    RecordField::IP,
+    RecordField::COUNTRY,
+    RecordField::REGION,
+    RecordField::CITY,
+    RecordField::LATITUDE,
+    RecordField::LONGITUDE,
+    RecordField::ZIPCODE,
+    RecordField::TIMEZONE,
+    RecordField::IDDCODE,
+    RecordField::AREACODE,
+    RecordField::ELEVATION,
+];
+pub const RECORD_FIELDS_DB22: &[RecordField] = &[
+    RecordField::IP,
+    RecordField::COUNTRY,
+    RecordField::REGION,
+    RecordField::CITY,
+    RecordField::LATITUDE,
+    RecordField::LONGITUDE,
+    RecordField::ZIPCODE,
+    RecordField::TIMEZONE,
+    RecordField::ISP,
+    RecordField::DOMAIN,
+    RecordField::NETSPEED,
+    RecordField::IDDCODE,
+    RecordField::AREACODE,
+    RecordField::WEATHERSTATIONCODE,
+    RecordField::WEATHERSTATIONNAME,
+    RecordField::MCC,
+    RecordField::MNC,
+    RecordField::MOBILEBRAND,
+    RecordField::ELEVATION,
+];
+pub const RECORD_FIELDS_DB23: &[RecordField] = &[
+    RecordField::IP,
+    RecordField::COUNTRY,
+    RecordField::REGION,
+    RecordField::CITY,
+    RecordField::LATITUDE,
+    RecordField::LONGITUDE,
+    RecordField::ISP,
+    RecordField::DOMAIN,
+    RecordField::MCC,
+    RecordField::MNC,
+    RecordField::MOBILEBRAND,
+    RecordField::USAGETYPE,
+];
+pub const RECORD_FIELDS_DB24: &[RecordField] = &[
+    RecordField::IP,
+    RecordField::COUNTRY,
+    RecordField::REGION,
+    RecordField::CITY,
+    RecordField::LATITUDE,
+    RecordField::LONGITUDE,
+    RecordField::ZIPCODE,
+    RecordField::TIMEZONE,
+    RecordField::ISP,
+    RecordField::DOMAIN,
+    RecordField::NETSPEED,
+    RecordField::IDDCODE,
+    RecordField::AREACODE,
+    RecordField::WEATHERSTATIONCODE,
+    RecordField::WEATHERSTATIONNAME,
+    RecordField::MCC,
+    RecordField::MNC,
+    RecordField::MOBILEBRAND,
+    RecordField::ELEVATION,
+    RecordField::USAGETYPE,
+];
+pub const RECORD_FIELDS_DB25: &[RecordField] = &[
+    RecordField::IP,
+    RecordField::COUNTRY,
+    RecordField::REGION,
+    RecordField::CITY,
+    RecordField::LATITUDE,
+    RecordField::LONGITUDE,
+    RecordField::ZIPCODE,
+    RecordField::TIMEZONE,
+    RecordField::ISP,
+    RecordField::DOMAIN,
+    RecordField::NETSPEED,
+    RecordField::IDDCODE,
+    RecordField::AREACODE,
+    RecordField::WEATHERSTATIONCODE,
+    RecordField::WEATHERSTATIONNAME,
+    RecordField::MCC,
+    RecordField::MNC,
+    RecordField::MOBILEBRAND,
+    RecordField::ELEVATION,
+    RecordField::USAGETYPE,
+    RecordField::ADDRESSTYPE,
+    RecordField::CATEGORY,
+];
+pub const RECORD_FIELDS_DB26: &[RecordField] = &[
+    RecordField::IP,
+    RecordField::COUNTRY,
+    RecordField::REGION,
+    RecordField::CITY,
+    RecordField::LATITUDE,
+    RecordField::LONGITUDE,
+    RecordField::ZIPCODE,
+    RecordField::TIMEZONE,
+    RecordField::ISP,
+    RecordField::DOMAIN,
+    RecordField::NETSPEED,
+    RecordField::IDDCODE,
+    RecordField::AREACODE,
+    RecordField::WEATHERSTATIONCODE,
+    RecordField::WEATHERSTATIONNAME,
+    RecordField::MCC,
+    RecordField::MNC,
+    RecordField::MOBILEBRAND,
+    RecordField::ELEVATION,
+    RecordField::USAGETYPE,
+    RecordField::ADDRESSTYPE,
+    RecordField::CATEGORY,
+    RecordField::DISTRICT,
+    RecordField::ASN,
+    RecordField::AS,
+];
 
 //
-pub const RECORD_FIELDS_PXN_LIST: &[u8] = &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11];
+pub const RECORD_FIELDS_PXN_LIST: &[u8] = &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
 
 /*
-https://github.com/ip2location/ip2proxy-go/blob/v3.3.2/ip2proxy.go#L99-L110
-
-var countryPosition   = [12]uint8{0, 2, 3, 3, 3, 3, 3,  3,  3,  3,  3,  3}
-var regionPosition    = [12]uint8{0, 0, 0, 4, 4, 4, 4,  4,  4,  4,  4,  4}
-var cityPosition      = [12]uint8{0, 0, 0, 5, 5, 5, 5,  5,  5,  5,  5,  5}
-var ispPosition       = [12]uint8{0, 0, 0, 0, 6, 6, 6,  6,  6,  6,  6,  6}
-var proxyTypePosition = [12]uint8{0, 0, 2, 2, 2, 2, 2,  2,  2,  2,  2,  2}
-var domainPosition    = [12]uint8{0, 0, 0, 0, 0, 7, 7,  7,  7,  7,  7,  7}
-var usageTypePosition = [12]uint8{0, 0, 0, 0, 0, 0, 8,  8,  8,  8,  8,  8}
-var asnPosition       = [12]uint8{0, 0, 0, 0, 0, 0, 0,  9,  9,  9,  9,  9}
-var asPosition        = [12]uint8{0, 0, 0, 0, 0, 0, 0, 10, 10, 10, 10, 10}
-var lastSeenPosition  = [12]uint8{0, 0, 0, 0, 0, 0, 0,  0, 11, 11, 11, 11}
-var threatPosition    = [12]uint8{0, 0, 0, 0, 0, 0, 0,  0,  0, 12, 12, 12}
-var providerPosition  = [12]uint8{0, 0, 0, 0, 0, 0, 0,  0,  0,  0,  0, 13}
-
-                                     1  2  3  4  5  6   7   8   9  10  11
+https://github.com/ip2location/ip2proxy-go/blob/master/ip2proxy.go
+
+var countryPosition    = [13]uint8{0, 2, 3, 3, 3, 3, 3,  3,  3,  3,  3,  3,  3}
+var regionPosition     = [13]uint8{0, 0, 0, 4, 4, 4, 4,  4,  4,  4,  4,  4,  4}
+var cityPosition       = [13]uint8{0, 0, 0, 5, 5, 5, 5,  5,  5,  5,  5,  5,  5}
+var ispPosition        = [13]uint8{0, 0, 0, 0, 6, 6, 6,  6,  6,  6,  6,  6,  6}
+var proxyTypePosition  = [13]uint8{0, 0, 2, 2, 2, 2, 2,  2,  2,  2,  2,  2,  2}
+var domainPosition     = [13]uint8{0, 0, 0, 0, 0, 7, 7,  7,  7,  7,  7,  7,  7}
+var usageTypePosition  = [13]uint8{0, 0, 0, 0, 0, 0, 8,  8,  8,  8,  8,  8,  8}
+var asnPosition        = [13]uint8{0, 0, 0, 0, 0, 0, 0,  9,  9,  9,  9,  9,  9}
+var asPosition         = [13]uint8{0, 0, 0, 0, 0, 0, 0, 10, 10, 10, 10, 10, 10}
+var lastSeenPosition   = [13]uint8{0, 0, 0, 0, 0, 0, 0,  0, 11, 11, 11, 11, 11}
+var threatPosition     = [13]uint8{0, 0, 0, 0, 0, 0, 0,  0,  0, 12, 12, 12, 12}
+var providerPosition   = [13]uint8{0, 0, 0, 0, 0, 0, 0,  0,  0,  0,  0, 13, 13}
+var fraudScorePosition = [13]uint8{0, 0, 0, 0, 0, 0, 0,  0,  0,  0,  0,  0, 14}
+
+                                      1  2  3  4  5  6   7   8   9  10  11  12
+
+RESIDENTIAL is not in any BIN layout, it is only a column of some old CSVs.
 */
 
 pub const RECORD_FIELDS_PX1: &[RecordField] = &[RecordField::IP, RecordField::COUNTRY];
@@ -300,8 +516,25 @@ pub const RECORD_FIELDS_PX11: &[RecordField] = &[
     RecordField::PROVIDER,
 ];
 
+pub const RECORD_FIELDS_PX12: &[RecordField] = &[
+    RecordField::IP,
+    RecordField::PROXYTYPE,
+    RecordField::COUNTRY,
+    RecordField::REGION,
+    RecordField::CITY,
+    RecordField::ISP,
+    RecordField::DOMAIN,
+    RecordField::USAGETYPE,
+    RecordField::ASN,
+    RecordField::AS,
+    RecordField::LASTSEEN,
+    RecordField::THREAT,
+    RecordField::PROVIDER,
+    RecordField::FRAUDSCORE,
+];
+
 //
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum RecordField {
     IP,
     // Common
@@ -316,6 +549,17 @@ pub enum RecordField {
     ZIPCODE,
     TIMEZONE,
     NETSPEED,
+    IDDCODE,
+    AREACODE,
+    WEATHERSTATIONCODE,
+    WEATHERSTATIONNAME,
+    MCC,
+    MNC,
+    MOBILEBRAND,
+    ELEVATION,
+    ADDRESSTYPE,
+    CATEGORY,
+    DISTRICT,
     // IP2Proxy
     PROXYTYPE,
     USAGETYPE,
@@ -325,6 +569,7 @@ pub enum RecordField {
     THREAT,
     RESIDENTIAL,
     PROVIDER,
+    FRAUDSCORE,
 }
 
 //
@@ -380,6 +625,21 @@ impl RecordFields {
                 RecordField::ZIPCODE => RecordFieldContent::ZIPCODE(0, Default::default()),
                 RecordField::TIMEZONE => RecordFieldContent::TIMEZONE(0, Default::default()),
                 RecordField::NETSPEED => RecordFieldContent::NETSPEED(0, Default::default()),
+                RecordField::IDDCODE => RecordFieldContent::IDDCODE(0, Default::default()),
+                RecordField::AREACODE => RecordFieldContent::AREACODE(0, Default::default()),
+                RecordField::WEATHERSTATIONCODE => {
+                    RecordFieldContent::WEATHERSTATIONCODE(0, Default::default())
+                }
+                RecordField::WEATHERSTATIONNAME => {
+                    RecordFieldContent::WEATHERSTATIONNAME(0, Default::default())
+                }
+                RecordField::MCC => RecordFieldContent::MCC(0, Default::default()),
+                RecordField::MNC => RecordFieldContent::MNC(0, Default::default()),
+                RecordField::MOBILEBRAND => RecordFieldContent::MOBILEBRAND(0, Default::default()),
+                RecordField::ELEVATION => RecordFieldContent::ELEVATION(0, Default::default()),
+                RecordField::ADDRESSTYPE => RecordFieldContent::ADDRESSTYPE(0, Default::default()),
+                RecordField::CATEGORY => RecordFieldContent::CATEGORY(0, Default::default()),
+                RecordField::DISTRICT => RecordFieldContent::DISTRICT(0, Default::default()),
                 //
                 RecordField::PROXYTYPE => RecordFieldContent::PROXYTYPE(0, Default::default()),
                 RecordField::USAGETYPE => RecordFieldContent::USAGETYPE(0, Default::default()),
@@ -389,6 +649,7 @@ impl RecordFields {
                 RecordField::THREAT => RecordFieldContent::THREAT(0, Default::default()),
                 RecordField::RESIDENTIAL => RecordFieldContent::RESIDENTIAL(0, Default::default()),
                 RecordField::PROVIDER => RecordFieldContent::PROVIDER(0, Default::default()),
+                RecordField::FRAUDSCORE => RecordFieldContent::FRAUDSCORE(0, Default::default()),
             })
             .collect::<Vec<_>>();
 
@@ -424,6 +685,18 @@ impl TryFrom<(SchemaType, SchemaSubType)> for RecordFields {
                 12 => Ok(Self(RECORD_FIELDS_DB12.to_owned())),
                 13 => Ok(Self(RECORD_FIELDS_DB13.to_owned())),
                 14 => Ok(Self(RECORD_FIELDS_DB14.to_owned())),
+                15 => Ok(Self(RECORD_FIELDS_DB15.to_owned())),
+                16 => Ok(Self(RECORD_FIELDS_DB16.to_owned())),
+                17 => Ok(Self(RECORD_FIELDS_DB17.to_owned())),
+                18 => Ok(Self(RECORD_FIELDS_DB18.to_owned())),
+                19 => Ok(Self(RECORD_FIELDS_DB19.to_owned())),
+                20 => Ok(Self(RECORD_FIELDS_DB20.to_owned())),
+                21 => Ok(Self(RECORD_FIELDS_DB21.to_owned())),
+                22 => Ok(Self(RECORD_FIELDS_DB22.to_owned())),
+                23 => Ok(Self(RECORD_FIELDS_DB23.to_owned())),
+                24 => Ok(Self(RECORD_FIELDS_DB24.to_owned())),
+                25 => Ok(Self(RECORD_FIELDS_DB25.to_owned())),
+                26 => Ok(Self(RECORD_FIELDS_DB26.to_owned())),
                 _ => Err(sub_type),
             },
             SchemaType::IP2Proxy => match sub_type.0 {
@@ -438,6 +711,7 @@ impl TryFrom<(SchemaType, SchemaSubType)> for RecordFields {
                 9 => Ok(Self(RECORD_FIELDS_PX9.to_owned())),
                 10 => Ok(Self(RECORD_FIELDS_PX10.to_owned())),
                 11 => Ok(Self(RECORD_FIELDS_PX11.to_owned())),
+                12 => Ok(Self(RECORD_FIELDS_PX12.to_owned())),
                 _ => Err(sub_type),
             },
         }
@@ -459,6 +733,17 @@ pub enum RecordFieldContent {
     ZIPCODE(u32, Option<Box<str>>),
     TIMEZONE(u32, Option<Box<str>>),
     NETSPEED(u32, Option<Box<str>>),
+    IDDCODE(u32, Option<Box<str>>),
+    AREACODE(u32, Option<Box<str>>),
+    WEATHERSTATIONCODE(u32, Option<Box<str>>),
+    WEATHERSTATIONNAME(u32, Option<Box<str>>),
+    MCC(u32, Option<Box<str>>),
+    MNC(u32, Option<Box<str>>),
+    MOBILEBRAND(u32, Option<Box<str>>),
+    ELEVATION(u32, Option<Box<str>>),
+    ADDRESSTYPE(u32, Option<Box<str>>),
+    CATEGORY(u32, Option<Box<str>>),
+    DISTRICT(u32, Option<Box<str>>),
     // IP2Proxy
     PROXYTYPE(u32, Option<Box<str>>),
     USAGETYPE(u32, Option<Box<str>>),
@@ -468,6 +753,46 @@ pub enum RecordFieldContent {
     THREAT(u32, Option<Box<str>>),
     RESIDENTIAL(u32, Option<Box<str>>),
     PROVIDER(u32, Option<Box<str>>),
+    FRAUDSCORE(u32, Option<Box<str>>),
+}
+
+impl RecordFieldContent {
+    pub fn record_field(&self) -> RecordField {
+        match self {
+            Self::COUNTRY(..) => RecordField::COUNTRY,
+            Self::REGION(..) => RecordField::REGION,
+            Self::CITY(..) => RecordField::CITY,
+            Self::ISP(..) => RecordField::ISP,
+            Self::DOMAIN(..) => RecordField::DOMAIN,
+            //
+            Self::LATITUDE(_) => RecordField::LATITUDE,
+            Self::LONGITUDE(_) => RecordField::LONGITUDE,
+            Self::ZIPCODE(..) => RecordField::ZIPCODE,
+            Self::TIMEZONE(..) => RecordField::TIMEZONE,
+            Self::NETSPEED(..) => RecordField::NETSPEED,
+            Self::IDDCODE(..) => RecordField::IDDCODE,
+            Self::AREACODE(..) => RecordField::AREACODE,
+            Self::WEATHERSTATIONCODE(..) => RecordField::WEATHERSTATIONCODE,
+            Self::WEATHERSTATIONNAME(..) => RecordField::WEATHERSTATIONNAME,
+            Self::MCC(..) => RecordField::MCC,
+            Self::MNC(..) => RecordField::MNC,
+            Self::MOBILEBRAND(..) => RecordField::MOBILEBRAND,
+            Self::ELEVATION(..) => RecordField::ELEVATION,
+            Self::ADDRESSTYPE(..) => RecordField::ADDRESSTYPE,
+            Self::CATEGORY(..) => RecordField::CATEGORY,
+            Self::DISTRICT(..) => RecordField::DISTRICT,
+            //
+            Self::PROXYTYPE(..) => RecordField::PROXYTYPE,
+            Self::USAGETYPE(..) => RecordField::USAGETYPE,
+            Self::ASN(..) => RecordField::ASN,
+            Self::AS(..) => RecordField::AS,
+            Self::LASTSEEN(..) => RecordField::LASTSEEN,
+            Self::THREAT(..) => RecordField::THREAT,
+            Self::RESIDENTIAL(..) => RecordField::RESIDENTIAL,
+            Self::PROVIDER(..) => RecordField::PROVIDER,
+            Self::FRAUDSCORE(..) => RecordField::FRAUDSCORE,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -501,6 +826,25 @@ impl RecordFieldContents {
             RecordFieldContent::ZIPCODE(_, _) => record_fields.contains(&RecordField::ZIPCODE),
             RecordFieldContent::TIMEZONE(_, _) => record_fields.contains(&RecordField::TIMEZONE),
             RecordFieldContent::NETSPEED(_, _) => record_fields.contains(&RecordField::NETSPEED),
+            RecordFieldContent::IDDCODE(_, _) => record_fields.contains(&RecordField::IDDCODE),
+            RecordFieldContent::AREACODE(_, _) => record_fields.contains(&RecordField::AREACODE),
+            RecordFieldContent::WEATHERSTATIONCODE(_, _) => {
+                record_fields.contains(&RecordField::WEATHERSTATIONCODE)
+            }
+            RecordFieldContent::WEATHERSTATIONNAME(_, _) => {
+                record_fields.contains(&RecordField::WEATHERSTATIONNAME)
+            }
+            RecordFieldContent::MCC(_, _) => record_fields.contains(&RecordField::MCC),
+            RecordFieldContent::MNC(_, _) => record_fields.contains(&RecordField::MNC),
+            RecordFieldContent::MOBILEBRAND(_, _) => {
+                record_fields.contains(&RecordField::MOBILEBRAND)
+            }
+            RecordFieldContent::ELEVATION(_, _) => record_fields.contains(&RecordField::ELEVATION),
+            RecordFieldContent::ADDRESSTYPE(_, _) => {
+                record_fields.contains(&RecordField::ADDRESSTYPE)
+            }
+            RecordFieldContent::CATEGORY(_, _) => record_fields.contains(&RecordField::CATEGORY),
+            RecordFieldContent::DISTRICT(_, _) => record_fields.contains(&RecordField::DISTRICT),
             //
             RecordFieldContent::PROXYTYPE(_, _) => record_fields.contains(&RecordField::PROXYTYPE),
             RecordFieldContent::USAGETYPE(_, _) => record_fields.contains(&RecordField::USAGETYPE),
@@ -512,6 +856,9 @@ impl RecordFieldContents {
                 record_fields.contains(&RecordField::RESIDENTIAL)
             }
             RecordFieldContent::PROVIDER(_, _) => record_fields.contains(&RecordField::PROVIDER),
+            RecordFieldContent::FRAUDSCORE(_, _) => {
+                record_fields.contains(&RecordField::FRAUDSCORE)
+            }
         });
     }
 }
@@ -1 +1,197 @@
+use std::net::{Ipv4Addr, Ipv6Addr};
 
+use crate::record_field::{RecordField, RecordFieldContent, RecordFieldContents, RecordFields};
+
+//
+#[derive(Debug)]
+pub struct V4Builder {
+    inner: Inner,
+}
+
+impl V4Builder {
+    pub fn new(record_fields: RecordFields) -> Self {
+        Self {
+            inner: Inner::new(record_fields, 4),
+        }
+    }
+
+    pub fn count(&self) -> u32 {
+        self.inner.count
+    }
+
+    /// The content indexes of record_field_contents are relative to the start of content,
+    /// see [`finish`](Self::finish).
+    pub fn append(
+        &mut self,
+        ip_from: Ipv4Addr,
+        record_field_contents: &RecordFieldContents,
+    ) -> Result<(), AppendError> {
+        self.inner
+            .append(&u32::from(ip_from).to_ne_bytes(), record_field_contents)
+    }
+
+    pub fn finish(self, content_seek_from_start: u32) -> Vec<u8> {
+        self.inner.finish(content_seek_from_start)
+    }
+}
+
+//
+#[derive(Debug)]
+pub struct V6Builder {
+    inner: Inner,
+}
+
+impl V6Builder {
+    pub fn new(record_fields: RecordFields) -> Self {
+        Self {
+            inner: Inner::new(record_fields, 16),
+        }
+    }
+
+    pub fn count(&self) -> u32 {
+        self.inner.count
+    }
+
+    /// The content indexes of record_field_contents are relative to the start of content,
+    /// see [`finish`](Self::finish).
+    pub fn append(
+        &mut self,
+        ip_from: Ipv6Addr,
+        record_field_contents: &RecordFieldContents,
+    ) -> Result<(), AppendError> {
+        self.inner
+            .append(&u128::from(ip_from).to_ne_bytes(), record_field_contents)
+    }
+
+    pub fn finish(self, content_seek_from_start: u32) -> Vec<u8> {
+        self.inner.finish(content_seek_from_start)
+    }
+}
+
+//
+#[derive(Debug)]
+struct Inner {
+    record_fields: RecordFields,
+    record_field_contents: RecordFieldContents,
+    ip_len: usize,
+    count: u32,
+    bytes: Vec<u8>,
+}
+
+impl Inner {
+    fn new(record_fields: RecordFields, ip_len: usize) -> Self {
+        let record_field_contents = record_fields.to_contents();
+
+        Self {
+            record_fields,
+            record_field_contents,
+            ip_len,
+            count: 0,
+            bytes: vec![],
+        }
+    }
+
+    fn append(
+        &mut self,
+        ip_from_bytes: &[u8],
+        record_field_contents: &RecordFieldContents,
+    ) -> Result<(), AppendError> {
+        debug_assert_eq!(ip_from_bytes.len(), self.ip_len);
+
+        if record_field_contents.len() != self.record_field_contents.len()
+            || record_field_contents
+                .iter()
+                .zip(self.record_field_contents.iter())
+                .any(|(a, b)| core::mem::discriminant(a) != core::mem::discriminant(b))
+        {
+            return Err(AppendError::RecordFieldContentsMismatch);
+        }
+
+        self.count = self
+            .count
+            .checked_add(1)
+            .ok_or(AppendError::CountOverflow)?;
+
+        self.bytes.extend_from_slice(ip_from_bytes);
+        for record_field_content in record_field_contents.iter() {
+            let bytes = match record_field_content {
+                RecordFieldContent::COUNTRY(i, _, _) => i.to_ne_bytes(),
+                RecordFieldContent::REGION(i, _) => i.to_ne_bytes(),
+                RecordFieldContent::CITY(i, _) => i.to_ne_bytes(),
+                RecordFieldContent::ISP(i, _) => i.to_ne_bytes(),
+                RecordFieldContent::DOMAIN(i, _) => i.to_ne_bytes(),
+                //
+                RecordFieldContent::LATITUDE(v) => v.to_ne_bytes(),
+                RecordFieldContent::LONGITUDE(v) => v.to_ne_bytes(),
+                RecordFieldContent::ZIPCODE(i, _) => i.to_ne_bytes(),
+                RecordFieldContent::TIMEZONE(i, _) => i.to_ne_bytes(),
+                RecordFieldContent::NETSPEED(i, _) => i.to_ne_bytes(),
+                RecordFieldContent::IDDCODE(i, _) => i.to_ne_bytes(),
+                RecordFieldContent::AREACODE(i, _) => i.to_ne_bytes(),
+                RecordFieldContent::WEATHERSTATIONCODE(i, _) => i.to_ne_bytes(),
+                RecordFieldContent::WEATHERSTATIONNAME(i, _) => i.to_ne_bytes(),
+                RecordFieldContent::MCC(i, _) => i.to_ne_bytes(),
+                RecordFieldContent::MNC(i, _) => i.to_ne_bytes(),
+                RecordFieldContent::MOBILEBRAND(i, _) => i.to_ne_bytes(),
+                RecordFieldContent::ELEVATION(i, _) => i.to_ne_bytes(),
+                RecordFieldContent::ADDRESSTYPE(i, _) => i.to_ne_bytes(),
+                RecordFieldContent::CATEGORY(i, _) => i.to_ne_bytes(),
+                RecordFieldContent::DISTRICT(i, _) => i.to_ne_bytes(),
+                //
+                RecordFieldContent::PROXYTYPE(i, _) => i.to_ne_bytes(),
+                RecordFieldContent::USAGETYPE(i, _) => i.to_ne_bytes(),
+                RecordFieldContent::ASN(i, _) => i.to_ne_bytes(),
+                RecordFieldContent::AS(i, _) => i.to_ne_bytes(),
+                RecordFieldContent::LASTSEEN(i, _) => i.to_ne_bytes(),
+                RecordFieldContent::THREAT(i, _) => i.to_ne_bytes(),
+                RecordFieldContent::RESIDENTIAL(i, _) => i.to_ne_bytes(),
+                RecordFieldContent::PROVIDER(i, _) => i.to_ne_bytes(),
+                RecordFieldContent::FRAUDSCORE(i, _) => i.to_ne_bytes(),
+            };
+            self.bytes.extend_from_slice(&bytes);
+        }
+
+        Ok(())
+    }
+
+    fn finish(mut self, content_seek_from_start: u32) -> Vec<u8> {
+        let record_len = self.ip_len + (self.record_fields.len() - 1) * 4;
+
+        // Make the content indexes absolute.
+        for record in self.bytes.chunks_exact_mut(record_len) {
+            for (n, record_field) in self.record_fields[1..].iter().enumerate() {
+                if matches!(record_field, RecordField::LATITUDE | RecordField::LONGITUDE) {
+                    continue;
+                }
+
+                let index = self.ip_len + n * 4;
+                let content_index =
+                    u32::from_ne_bytes(record[index..index + 4].try_into().unwrap());
+                record[index..index + 4]
+                    .copy_from_slice(&(content_index + content_seek_from_start).to_ne_bytes());
+            }
+        }
+
+        self.bytes
+    }
+}
+
+//
+#[derive(Debug)]
+pub enum AppendError {
+    RecordFieldContentsMismatch,
+    CountOverflow,
+}
+
+impl core::fmt::Display for AppendError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::RecordFieldContentsMismatch => {
+                write!(f, "record field contents mismatch the record fields")
+            }
+            Self::CountOverflow => write!(f, "too many records"),
+        }
+    }
+}
+
+impl std::error::Error for AppendError {}
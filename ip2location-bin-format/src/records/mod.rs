@@ -2,6 +2,7 @@
 pub mod builder;
 pub mod querier;
 
+pub use builder::{V4Builder, V6Builder};
 pub use querier::{V4Querier, V6Querier};
 
 //
@@ -3,15 +3,29 @@ use std::io::Error as IoError;
 //
 #[derive(Debug)]
 pub enum Error {
-    SeekFailed(IoError),
-    ReadFailed(IoError),
+    /// The offset is from the start of the BIN.
+    SeekFailed(u64, IoError),
+    ReadFailed(u64, IoError),
     MaxDepthReached,
 }
 
 impl core::fmt::Display for Error {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{self:?}")
+        match self {
+            Self::SeekFailed(offset, _) => write!(f, "seek to record at offset {offset} failed"),
+            Self::ReadFailed(offset, _) => write!(f, "read record at offset {offset} failed"),
+            Self::MaxDepthReached => {
+                write!(f, "max search depth reached, the records are corrupted")
+            }
+        }
     }
 }
 
-impl std::error::Error for Error {}
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::SeekFailed(_, err) | Self::ReadFailed(_, err) => Some(err),
+            Self::MaxDepthReached => None,
+        }
+    }
+}
@@ -1,5 +1,6 @@
+use core::ops::ControlFlow;
 use std::{
-    io::SeekFrom,
+    io::{Error as IoError, SeekFrom},
     net::{IpAddr, Ipv4Addr, Ipv6Addr},
 };
 
@@ -51,147 +52,306 @@ where
 {
     pub(super) async fn query(
         &mut self,
+        ip: IpAddr,
+        position_range: PositionRange,
+    ) -> Result<Option<(IpAddr, IpAddr, RecordFieldContents)>, Error> {
+        let mut search = Search::new(ip, position_range, self.count);
+        while let Some(position) = search.position() {
+            let seek_from_start = record_seek_from_start(
+                self.seek_from_start_base,
+                &self.record_fields,
+                position,
+                ip.is_ipv6(),
+            );
+
+            self.stream
+                .seek(SeekFrom::Start(seek_from_start))
+                .await
+                .map_err(|err| Error::SeekFailed(seek_from_start, err))?;
+
+            self.stream
+                .read_exact(&mut self.buf)
+                .await
+                .map_err(|err| Error::ReadFailed(seek_from_start, err))?;
+
+            if let ControlFlow::Break(range) = search.step(&self.buf)? {
+                return Ok(range.map(|(ip_from, ip_to)| {
+                    (
+                        ip_from,
+                        ip_to,
+                        self.record_field_contents_from_buf(ip.is_ipv6()),
+                    )
+                }));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Reads the record at position, ip_to is the ip_from of the next record (exclusive).
+    ///
+    /// The last record only ends the previous range, so there is no range at it.
+    pub(super) async fn read(
+        &mut self,
+        position: u32,
+        is_ipv6: bool,
+    ) -> Result<Option<(IpAddr, IpAddr, RecordFieldContents)>, Error> {
+        if position.saturating_add(1) >= self.count {
+            return Ok(None);
+        }
+
+        let seek_from_start = record_seek_from_start(
+            self.seek_from_start_base,
+            &self.record_fields,
+            position,
+            is_ipv6,
+        );
+
+        self.stream
+            .seek(SeekFrom::Start(seek_from_start))
+            .await
+            .map_err(|err| Error::SeekFailed(seek_from_start, err))?;
+
+        self.stream
+            .read_exact(&mut self.buf)
+            .await
+            .map_err(|err| Error::ReadFailed(seek_from_start, err))?;
+
+        let ip_from = ip_from_bytes(&self.buf, is_ipv6);
+        let ip_to = ip_from_bytes(
+            &self.buf[self.buf.len() - if is_ipv6 { 16 } else { 4 }..],
+            is_ipv6,
+        );
+
+        let record_field_contents = self.record_field_contents_from_buf(is_ipv6);
+
+        Ok(Some((ip_from, ip_to, record_field_contents)))
+    }
+
+    fn record_field_contents_from_buf(&self, is_ipv6: bool) -> RecordFieldContents {
+        record_field_contents_from_bytes(&self.record_field_contents, &self.buf, is_ipv6)
+    }
+}
+
+/// bytes is a record, ip_from and then the fields.
+pub(crate) fn record_field_contents_from_bytes(
+    record_field_contents: &RecordFieldContents,
+    bytes: &[u8],
+    is_ipv6: bool,
+) -> RecordFieldContents {
+    let mut record_field_contents = record_field_contents.to_owned();
+    for (n, record_field_content) in record_field_contents.iter_mut().enumerate() {
+        let index = if is_ipv6 { 16 + n * 4 } else { 4 + n * 4 };
+
+        let content_index = u32::from_ne_bytes(bytes[index..index + 4].try_into().unwrap());
+
+        match record_field_content {
+            RecordFieldContent::COUNTRY(i, _, _) => *i = content_index,
+            RecordFieldContent::REGION(i, _) => *i = content_index,
+            RecordFieldContent::CITY(i, _) => *i = content_index,
+            RecordFieldContent::ISP(i, _) => *i = content_index,
+            RecordFieldContent::DOMAIN(i, _) => *i = content_index,
+            //
+            RecordFieldContent::LATITUDE(v) => {
+                *v = f32::from_ne_bytes(bytes[index..index + 4].try_into().unwrap())
+            }
+            RecordFieldContent::LONGITUDE(v) => {
+                *v = f32::from_ne_bytes(bytes[index..index + 4].try_into().unwrap())
+            }
+            RecordFieldContent::ZIPCODE(i, _) => *i = content_index,
+            RecordFieldContent::TIMEZONE(i, _) => *i = content_index,
+            RecordFieldContent::NETSPEED(i, _) => *i = content_index,
+            RecordFieldContent::IDDCODE(i, _) => *i = content_index,
+            RecordFieldContent::AREACODE(i, _) => *i = content_index,
+            RecordFieldContent::WEATHERSTATIONCODE(i, _) => *i = content_index,
+            RecordFieldContent::WEATHERSTATIONNAME(i, _) => *i = content_index,
+            RecordFieldContent::MCC(i, _) => *i = content_index,
+            RecordFieldContent::MNC(i, _) => *i = content_index,
+            RecordFieldContent::MOBILEBRAND(i, _) => *i = content_index,
+            RecordFieldContent::ELEVATION(i, _) => *i = content_index,
+            RecordFieldContent::ADDRESSTYPE(i, _) => *i = content_index,
+            RecordFieldContent::CATEGORY(i, _) => *i = content_index,
+            RecordFieldContent::DISTRICT(i, _) => *i = content_index,
+            //
+            RecordFieldContent::PROXYTYPE(i, _) => *i = content_index,
+            RecordFieldContent::USAGETYPE(i, _) => *i = content_index,
+            RecordFieldContent::ASN(i, _) => *i = content_index,
+            RecordFieldContent::AS(i, _) => *i = content_index,
+            RecordFieldContent::LASTSEEN(i, _) => *i = content_index,
+            RecordFieldContent::THREAT(i, _) => *i = content_index,
+            RecordFieldContent::RESIDENTIAL(i, _) => *i = content_index,
+            RecordFieldContent::PROVIDER(i, _) => *i = content_index,
+            RecordFieldContent::FRAUDSCORE(i, _) => *i = content_index,
+        }
+    }
+
+    record_field_contents
+}
+
+/// The seek_from_start of the record at position.
+pub(crate) fn record_seek_from_start(
+    seek_from_start_base: u64,
+    record_fields: &RecordFields,
+    position: u32,
+    is_ipv6: bool,
+) -> u64 {
+    let record_len = if is_ipv6 {
+        record_fields.record_bytes_len_for_ipv6()
+    } else {
+        record_fields.record_bytes_len_for_ipv4()
+    };
+
+    seek_from_start_base + record_len as u64 * position as u64
+}
+
+/// The ip at the start of bytes.
+fn ip_from_bytes(bytes: &[u8], is_ipv6: bool) -> IpAddr {
+    if is_ipv6 {
+        Ipv6Addr::from(u128::from_ne_bytes(bytes[0..16].try_into().unwrap())).into()
+    } else {
+        Ipv4Addr::from(u32::from_ne_bytes(bytes[0..4].try_into().unwrap())).into()
+    }
+}
+
+//
+/// The binary search over the records, shared by the async [`Inner::query`] and the sync [`search`].
+///
+/// The caller reads the record at [`position`](Self::position) and passes it to [`step`](Self::step).
+#[derive(Debug)]
+pub(crate) struct Search {
+    ip: IpAddr,
+    low: u32,
+    high: u32,
+    count: u32,
+    n_depth: usize,
+}
+
+impl Search {
+    pub(crate) fn new(
         ip: IpAddr,
         PositionRange {
             start: mut low,
             end: mut high,
         }: PositionRange,
-    ) -> Result<Option<(IpAddr, IpAddr, RecordFieldContents)>, Error> {
-        if high > self.count {
-            high = self.count;
+        count: u32,
+    ) -> Self {
+        if high > count {
+            high = count;
         }
         if low > high {
             low = high;
         }
 
-        let mut n_depth = 0;
-        while low <= high {
-            let mid = (low + high) >> 1;
+        Self {
+            ip,
+            low,
+            high,
+            count,
+            n_depth: 0,
+        }
+    }
 
-            let seek_from_start = self.seek_from_start_base
-                + match ip {
-                    IpAddr::V4(_) => self.record_fields.records_bytes_len_for_ipv4(mid),
-                    IpAddr::V6(_) => self.record_fields.records_bytes_len_for_ipv6(mid),
-                } as u64;
+    /// The position of the record to read next, None when the ip is not found.
+    pub(crate) fn position(&self) -> Option<u32> {
+        (self.low <= self.high).then(|| (self.low + self.high) >> 1)
+    }
 
-            self.stream
-                .seek(SeekFrom::Start(seek_from_start))
-                .await
-                .map_err(Error::SeekFailed)?;
+    /// record is the record at the position, ip_from and then the fields and the ip_from of the next record.
+    ///
+    /// Breaks with the range which contains the ip, or None when the ip is not found.
+    pub(crate) fn step(
+        &mut self,
+        record: &[u8],
+    ) -> Result<ControlFlow<Option<(IpAddr, IpAddr)>>, Error> {
+        let mid = (self.low + self.high) >> 1;
+        let is_ipv6 = self.ip.is_ipv6();
 
-            self.stream
-                .read_exact(&mut self.buf)
-                .await
-                .map_err(Error::ReadFailed)?;
-
-            let ip_from: IpAddr = match ip {
-                IpAddr::V4(_) => {
-                    Ipv4Addr::from(u32::from_ne_bytes(self.buf[0..4].try_into().unwrap())).into()
-                }
-                IpAddr::V6(_) => {
-                    Ipv6Addr::from(u128::from_ne_bytes(self.buf[0..16].try_into().unwrap())).into()
-                }
-            };
-            let ip_to: IpAddr = if high < self.count {
-                match ip {
-                    IpAddr::V4(_) => Ipv4Addr::from(u32::from_ne_bytes(
-                        self.buf[self.buf.len() - 4..self.buf.len()]
-                            .try_into()
-                            .unwrap(),
-                    ))
-                    .into(),
-                    IpAddr::V6(_) => Ipv6Addr::from(u128::from_ne_bytes(
-                        self.buf[self.buf.len() - 16..self.buf.len()]
-                            .try_into()
-                            .unwrap(),
-                    ))
-                    .into(),
-                }
-            } else {
-                match ip_from {
-                    IpAddr::V4(ip_from) => {
-                        Ipv4Addr::from(u32::from(ip_from).saturating_add(1)).into()
-                    }
-                    IpAddr::V6(ip_from) => {
-                        Ipv6Addr::from(u128::from(ip_from).saturating_add(1)).into()
-                    }
-                }
-            };
-
-            if (ip >= ip_from) && (ip < ip_to) {
-                let mut record_field_contents = self.record_field_contents.to_owned();
-                for (n, record_field_content) in record_field_contents.iter_mut().enumerate() {
-                    let index = match ip {
-                        IpAddr::V4(_) => 4 + n * 4,
-                        IpAddr::V6(_) => 16 + n * 4,
-                    };
-
-                    let content_index =
-                        u32::from_ne_bytes(self.buf[index..index + 4].try_into().unwrap());
-
-                    match record_field_content {
-                        RecordFieldContent::COUNTRY(i, _, _) => *i = content_index,
-                        RecordFieldContent::REGION(i, _) => *i = content_index,
-                        RecordFieldContent::CITY(i, _) => *i = content_index,
-                        RecordFieldContent::ISP(i, _) => *i = content_index,
-                        RecordFieldContent::DOMAIN(i, _) => *i = content_index,
-                        //
-                        RecordFieldContent::LATITUDE(v) => {
-                            *v = {
-                                f32::from_ne_bytes(self.buf[index..index + 4].try_into().unwrap())
-                            }
-                        }
-                        RecordFieldContent::LONGITUDE(v) => {
-                            *v = {
-                                f32::from_ne_bytes(self.buf[index..index + 4].try_into().unwrap())
-                            }
-                        }
-                        RecordFieldContent::ZIPCODE(i, _) => *i = content_index,
-                        RecordFieldContent::TIMEZONE(i, _) => *i = content_index,
-                        RecordFieldContent::NETSPEED(i, _) => *i = content_index,
-                        //
-                        RecordFieldContent::PROXYTYPE(i, _) => *i = content_index,
-                        RecordFieldContent::USAGETYPE(i, _) => *i = content_index,
-                        RecordFieldContent::ASN(i, _) => *i = content_index,
-                        RecordFieldContent::AS(i, _) => *i = content_index,
-                        RecordFieldContent::LASTSEEN(i, _) => *i = content_index,
-                        RecordFieldContent::THREAT(i, _) => *i = content_index,
-                        RecordFieldContent::RESIDENTIAL(i, _) => *i = content_index,
-                        RecordFieldContent::PROVIDER(i, _) => *i = content_index,
-                    }
-                }
-
-                return Ok(Some((ip_from, ip_to, record_field_contents)));
-            } else if ip < ip_from {
-                high = mid.saturating_sub(1);
-            } else {
-                low = mid.saturating_add(1);
+        let ip_from = ip_from_bytes(record, is_ipv6);
+        let ip_to = if self.high < self.count {
+            ip_from_bytes(
+                &record[record.len() - if is_ipv6 { 16 } else { 4 }..],
+                is_ipv6,
+            )
+        } else {
+            match ip_from {
+                IpAddr::V4(ip_from) => Ipv4Addr::from(u32::from(ip_from).saturating_add(1)).into(),
+                IpAddr::V6(ip_from) => Ipv6Addr::from(u128::from(ip_from).saturating_add(1)).into(),
             }
+        };
 
-            //
-            //
-            //
-            if high == 0 {
-                return Ok(None);
+        if (self.ip >= ip_from) && (self.ip < ip_to) {
+            return Ok(ControlFlow::Break(Some((ip_from, ip_to))));
+        } else if self.ip < ip_from {
+            self.high = mid.saturating_sub(1);
+        } else {
+            self.low = mid.saturating_add(1);
+        }
+
+        //
+        //
+        //
+        if self.high == 0 {
+            return Ok(ControlFlow::Break(None));
+        }
+        #[allow(clippy::collapsible_else_if)]
+        if self.count == u32::MAX {
+            if self.low == self.count {
+                return Ok(ControlFlow::Break(None));
             }
-            #[allow(clippy::collapsible_else_if)]
-            if self.count == u32::MAX {
-                if low == self.count {
-                    return Ok(None);
-                }
-            } else {
-                if low > self.count {
-                    return Ok(None);
-                }
+        } else {
+            if self.low > self.count {
+                return Ok(ControlFlow::Break(None));
             }
+        }
 
-            if n_depth > 30 {
-                return Err(Error::MaxDepthReached);
-            }
+        if self.n_depth > 30 {
+            return Err(Error::MaxDepthReached);
+        }
 
-            n_depth += 1;
+        self.n_depth += 1;
+
+        Ok(ControlFlow::Continue(()))
+    }
+}
+
+/// [`Search`] with a sync read, it gets the seek_from_start and the len, and returns the record bytes.
+pub(crate) fn search<B, F>(
+    ip: IpAddr,
+    position_range: PositionRange,
+    count: u32,
+    seek_from_start_base: u64,
+    record_fields: &RecordFields,
+    mut read: F,
+) -> Result<Option<(IpAddr, IpAddr, B)>, Error>
+where
+    B: AsRef<[u8]>,
+    F: FnMut(u64, usize) -> Result<B, IoError>,
+{
+    let len = record_bytes_len_with_double_ip(record_fields, ip.is_ipv6());
+
+    let mut search = Search::new(ip, position_range, count);
+    while let Some(position) = search.position() {
+        let seek_from_start =
+            record_seek_from_start(seek_from_start_base, record_fields, position, ip.is_ipv6());
+        let record =
+            read(seek_from_start, len).map_err(|err| Error::ReadFailed(seek_from_start, err))?;
+
+        if let ControlFlow::Break(range) = search.step(record.as_ref())? {
+            return Ok(range.map(|(ip_from, ip_to)| (ip_from, ip_to, record)));
         }
+    }
 
-        Ok(None)
+    Ok(None)
+}
+
+/// The len of a record and the ip_from of the next record.
+pub(crate) fn record_bytes_len_with_double_ip(
+    record_fields: &RecordFields,
+    is_ipv6: bool,
+) -> usize {
+    if is_ipv6 {
+        record_fields.record_bytes_len_for_ipv6_with_double_ip() as usize
+    } else {
+        record_fields.record_bytes_len_for_ipv4_with_double_ip() as usize
     }
 }
@@ -4,5 +4,9 @@ pub mod v4_querier;
 pub mod v6_querier;
 
 pub use error::Error;
+pub(crate) use inner::{
+    record_bytes_len_with_double_ip, record_field_contents_from_bytes, record_seek_from_start,
+    search, Search,
+};
 pub use v4_querier::V4Querier;
 pub use v6_querier::V6Querier;
@@ -50,7 +50,9 @@ pub enum NewError {
 
 impl core::fmt::Display for NewError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{self:?}")
+        match self {
+            Self::RecordFieldsMissing => write!(f, "record fields of the header are missing"),
+        }
     }
 }
 
@@ -70,4 +72,12 @@ where
     ) -> Result<Option<(IpAddr, IpAddr, RecordFieldContents)>, Error> {
         self.inner.query(ip.into(), position_range).await
     }
+
+    /// Reads the record at position, ip_to is the ip_from of the next record (exclusive).
+    pub async fn read(
+        &mut self,
+        position: u32,
+    ) -> Result<Option<(IpAddr, IpAddr, RecordFieldContents)>, Error> {
+        self.inner.read(position, false).await
+    }
 }
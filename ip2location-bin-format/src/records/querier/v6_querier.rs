@@ -57,7 +57,10 @@ pub enum NewError {
 
 impl core::fmt::Display for NewError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{self:?}")
+        match self {
+            Self::RecordFieldsMissing => write!(f, "record fields of the header are missing"),
+            Self::Unsupported => write!(f, "the BIN has no ipv6 records"),
+        }
     }
 }
 
@@ -79,4 +82,12 @@ where
 
         self.inner.query(ip.into(), position_range).await
     }
+
+    /// Reads the record at position, ip_to is the ip_from of the next record (exclusive).
+    pub async fn read(
+        &mut self,
+        position: u32,
+    ) -> Result<Option<(IpAddr, IpAddr, RecordFieldContents)>, Error> {
+        self.inner.read(position, true).await
+    }
 }
@@ -0,0 +1,243 @@
+//! A database which swaps to a new BIN, e.g. the monthly LITE update.
+//!
+//! The lookups in flight keep the old database until they finish.
+//! Replace the file by rename, the old database may still read the old file.
+//! A memory mapped database, e.g. by `Bytes::open_mmap`, must only be replaced by rename,
+//! writing the file in place is undefined behavior.
+
+use core::future::Future;
+use std::{
+    io::Error as IoError,
+    path::{Path, PathBuf},
+    sync::{Arc, RwLock},
+    time::SystemTime,
+};
+
+use futures_util::future::BoxFuture;
+
+use crate::{header::Schema as HeaderSchema, querier::Querier};
+
+//
+/// The database which [`ReloadableDatabase`] swaps, the header of the new BIN is checked against it.
+pub trait DatabaseHeader {
+    fn header(&self) -> &HeaderSchema;
+}
+
+impl<S> DatabaseHeader for Querier<S> {
+    fn header(&self) -> &HeaderSchema {
+        &self.header
+    }
+}
+
+//
+type Loader<D, E> = Box<dyn Fn(PathBuf) -> BoxFuture<'static, Result<D, E>> + Send + Sync>;
+
+pub struct ReloadableDatabase<D, E> {
+    path: PathBuf,
+    loader: Loader<D, E>,
+    current: RwLock<(Arc<D>, Option<SystemTime>)>,
+}
+
+impl<D, E> core::fmt::Debug for ReloadableDatabase<D, E>
+where
+    D: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ReloadableDatabase")
+            .field("path", &self.path)
+            .field("current", &self.current)
+            .finish()
+    }
+}
+
+//
+//
+//
+impl<D, E> ReloadableDatabase<D, E>
+where
+    D: DatabaseHeader,
+{
+    /// The loader opens the database at the path, e.g. `Querier::open_read_at`.
+    pub async fn new<F, Fut>(
+        path: impl AsRef<Path>,
+        loader: F,
+    ) -> Result<Self, DatabaseReloadError<E>>
+    where
+        F: Fn(PathBuf) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<D, E>> + Send + 'static,
+    {
+        let path = path.as_ref().to_owned();
+        let loader: Loader<D, E> = Box::new(move |path| Box::pin(loader(path)));
+
+        let modified = modified(&path)?;
+        let database = loader(path.clone())
+            .await
+            .map_err(DatabaseReloadError::DatabaseNewError)?;
+
+        Ok(Self {
+            path,
+            loader,
+            current: RwLock::new((Arc::new(database), modified)),
+        })
+    }
+
+    /// The current database, it is not swapped under the holder.
+    pub fn database(&self) -> Arc<D> {
+        self.current
+            .read()
+            .unwrap_or_else(|err| err.into_inner())
+            .0
+            .clone()
+    }
+
+    /// Opens the file again and swaps to it, the header must have the same type and sub type.
+    pub async fn reload(&self) -> Result<(), DatabaseReloadError<E>> {
+        let modified = modified(&self.path)?;
+        let database = (self.loader)(self.path.clone())
+            .await
+            .map_err(DatabaseReloadError::DatabaseNewError)?;
+
+        let header = database.header();
+        let header_current = *self.database().header();
+        if header.r#type != header_current.r#type || header.sub_type.0 != header_current.sub_type.0
+        {
+            return Err(DatabaseReloadError::HeaderMismatch);
+        }
+
+        *self.current.write().unwrap_or_else(|err| err.into_inner()) =
+            (Arc::new(database), modified);
+
+        Ok(())
+    }
+
+    /// Reloads when the mtime of the file is changed, returns whether it is reloaded.
+    pub async fn reload_if_modified(&self) -> Result<bool, DatabaseReloadError<E>> {
+        let modified = modified(&self.path)?;
+        let modified_current = self.current.read().unwrap_or_else(|err| err.into_inner()).1;
+
+        if modified.is_some() && modified == modified_current {
+            return Ok(false);
+        }
+
+        self.reload().await?;
+
+        Ok(true)
+    }
+}
+
+fn modified<E>(path: &Path) -> Result<Option<SystemTime>, DatabaseReloadError<E>> {
+    let metadata = std::fs::metadata(path).map_err(DatabaseReloadError::MetadataReadFailed)?;
+    // None on the platforms without mtime, then it is always reloaded.
+    Ok(metadata.modified().ok())
+}
+
+//
+#[derive(Debug)]
+pub enum DatabaseReloadError<E> {
+    MetadataReadFailed(IoError),
+    DatabaseNewError(E),
+    HeaderMismatch,
+}
+
+impl<E> core::fmt::Display for DatabaseReloadError<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::MetadataReadFailed(_) => write!(f, "read metadata failed"),
+            Self::DatabaseNewError(_) => write!(f, "create database failed"),
+            Self::HeaderMismatch => write!(f, "the type or sub type of the new BIN mismatch"),
+        }
+    }
+}
+
+impl<E> std::error::Error for DatabaseReloadError<E>
+where
+    E: std::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::MetadataReadFailed(err) => Some(err),
+            Self::DatabaseNewError(err) => Some(err),
+            Self::HeaderMismatch => None,
+        }
+    }
+}
+
+#[cfg(all(test, any(unix, windows)))]
+mod tests {
+    use super::*;
+
+    use std::net::Ipv4Addr;
+
+    use crate::{
+        header::schema::SchemaType,
+        record_field::RecordFieldContent,
+        test_helper::{bin_bytes, Location, BRISBANE, MELBOURNE, SYDNEY},
+    };
+
+    fn write_bin(
+        path: &Path,
+        sub_type: u8,
+        location: &Location,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let bytes = bin_bytes(
+            SchemaType::IP2Location,
+            sub_type,
+            &[("1.0.0.0", "1.0.0.255", location)],
+        )?;
+
+        // Replaced by rename, the old database still reads the old file.
+        let path_tmp = path.with_extension("BIN.tmp");
+        std::fs::write(&path_tmp, bytes)?;
+        std::fs::rename(&path_tmp, path)?;
+
+        Ok(())
+    }
+
+    async fn city<S>(db: &Querier<S>) -> Result<Option<Box<str>>, Box<dyn std::error::Error>>
+    where
+        S: futures_util::AsyncSeek + futures_util::AsyncRead + Unpin,
+    {
+        let (_, _, record_field_contents) = db
+            .lookup(Ipv4Addr::new(1, 0, 0, 1).into(), None)
+            .await?
+            .unwrap();
+        Ok(record_field_contents.iter().find_map(|x| match x {
+            RecordFieldContent::CITY(_, v) => v.clone(),
+            _ => None,
+        }))
+    }
+
+    #[tokio::test]
+    async fn test_reload() -> Result<(), Box<dyn std::error::Error>> {
+        let path = std::env::temp_dir().join(format!(
+            "ip2location_bin_format_reloadable_{}.BIN",
+            std::process::id()
+        ));
+
+        write_bin(&path, 3, &BRISBANE)?;
+
+        let db = ReloadableDatabase::new(&path, Querier::open_read_at).await?;
+        assert!(!db.reload_if_modified().await?);
+
+        let db_old = db.database();
+
+        write_bin(&path, 3, &MELBOURNE)?;
+        db.reload().await?;
+        assert!(!db.reload_if_modified().await?);
+
+        assert_eq!(city(&db.database()).await?.as_deref(), Some("Melbourne"));
+        assert_eq!(city(&db_old).await?.as_deref(), Some("Brisbane"));
+
+        //
+        write_bin(&path, 5, &SYDNEY)?;
+        assert!(matches!(
+            db.reload().await,
+            Err(DatabaseReloadError::HeaderMismatch)
+        ));
+        assert_eq!(city(&db.database()).await?.as_deref(), Some("Melbourne"));
+
+        std::fs::remove_file(&path)?;
+
+        Ok(())
+    }
+}
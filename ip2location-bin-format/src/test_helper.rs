@@ -2,6 +2,12 @@ use std::path::{Path, PathBuf};
 
 use walkdir::WalkDir;
 
+use crate::{
+    header::schema::{Schema as HeaderSchema, SchemaSubType, SchemaType},
+    record_field::RecordFieldContent,
+    Builder,
+};
+
 pub(crate) fn ip2location_bin_files() -> Vec<PathBuf> {
     WalkDir::new(Path::new("data"))
         .follow_links(true)
@@ -33,3 +39,84 @@ pub(crate) fn ip2proxy_bin_files() -> Vec<PathBuf> {
         .map(|e| e.path().into())
         .collect()
 }
+
+//
+pub(crate) struct Location {
+    pub(crate) country: (&'static str, &'static str),
+    pub(crate) city: &'static str,
+    pub(crate) isp: &'static str,
+    pub(crate) latitude: f32,
+}
+
+pub(crate) const MOUNTAIN_VIEW: Location = Location {
+    country: ("US", "United States of America"),
+    city: "Mountain View",
+    isp: "Google LLC",
+    latitude: 37.40599,
+};
+
+pub(crate) const BRISBANE: Location = Location {
+    country: ("AU", "Australia"),
+    city: "Brisbane",
+    isp: "Cloudflare Inc",
+    latitude: -27.46794,
+};
+
+pub(crate) const TOKYO: Location = Location {
+    country: ("JP", "Japan"),
+    city: "Tokyo",
+    isp: "Example Networks",
+    latitude: 35.6895,
+};
+
+pub(crate) const MELBOURNE: Location = Location {
+    country: ("AU", "Australia"),
+    city: "Melbourne",
+    isp: "Telstra Corporation",
+    latitude: -37.814,
+};
+
+pub(crate) const SYDNEY: Location = Location {
+    country: ("AU", "Australia"),
+    city: "Sydney",
+    isp: "Optus",
+    latitude: -33.86785,
+};
+
+/// The BIN of the ranges, the fields which are not in the layout are skipped,
+/// the proxy type of IP2Proxy is `PUB`.
+pub(crate) fn bin_bytes(
+    r#type: SchemaType,
+    sub_type: u8,
+    ranges: &[(&str, &str, &Location)],
+) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    let mut builder = Builder::new(HeaderSchema {
+        r#type,
+        sub_type: SchemaSubType(sub_type),
+        date: (22, 11, 1),
+        ..Default::default()
+    })?;
+
+    for (ip_from, ip_to, location) in ranges {
+        let mut record_field_contents = builder.record_fields().to_contents();
+        for record_field_content in record_field_contents.iter_mut() {
+            match record_field_content {
+                RecordFieldContent::COUNTRY(_, v, v_name) => {
+                    *v = Some(location.country.0.into());
+                    *v_name = Some(location.country.1.into());
+                }
+                RecordFieldContent::CITY(_, v) => *v = Some(location.city.into()),
+                RecordFieldContent::ISP(_, v) => *v = Some(location.isp.into()),
+                RecordFieldContent::LATITUDE(v) => *v = location.latitude,
+                RecordFieldContent::PROXYTYPE(_, v) => *v = Some("PUB".into()),
+                _ => {}
+            }
+        }
+        builder.append(ip_from.parse()?, ip_to.parse()?, record_field_contents)?;
+    }
+
+    let mut bytes = vec![];
+    builder.finish(&mut bytes)?;
+
+    Ok(bytes)
+}
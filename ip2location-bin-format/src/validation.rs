@@ -0,0 +1,427 @@
+//! Integrity validation of the whole BIN, beyond the header verification.
+//!
+//! It is for rejecting corrupted downloads before deploying them, it reads every index entry, record and content.
+
+use core::ops::ControlFlow;
+use std::{
+    collections::HashSet,
+    io::{Cursor, Error as IoError},
+};
+
+use deadpool::unmanaged::PoolError;
+
+use crate::{
+    content::querier::{str_mut, COUNTRY_NAME_INDEX_OFFSET},
+    header::{
+        parser::ParseError as HeaderParseError, Parser as HeaderParser, Schema as HeaderSchema,
+        HEADER_LEN,
+    },
+    index::{INDEX_ELEMENT_LEN, INDEX_LEN},
+    record_field::{RecordField, RecordFieldContent},
+    records::querier::record_field_contents_from_bytes,
+};
+
+//
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValidationFinding {
+    TotalSizeMismatch {
+        total_size: u32,
+        len: usize,
+    },
+    /// The region (e.g. "v4 index") is not inside the bytes, it is not validated.
+    RegionOutOfBounds(&'static str),
+    /// The start is greater than the end, or the end is not a record.
+    IndexPositionInvalid {
+        is_ipv6: bool,
+        prefix: u32,
+        start: u32,
+        end: u32,
+    },
+    IndexNotMonotonic {
+        is_ipv6: bool,
+        prefix: u32,
+    },
+    /// The records between the start and the end do not cover the ips of the prefix.
+    IndexInconsistent {
+        is_ipv6: bool,
+        prefix: u32,
+    },
+    /// The ip_from is not greater than the previous one, so the ranges are not sorted or overlapping.
+    RecordsNotSorted {
+        is_ipv6: bool,
+        position: u32,
+    },
+    /// The position is the first record with the offset.
+    ContentOutOfBounds {
+        is_ipv6: bool,
+        position: u32,
+        record_field: RecordField,
+        offset: u32,
+    },
+    ContentUtf8Invalid {
+        is_ipv6: bool,
+        position: u32,
+        record_field: RecordField,
+        offset: u32,
+    },
+    LatitudeOutOfRange {
+        is_ipv6: bool,
+        position: u32,
+        latitude: f32,
+    },
+    LongitudeOutOfRange {
+        is_ipv6: bool,
+        position: u32,
+        longitude: f32,
+    },
+}
+
+//
+/// All findings of the BIN bytes, it is valid when there is none.
+///
+/// Without a valid header (including [`Schema::verify`](crate::header::Schema::verify)) the rest is not validated, it is an error.
+pub fn validate(bytes: &[u8]) -> Result<Vec<ValidationFinding>, ValidateError> {
+    let mut findings = vec![];
+
+    //
+    let header = parse_header(bytes)?;
+
+    if header.total_size as usize != bytes.len() {
+        findings.push(ValidationFinding::TotalSizeMismatch {
+            total_size: header.total_size,
+            len: bytes.len(),
+        });
+    }
+
+    //
+    validate_family(bytes, &header, false, &mut findings);
+    if header.has_v6() {
+        validate_family(bytes, &header, true, &mut findings);
+    }
+
+    Ok(findings)
+}
+
+fn parse_header(bytes: &[u8]) -> Result<HeaderSchema, ValidateError> {
+    let bytes = bytes
+        .get(..HEADER_LEN as usize)
+        .ok_or(ValidateError::HeaderIncomplete)?;
+
+    match HeaderParser::new()
+        .parse(&mut Cursor::new(bytes))
+        .map_err(ValidateError::HeaderParseFailed)?
+    {
+        ControlFlow::Continue(_) => Err(ValidateError::HeaderIncomplete),
+        ControlFlow::Break((_, header)) => Ok(header),
+    }
+}
+
+fn validate_family(
+    bytes: &[u8],
+    header: &HeaderSchema,
+    is_ipv6: bool,
+    findings: &mut Vec<ValidationFinding>,
+) {
+    let record_fields = match header.record_fields() {
+        Some(x) => x,
+        None => return,
+    };
+
+    let (index_seek_from_start, records_seek_from_start, count, record_len, ip_len) = if is_ipv6 {
+        (
+            header.v6_index_seek_from_start().unwrap_or_default() as usize,
+            header.v6_records_seek_from_start().unwrap_or_default() as usize,
+            header.v6_records_count,
+            record_fields.records_bytes_len_for_ipv6(1) as usize,
+            16,
+        )
+    } else {
+        (
+            header.v4_index_seek_from_start() as usize,
+            header.v4_records_seek_from_start() as usize,
+            header.v4_records_count,
+            record_fields.records_bytes_len_for_ipv4(1) as usize,
+            4,
+        )
+    };
+
+    //
+    let records = match bytes
+        .get(records_seek_from_start..records_seek_from_start + record_len * count as usize)
+    {
+        Some(x) => x,
+        None => {
+            findings.push(ValidationFinding::RegionOutOfBounds(if is_ipv6 {
+                "v6 records"
+            } else {
+                "v4 records"
+            }));
+            return;
+        }
+    };
+    let record = |position: u32| {
+        let start = record_len * position as usize;
+        &records[start..start + record_len]
+    };
+    let ip_from = |position: u32| -> u128 {
+        let bytes = &record(position)[..ip_len];
+        if is_ipv6 {
+            u128::from_ne_bytes(bytes.try_into().unwrap())
+        } else {
+            u32::from_ne_bytes(bytes.try_into().unwrap()) as u128
+        }
+    };
+
+    //
+    match bytes.get(index_seek_from_start..index_seek_from_start + INDEX_LEN as usize) {
+        Some(index) => {
+            let mut start_and_end_prev = (0, 0);
+            for (prefix, x) in index
+                .chunks_exact(INDEX_ELEMENT_LEN as usize * 2)
+                .enumerate()
+            {
+                let prefix = prefix as u32;
+                let start = u32::from_ne_bytes(x[..4].try_into().unwrap());
+                let end = u32::from_ne_bytes(x[4..].try_into().unwrap());
+
+                if start > end || end >= count {
+                    findings.push(ValidationFinding::IndexPositionInvalid {
+                        is_ipv6,
+                        prefix,
+                        start,
+                        end,
+                    });
+                    continue;
+                }
+
+                if start < start_and_end_prev.0 || end < start_and_end_prev.1 {
+                    findings.push(ValidationFinding::IndexNotMonotonic { is_ipv6, prefix });
+                }
+                start_and_end_prev = (start, end);
+
+                // The lookups skip it, see `Querier::lookup`.
+                if end == 0 {
+                    continue;
+                }
+
+                let (prefix_first, prefix_last) = if is_ipv6 {
+                    (
+                        (prefix as u128) << 112,
+                        ((prefix as u128) << 112) | (u128::MAX >> 16),
+                    )
+                } else {
+                    ((prefix as u128) << 16, ((prefix as u128) << 16) | 0xffff)
+                };
+                if ip_from(start) > prefix_first
+                    || (end + 1 < count && ip_from(end + 1) <= prefix_last)
+                {
+                    findings.push(ValidationFinding::IndexInconsistent { is_ipv6, prefix });
+                }
+            }
+        }
+        None => {
+            findings.push(ValidationFinding::RegionOutOfBounds(if is_ipv6 {
+                "v6 index"
+            } else {
+                "v4 index"
+            }));
+        }
+    }
+
+    //
+    let record_field_contents = record_fields.to_contents();
+    let mut offsets_validated = HashSet::new();
+    for position in 0..count {
+        if position > 0 && ip_from(position) <= ip_from(position - 1) {
+            findings.push(ValidationFinding::RecordsNotSorted { is_ipv6, position });
+        }
+
+        // The last record only ends the previous range.
+        if position + 1 == count {
+            break;
+        }
+
+        let mut record_field_contents =
+            record_field_contents_from_bytes(&record_field_contents, record(position), is_ipv6);
+        for record_field_content in record_field_contents.iter_mut() {
+            let record_field = record_field_content.record_field();
+
+            let offsets = match record_field_content {
+                RecordFieldContent::COUNTRY(i, _, _) => {
+                    vec![*i, i.saturating_add(COUNTRY_NAME_INDEX_OFFSET as u32)]
+                }
+                RecordFieldContent::LATITUDE(v) => {
+                    if !(-90.0..=90.0).contains(v) {
+                        findings.push(ValidationFinding::LatitudeOutOfRange {
+                            is_ipv6,
+                            position,
+                            latitude: *v,
+                        });
+                    }
+                    continue;
+                }
+                RecordFieldContent::LONGITUDE(v) => {
+                    if !(-180.0..=180.0).contains(v) {
+                        findings.push(ValidationFinding::LongitudeOutOfRange {
+                            is_ipv6,
+                            position,
+                            longitude: *v,
+                        });
+                    }
+                    continue;
+                }
+                _ => match str_mut(record_field_content) {
+                    Some((i, _)) => vec![i],
+                    None => continue,
+                },
+            };
+
+            for offset in offsets {
+                if !offsets_validated.insert(offset) {
+                    continue;
+                }
+
+                let s = bytes.get(offset as usize).and_then(|len| {
+                    bytes.get(offset as usize + 1..offset as usize + 1 + *len as usize)
+                });
+                match s {
+                    Some(s) => {
+                        if core::str::from_utf8(s).is_err() {
+                            findings.push(ValidationFinding::ContentUtf8Invalid {
+                                is_ipv6,
+                                position,
+                                record_field,
+                                offset,
+                            });
+                        }
+                    }
+                    None => findings.push(ValidationFinding::ContentOutOfBounds {
+                        is_ipv6,
+                        position,
+                        record_field,
+                        offset,
+                    }),
+                }
+            }
+        }
+    }
+}
+
+//
+#[derive(Debug)]
+pub enum ValidateError {
+    /// The bytes are shorter than the header.
+    HeaderIncomplete,
+    HeaderParseFailed(HeaderParseError),
+    /// Only of [`Querier::validate`](crate::querier::Querier::validate), when it reads the BIN.
+    PoolGetFailed(PoolError),
+    /// Only of [`Querier::validate`](crate::querier::Querier::validate), when it reads the BIN.
+    ReadFailed(IoError),
+}
+
+impl core::fmt::Display for ValidateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::HeaderIncomplete => write!(f, "the BIN is shorter than the header"),
+            Self::HeaderParseFailed(_) => write!(f, "parse header failed"),
+            Self::PoolGetFailed(_) => write!(f, "get stream from pool failed"),
+            Self::ReadFailed(_) => write!(f, "read BIN failed"),
+        }
+    }
+}
+
+impl std::error::Error for ValidateError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::HeaderIncomplete => None,
+            Self::HeaderParseFailed(err) => Some(err),
+            Self::PoolGetFailed(err) => Some(err),
+            Self::ReadFailed(err) => Some(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{
+        header::schema::SchemaType,
+        test_helper::{bin_bytes, BRISBANE, MOUNTAIN_VIEW},
+    };
+
+    #[test]
+    fn test_validate() -> Result<(), Box<dyn std::error::Error>> {
+        let bytes = bin_bytes(
+            SchemaType::IP2Location,
+            5,
+            &[
+                ("1.0.0.0", "1.0.0.255", &BRISBANE),
+                ("8.8.8.0", "8.8.8.255", &MOUNTAIN_VIEW),
+                ("2001:4860::", "2001:4860::ffff", &MOUNTAIN_VIEW),
+            ],
+        )?;
+
+        assert_eq!(validate(&bytes)?, vec![]);
+
+        //
+        let header = parse_header(&bytes)?;
+        let record_len = header
+            .record_fields()
+            .unwrap()
+            .records_bytes_len_for_ipv4(1) as usize;
+        let record_start =
+            |position: usize| header.v4_records_seek_from_start() as usize + record_len * position;
+
+        // IP, COUNTRY, REGION, CITY, LATITUDE, LONGITUDE
+        let mut bytes_corrupted = bytes.clone();
+        let i = record_start(1);
+        bytes_corrupted[i..i + 4].copy_from_slice(&0_u32.to_ne_bytes());
+        bytes_corrupted[i + 4..i + 8].copy_from_slice(&u32::MAX.to_ne_bytes());
+        bytes_corrupted[i + 16..i + 20].copy_from_slice(&100_f32.to_ne_bytes());
+        let findings = validate(&bytes_corrupted)?;
+        for finding in [
+            ValidationFinding::RecordsNotSorted {
+                is_ipv6: false,
+                position: 1,
+            },
+            ValidationFinding::ContentOutOfBounds {
+                is_ipv6: false,
+                position: 1,
+                record_field: RecordField::COUNTRY,
+                offset: u32::MAX,
+            },
+            ValidationFinding::LatitudeOutOfRange {
+                is_ipv6: false,
+                position: 1,
+                latitude: 100.0,
+            },
+        ] {
+            assert!(findings.contains(&finding), "{finding:?} {findings:?}");
+        }
+
+        //
+        let findings = validate(&bytes[..bytes.len() - 1])?;
+        assert_eq!(
+            findings[0],
+            ValidationFinding::TotalSizeMismatch {
+                total_size: bytes.len() as u32,
+                len: bytes.len() - 1
+            }
+        );
+
+        assert!(matches!(
+            validate(&bytes[..10]),
+            Err(ValidateError::HeaderIncomplete)
+        ));
+
+        let mut bytes_header_invalid = bytes.clone();
+        bytes_header_invalid[0] = 0;
+        assert!(matches!(
+            validate(&bytes_header_invalid),
+            Err(ValidateError::HeaderParseFailed(_))
+        ));
+
+        Ok(())
+    }
+}
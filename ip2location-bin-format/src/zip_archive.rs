@@ -0,0 +1,126 @@
+//! The BIN and CSV files are distributed as ZIP archives,
+//! e.g. `IP2LOCATION-LITE-DB11.BIN.ZIP` or `IP2LOCATION-LITE-DB11.IPV6.CSV.ZIP`.
+
+use std::io::{Error as IoError, Read, Seek};
+
+use zip::{result::ZipError, ZipArchive};
+
+// The LITE BINs are about a few hundred MB, the larger ones grow by read_to_end.
+const SIZE_PREALLOCATED_MAX: u64 = 256 * 1024 * 1024;
+
+//
+/// The BIN entry of the archive, the IPV6 one if there are both.
+pub fn read_bin<R: Read + Seek>(reader: R) -> Result<Vec<u8>, ReadError> {
+    read_entry(reader, "BIN")
+}
+
+/// The CSV entry of the archive, the IPV6 one if there are both,
+/// e.g. for [`csv::Reader::from_reader`](https://docs.rs/csv/1/csv/struct.Reader.html#method.from_reader).
+pub fn read_csv<R: Read + Seek>(reader: R) -> Result<Vec<u8>, ReadError> {
+    read_entry(reader, "CSV")
+}
+
+/// The entry whose name ends with the extension, case insensitive.
+pub fn read_entry<R: Read + Seek>(reader: R, extension: &str) -> Result<Vec<u8>, ReadError> {
+    let mut archive = ZipArchive::new(reader).map_err(ReadError::OpenFailed)?;
+
+    let suffix = format!(".{}", extension.to_ascii_uppercase());
+    let mut names = archive
+        .file_names()
+        .filter(|name| !name.ends_with('/') && name.to_ascii_uppercase().ends_with(&suffix))
+        .map(ToOwned::to_owned)
+        .collect::<Vec<_>>();
+    names.sort_by_key(|name| !name.to_ascii_uppercase().contains(".IPV6."));
+    let name = names
+        .into_iter()
+        .next()
+        .ok_or_else(|| ReadError::EntryMissing(extension.into()))?;
+
+    let mut file = archive.by_name(&name).map_err(ReadError::EntryOpenFailed)?;
+    // The size is of the archive, it is not trusted beyond a preallocation cap.
+    let mut bytes = Vec::with_capacity(file.size().min(SIZE_PREALLOCATED_MAX) as usize);
+    file.read_to_end(&mut bytes)
+        .map_err(ReadError::EntryReadFailed)?;
+
+    Ok(bytes)
+}
+
+//
+#[derive(Debug)]
+pub enum ReadError {
+    OpenFailed(ZipError),
+    EntryMissing(Box<str>),
+    EntryOpenFailed(ZipError),
+    EntryReadFailed(IoError),
+}
+
+impl core::fmt::Display for ReadError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::OpenFailed(_) => write!(f, "open ZIP archive failed"),
+            Self::EntryMissing(extension) => write!(f, "no {extension} entry in the ZIP archive"),
+            Self::EntryOpenFailed(_) => write!(f, "open ZIP entry failed"),
+            Self::EntryReadFailed(_) => write!(f, "decompress ZIP entry failed"),
+        }
+    }
+}
+
+impl std::error::Error for ReadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::OpenFailed(err) | Self::EntryOpenFailed(err) => Some(err),
+            Self::EntryReadFailed(err) => Some(err),
+            Self::EntryMissing(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::io::{Cursor, Write as _};
+
+    use zip::{write::SimpleFileOptions, CompressionMethod, ZipWriter};
+
+    fn archive(entries: &[(&str, &[u8])]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+        let mut w = ZipWriter::new(Cursor::new(vec![]));
+        for (name, bytes) in entries {
+            w.start_file(
+                *name,
+                SimpleFileOptions::default().compression_method(CompressionMethod::Deflated),
+            )?;
+            w.write_all(bytes)?;
+        }
+        Ok(w.finish()?.into_inner())
+    }
+
+    #[test]
+    fn test_read_entry() -> Result<(), Box<dyn std::error::Error>> {
+        let zip = archive(&[
+            ("README_LITE.TXT", b"readme"),
+            ("LICENSE_LITE.TXT", b"license"),
+            ("IP2LOCATION-LITE-DB11.BIN", b"v4"),
+        ])?;
+        assert_eq!(read_bin(Cursor::new(&zip))?, b"v4");
+        assert!(matches!(
+            read_csv(Cursor::new(&zip)),
+            Err(ReadError::EntryMissing(x)) if x.as_ref() == "CSV"
+        ));
+
+        let zip = archive(&[
+            ("IP2LOCATION-LITE-DB11.BIN", b"v4"),
+            ("IP2LOCATION-LITE-DB11.IPV6.BIN", b"v6"),
+            ("IP2LOCATION-LITE-DB11.IPV6.csv", b"csv"),
+        ])?;
+        assert_eq!(read_bin(Cursor::new(&zip))?, b"v6");
+        assert_eq!(read_csv(Cursor::new(&zip))?, b"csv");
+
+        assert!(matches!(
+            read_bin(Cursor::new(b"not a zip")),
+            Err(ReadError::OpenFailed(_))
+        ));
+
+        Ok(())
+    }
+}
@@ -12,6 +12,49 @@ pub static RECORDS: once_cell::sync::Lazy<Records> = once_cell::sync::Lazy::new(
     Records::from_csv(csv.as_bytes()).unwrap()
 });
 
+#[cfg(feature = "once_cell")]
+pub static RECORDS_COUNTRY_CODE_AND_LANGUAGE_CODE_MAP: once_cell::sync::Lazy<
+    std::collections::HashMap<
+        (country_code::CountryCode, language_code::LanguageCode),
+        Vec<&'static Record>,
+    >,
+> = once_cell::sync::Lazy::new(|| {
+    let mut map = std::collections::HashMap::<_, Vec<&'static Record>>::new();
+    for x in RECORDS.iter() {
+        map.entry((
+            x.country_alpha2_code.to_owned(),
+            x.lang.language_code.to_owned(),
+        ))
+        .or_default()
+        .push(x);
+    }
+    map
+});
+
+/// The continent name of the country in the language, with the fallbacks,
+/// e.g. `zh-TW`, then `zh`, then any `zh-*`, then `en`.
+#[cfg(feature = "once_cell")]
+pub fn continent_name(
+    country_code: &country_code::CountryCode,
+    lang: &language_code::LanguageTag,
+) -> Option<&'static str> {
+    use language_code::{LanguageCode, LanguageTag};
+
+    let find = |lang: &LanguageTag| {
+        let records = RECORDS_COUNTRY_CODE_AND_LANGUAGE_CODE_MAP
+            .get(&(country_code.to_owned(), lang.language_code.to_owned()))?;
+        records
+            .iter()
+            .find(|x| &x.lang == lang)
+            .or_else(|| records.iter().find(|x| x.lang.country_code.is_none()))
+            .or_else(|| records.first())
+    };
+
+    find(lang)
+        .or_else(|| find(&LanguageTag::new(LanguageCode::en, None)))
+        .map(|x| x.continent.as_ref())
+}
+
 //
 #[derive(Debug, Clone)]
 pub struct Records(pub Vec<Record>);
@@ -52,8 +95,17 @@ pub enum RecordsFromCsvError {
 
 impl core::fmt::Display for RecordsFromCsvError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{self:?}")
+        match self {
+            Self::CsvParseFailed(_) => write!(f, "parse CSV failed"),
+            Self::RecordDeFailed(_) => write!(f, "deserialize record failed"),
+        }
     }
 }
 
-impl std::error::Error for RecordsFromCsvError {}
+impl std::error::Error for RecordsFromCsvError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::CsvParseFailed(err) | Self::RecordDeFailed(err) => Some(err),
+        }
+    }
+}
@@ -26,3 +26,43 @@ fn test_static() {
         .unwrap();
     println!("{record:?}");
 }
+
+#[test]
+fn test_continent_name() {
+    use ip2location_continent_multilingual::continent_name;
+
+    let zh_tw = LanguageTag::new(LanguageCode::zh, Some(CountryCode::TW));
+    let zh_cn = LanguageTag::new(LanguageCode::zh, Some(CountryCode::CN));
+    assert_eq!(
+        continent_name(&CountryCode::US, &"zh-TW".parse().unwrap()),
+        continent_name(&CountryCode::US, &zh_tw)
+    );
+    assert_ne!(
+        continent_name(&CountryCode::US, &zh_tw),
+        continent_name(&CountryCode::US, &LanguageTag::new(LanguageCode::en, None))
+    );
+    // no zh-HK, no zh
+    assert_eq!(
+        continent_name(
+            &CountryCode::US,
+            &LanguageTag::new(LanguageCode::zh, Some(CountryCode::HK))
+        ),
+        continent_name(&CountryCode::US, &zh_cn)
+    );
+    // no zu
+    assert_eq!(
+        continent_name(&CountryCode::US, &LanguageTag::new(LanguageCode::zu, None)),
+        Some("North America")
+    );
+    assert_eq!(
+        continent_name(
+            &CountryCode::US,
+            &LanguageTag::new(LanguageCode::en, Some(CountryCode::GB))
+        ),
+        Some("North America")
+    );
+    assert_eq!(
+        continent_name(&CountryCode::Other("ZZ".into()), &zh_tw),
+        None
+    );
+}
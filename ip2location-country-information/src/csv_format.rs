@@ -64,8 +64,17 @@ pub enum RecordsFromCsvError {
 
 impl core::fmt::Display for RecordsFromCsvError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{self:?}")
+        match self {
+            Self::CsvParseFailed(_) => write!(f, "parse CSV failed"),
+            Self::RecordDeFailed(_) => write!(f, "deserialize record failed"),
+        }
     }
 }
 
-impl std::error::Error for RecordsFromCsvError {}
+impl std::error::Error for RecordsFromCsvError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::CsvParseFailed(err) | Self::RecordDeFailed(err) => Some(err),
+        }
+    }
+}
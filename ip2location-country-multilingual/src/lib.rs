@@ -4,5 +4,5 @@ pub mod record;
 
 pub use csv_format::Records;
 #[cfg(feature = "once_cell")]
-pub use csv_format::RECORDS;
+pub use csv_format::{country_name, RECORDS, RECORDS_COUNTRY_CODE_AND_LANGUAGE_CODE_MAP};
 pub use record::Record;
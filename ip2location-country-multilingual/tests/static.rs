@@ -26,3 +26,40 @@ fn test_static() {
         .unwrap();
     println!("{record:?}");
 }
+
+#[test]
+fn test_country_name() {
+    use ip2location_country_multilingual::country_name;
+
+    let zh_tw = LanguageTag::new(LanguageCode::zh, Some(CountryCode::TW));
+    let zh_cn = LanguageTag::new(LanguageCode::zh, Some(CountryCode::CN));
+    assert_eq!(
+        country_name(&CountryCode::US, &"zh-TW".parse().unwrap()),
+        country_name(&CountryCode::US, &zh_tw)
+    );
+    assert_ne!(
+        country_name(&CountryCode::US, &zh_tw),
+        country_name(&CountryCode::US, &LanguageTag::new(LanguageCode::en, None))
+    );
+    // no zh-HK, no zh
+    assert_eq!(
+        country_name(
+            &CountryCode::US,
+            &LanguageTag::new(LanguageCode::zh, Some(CountryCode::HK))
+        ),
+        country_name(&CountryCode::US, &zh_cn)
+    );
+    // no zu
+    assert_eq!(
+        country_name(&CountryCode::US, &LanguageTag::new(LanguageCode::zu, None)),
+        Some("United States of America")
+    );
+    assert_eq!(
+        country_name(
+            &CountryCode::US,
+            &LanguageTag::new(LanguageCode::en, Some(CountryCode::GB))
+        ),
+        Some("United States of America")
+    );
+    assert_eq!(country_name(&CountryCode::Other("ZZ".into()), &zh_tw), None);
+}
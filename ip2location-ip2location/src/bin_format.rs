@@ -1,3 +1,8 @@
+//
+#[cfg(feature = "blocking")]
+pub mod blocking;
+pub mod reloadable;
+
 //
 #[cfg(feature = "tokio_fs")]
 pub type TokioFile = async_compat::Compat<tokio::fs::File>;
@@ -5,14 +10,36 @@ pub type TokioFile = async_compat::Compat<tokio::fs::File>;
 #[cfg(feature = "async_fs")]
 pub type AsyncFsFile = async_fs::File;
 
-use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::{
+    future::Future,
+    io::Error as IoError,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+    pin::Pin,
+    sync::Arc,
+};
 
-use futures_util::{AsyncRead, AsyncSeek};
-use ip2location_bin_format::querier::{
-    LookupError as QuerierLookupError, NewError as QuerierNewError, Querier,
+use futures_util::{future, io::Cursor, AsyncRead, AsyncSeek, Stream, TryStreamExt as _};
+use ip2location_bin_format::{
+    querier::{
+        IpLookupError as QuerierIpLookupError, LookupError as QuerierLookupError,
+        NewError as QuerierNewError, Querier,
+    },
+    Bytes,
 };
 
-use crate::record::{OptionRecord, Record, RecordField};
+#[cfg(feature = "zip")]
+use ip2location_bin_format::zip_archive;
+pub use ip2location_bin_format::{
+    content::{ContentCacheConfig, ContentCachePolicy},
+    info::{DatabaseInfo, Product},
+    ipv6_translation::{Ipv6Translation, Ipv6TranslationMode},
+    range_cache::RangeCacheStats,
+    validation::{validate, ValidationFinding},
+};
+
+pub type DatabaseValidateError = ip2location_bin_format::validation::ValidateError;
+
+use crate::record::{OptionRecord, Record, RecordField, RecordRef, ToRecordError};
 
 //
 pub struct Database<S> {
@@ -30,28 +57,118 @@ where
     }
 }
 
+impl<S> Database<S> {
+    /// The product, DBN or PXN, release date, coverage and record fields of the BIN, see [`Querier::info`].
+    pub fn info(&self) -> &DatabaseInfo {
+        self.inner.info()
+    }
+
+    /// The IPv6 addresses which embed an IPv4 are looked up in the IPv4 records,
+    /// default is [`Ipv6TranslationMode::Ipv4MappedOnly`], [`Ipv6TranslationMode::All`] opts in to 6to4 and Teredo.
+    pub fn with_ipv6_translation_mode(
+        mut self,
+        ipv6_translation_mode: Ipv6TranslationMode,
+    ) -> Self {
+        self.set_ipv6_translation_mode(ipv6_translation_mode);
+        self
+    }
+
+    pub fn set_ipv6_translation_mode(&mut self, ipv6_translation_mode: Ipv6TranslationMode) {
+        self.inner.ipv6_translation_mode = ipv6_translation_mode;
+    }
+
+    pub fn ipv6_translation_mode(&self) -> Ipv6TranslationMode {
+        self.inner.ipv6_translation_mode
+    }
+
+    /// Caches the lookup results by range, like [`ContentCacheConfig::range_cache_capacity`] of the constructors.
+    pub fn with_range_cache(mut self, capacity: core::num::NonZeroUsize) -> Self {
+        self.inner = self.inner.with_range_cache(capacity);
+        self
+    }
+
+    /// The hits and misses of the range cache, none if there is no range cache.
+    pub fn range_cache_stats(&self) -> Option<RangeCacheStats> {
+        self.inner.range_cache_stats()
+    }
+
+    /// The querier is created by the caller, e.g. with [`Querier::new_with_content_cache_config`],
+    /// only the type of the BIN is checked.
+    pub fn from_querier(inner: Querier<S>) -> Result<Self, DatabaseNewError> {
+        if !inner.header.r#type.is_ip2location() {
+            return Err(DatabaseNewError::TypeMismatch);
+        }
+
+        Ok(Self { inner })
+    }
+}
+
+impl<S> Database<S>
+where
+    S: AsyncSeek + AsyncRead + Unpin,
+{
+    /// The streams are opened by stream_factory, e.g. over object store blobs, see [`Querier::new`].
+    pub async fn with_stream_factory<F>(
+        stream_factory: F,
+        pool_max_size: usize,
+    ) -> Result<Self, DatabaseNewError>
+    where
+        F: FnMut() -> Pin<Box<dyn Future<Output = Result<S, IoError>> + Send + 'static>>,
+    {
+        let inner = Querier::new(stream_factory, pool_max_size)
+            .await
+            .map_err(DatabaseNewError::QuerierNewError)?;
+
+        Self::from_querier(inner)
+    }
+}
+
+impl Database<Cursor<Arc<[u8]>>> {
+    /// The pooled streams are cursors over the shared bytes,
+    /// unlike [`from_bytes`](Database::from_bytes) the lookups seek and read like a file.
+    pub async fn from_shared_bytes(
+        bytes: impl Into<Arc<[u8]>>,
+        pool_max_size: usize,
+    ) -> Result<Self, DatabaseNewError> {
+        let bytes = bytes.into();
+
+        Self::with_stream_factory(
+            move || Box::pin(future::ok(Cursor::new(bytes.clone()))),
+            pool_max_size,
+        )
+        .await
+    }
+}
+
 #[cfg(feature = "tokio_fs")]
 impl Database<async_compat::Compat<tokio::fs::File>> {
     pub async fn new(
         path: impl AsRef<std::path::Path>,
         pool_max_size: usize,
+    ) -> Result<Self, DatabaseNewError> {
+        Self::new_with_content_cache_config(path, pool_max_size, ContentCacheConfig::default())
+            .await
+    }
+
+    /// Like [`new`](Self::new), see [`Querier::new_with_content_cache_config`].
+    pub async fn new_with_content_cache_config(
+        path: impl AsRef<std::path::Path>,
+        pool_max_size: usize,
+        content_cache_config: ContentCacheConfig,
     ) -> Result<Self, DatabaseNewError> {
         use futures_util::TryFutureExt as _;
 
         let path = path.as_ref().to_owned();
 
-        let inner = Querier::new(
+        let inner = Querier::new_with_content_cache_config(
             || Box::pin(tokio::fs::File::open(path.clone()).map_ok(async_compat::Compat::new)),
             pool_max_size,
+            content_cache_config,
         )
         .await
         .map_err(DatabaseNewError::QuerierNewError)?;
 
-        if !inner.header.r#type.is_ip2location() {
-            return Err(DatabaseNewError::TypeMismatch);
-        }
-
-        Ok(Self { inner })
+        Self::from_querier(inner)
     }
 }
 
@@ -60,38 +177,173 @@ impl Database<async_fs::File> {
     pub async fn new(
         path: impl AsRef<std::path::Path>,
         pool_max_size: usize,
+    ) -> Result<Self, DatabaseNewError> {
+        Self::new_with_content_cache_config(path, pool_max_size, ContentCacheConfig::default())
+            .await
+    }
+
+    /// Like [`new`](Self::new), see [`Querier::new_with_content_cache_config`].
+    pub async fn new_with_content_cache_config(
+        path: impl AsRef<std::path::Path>,
+        pool_max_size: usize,
+        content_cache_config: ContentCacheConfig,
     ) -> Result<Self, DatabaseNewError> {
         let path = path.as_ref().to_owned();
 
-        let inner = Querier::new(
+        let inner = Querier::new_with_content_cache_config(
             || Box::pin(async_fs::File::open(path.clone())),
             pool_max_size,
+            content_cache_config,
         )
         .await
         .map_err(DatabaseNewError::QuerierNewError)?;
 
-        if !inner.header.r#type.is_ip2location() {
-            return Err(DatabaseNewError::TypeMismatch);
+        Self::from_querier(inner)
+    }
+}
+
+impl Database<Cursor<Bytes>> {
+    /// The whole BIN is in memory, see [`Querier::from_bytes`].
+    pub async fn from_bytes(bytes: impl Into<Bytes>) -> Result<Self, DatabaseNewError> {
+        let inner = Querier::from_bytes(bytes)
+            .await
+            .map_err(DatabaseNewError::QuerierNewError)?;
+
+        Self::from_querier(inner)
+    }
+
+    /// The BIN is mapped into memory, see [`Bytes::open_mmap`].
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified or truncated while the `Database` is alive,
+    /// replace it by rename, see [`Bytes::open_mmap`].
+    #[cfg(feature = "mmap")]
+    pub async unsafe fn open_mmap(
+        path: impl AsRef<std::path::Path>,
+    ) -> Result<Self, DatabaseNewError> {
+        // SAFETY: the caller guarantees that the file is not modified while it is mapped.
+        let bytes = unsafe { Bytes::open_mmap(path) }
+            .map_err(|err| DatabaseNewError::QuerierNewError(QuerierNewError::OpenFailed(err)))?;
+
+        Self::from_bytes(bytes).await
+    }
+
+    /// The BIN entry of the ZIP archive is decompressed into memory, see [`zip_archive::read_bin`].
+    #[cfg(all(feature = "zip", feature = "tokio_fs"))]
+    pub async fn open_zip(path: impl AsRef<std::path::Path>) -> Result<Self, DatabaseNewError> {
+        let file = tokio::fs::File::open(path)
+            .await
+            .map_err(|err| DatabaseNewError::QuerierNewError(QuerierNewError::OpenFailed(err)))?
+            .into_std()
+            .await;
+
+        Self::from_zip(std::io::BufReader::new(file)).await
+    }
+
+    /// Like [`open_zip`](Self::open_zip), e.g. over an object store blob.
+    ///
+    /// The decompression is blocking, so it is in [`tokio::task::spawn_blocking`].
+    #[cfg(all(feature = "zip", feature = "tokio_fs"))]
+    pub async fn from_zip(
+        reader: impl std::io::Read + std::io::Seek + Send + 'static,
+    ) -> Result<Self, DatabaseNewError> {
+        let bytes = tokio::task::spawn_blocking(move || zip_archive::read_bin(reader))
+            .await
+            .map_err(|err| zip_archive::ReadError::EntryReadFailed(err.into()))
+            .and_then(|x| x)
+            .map_err(DatabaseNewError::ZipReadFailed)?;
+
+        Self::from_bytes(bytes).await
+    }
+
+    /// Like [`lookup`](Self::lookup), but the strs are borrowed from the bytes, without allocation.
+    pub fn lookup_ref<'a>(
+        &self,
+        ip: IpAddr,
+        selected_fields: impl Into<Option<&'a [RecordField]>>,
+    ) -> Result<Option<RecordRef<'_>>, DatabaseLookupError> {
+        let selected_fields = selected_fields.into();
+
+        let (ip_from, ip_to, record_field_contents) = match self
+            .inner
+            .lookup_ref(ip)
+            .map_err(|err| DatabaseLookupError::LookupFailed(ip, err))?
+        {
+            Some(x) => x,
+            None => return Ok(None),
+        };
+
+        let mut record = RecordRef::with_empty(ip_from, ip_to);
+        for record_field_content in record_field_contents.iter() {
+            let record_field_content = record_field_content.map_err(|err| {
+                DatabaseLookupError::LookupFailed(ip, QuerierLookupError::ContentFillFailed(err))
+            })?;
+
+            if let Some(selected_fields) = selected_fields {
+                if !selected_fields.iter().any(|x| {
+                    ip2location_bin_format::record_field::RecordField::from(x)
+                        == record_field_content.record_field()
+                }) {
+                    continue;
+                }
+            }
+
+            if !record
+                .fill(record_field_content)
+                .map_err(|err| DatabaseLookupError::ToRecordFailed(ip, err))?
+            {
+                return Ok(None);
+            }
         }
 
-        Ok(Self { inner })
+        Ok(Some(record))
+    }
+}
+
+#[cfg(any(unix, windows))]
+impl Database<futures_util::io::AllowStdIo<std::fs::File>> {
+    /// The lookups share one file handle without the pool, see [`Querier::open_read_at`].
+    pub async fn open_read_at(path: impl AsRef<std::path::Path>) -> Result<Self, DatabaseNewError> {
+        let inner = Querier::open_read_at(path)
+            .await
+            .map_err(DatabaseNewError::QuerierNewError)?;
+
+        Self::from_querier(inner)
     }
 }
 
 //
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum DatabaseNewError {
     QuerierNewError(QuerierNewError),
     TypeMismatch,
+    #[cfg(feature = "zip")]
+    ZipReadFailed(zip_archive::ReadError),
 }
 
 impl core::fmt::Display for DatabaseNewError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{self:?}")
+        match self {
+            Self::QuerierNewError(_) => write!(f, "create querier failed"),
+            Self::TypeMismatch => write!(f, "the BIN is not an IP2Location BIN"),
+            #[cfg(feature = "zip")]
+            Self::ZipReadFailed(_) => write!(f, "read BIN out of ZIP archive failed"),
+        }
     }
 }
 
-impl std::error::Error for DatabaseNewError {}
+impl std::error::Error for DatabaseNewError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::QuerierNewError(err) => Some(err),
+            Self::TypeMismatch => None,
+            #[cfg(feature = "zip")]
+            Self::ZipReadFailed(err) => Some(err),
+        }
+    }
+}
 
 //
 //
@@ -100,6 +352,14 @@ impl<S> Database<S>
 where
     S: AsyncSeek + AsyncRead + Unpin,
 {
+    /// Checks the whole BIN and returns all the findings, see [`validate`].
+    ///
+    /// It is empty for a sound BIN, e.g. reject a download with findings before deploying it.
+    /// Unless the database is from bytes, the BIN is read into memory.
+    pub async fn validate(&self) -> Result<Vec<ValidationFinding>, DatabaseValidateError> {
+        self.inner.validate().await
+    }
+
     pub async fn lookup(
         &self,
         ip: IpAddr,
@@ -127,10 +387,10 @@ where
             .inner
             .lookup_ipv4(ip, selected_fields)
             .await
-            .map_err(DatabaseLookupError::QuerierLookupError)?
+            .map_err(|err| DatabaseLookupError::LookupFailed(ip.into(), err))?
         {
             Some(x) => Ok(OptionRecord::try_from(x)
-                .map_err(DatabaseLookupError::ToRecordFailed)?
+                .map_err(|err| DatabaseLookupError::ToRecordFailed(ip.into(), err))?
                 .0),
             None => Ok(None),
         }
@@ -141,6 +401,17 @@ where
         ip: Ipv6Addr,
         selected_fields: impl Into<Option<&[RecordField]>>,
     ) -> Result<Option<Record>, DatabaseLookupError> {
+        self.lookup_ipv6_with_translation(ip, selected_fields)
+            .await
+            .map(|(x, _)| x)
+    }
+
+    /// Looks up the ips in one batch, the records are in the same order as the ips, see [`Querier::lookup_batch`].
+    pub async fn lookup_many(
+        &self,
+        ips: &[IpAddr],
+        selected_fields: impl Into<Option<&[RecordField]>>,
+    ) -> Result<Vec<Option<Record>>, DatabaseLookupError> {
         let selected_fields: Option<Vec<ip2location_bin_format::record_field::RecordField>> =
             selected_fields
                 .into()
@@ -148,44 +419,126 @@ where
         let selected_fields = selected_fields.as_deref();
 
         //
-        match self
+        self.inner
+            .lookup_batch(ips, selected_fields)
+            .await
+            .map_err(|QuerierIpLookupError::LookupFailed(ip, err)| {
+                DatabaseLookupError::LookupFailed(ip, err)
+            })?
+            .into_iter()
+            .zip(ips)
+            .map(|(x, ip)| match x {
+                Some(x) => Ok(OptionRecord::try_from(x)
+                    .map_err(|err| DatabaseLookupError::ToRecordFailed(*ip, err))?
+                    .0),
+                None => Ok(None),
+            })
+            .collect()
+    }
+
+    /// Like [`lookup_ipv6`](Self::lookup_ipv6), and returns the translation applied by [`ipv6_translation_mode`](Self::ipv6_translation_mode).
+    pub async fn lookup_ipv6_with_translation(
+        &self,
+        ip: Ipv6Addr,
+        selected_fields: impl Into<Option<&[RecordField]>>,
+    ) -> Result<(Option<Record>, Ipv6Translation), DatabaseLookupError> {
+        let selected_fields: Option<Vec<ip2location_bin_format::record_field::RecordField>> =
+            selected_fields
+                .into()
+                .map(|x| x.iter().map(Into::into).collect::<Vec<_>>());
+        let selected_fields = selected_fields.as_deref();
+
+        //
+        let (x, translation) = self
             .inner
-            .lookup_ipv6(ip, selected_fields)
+            .lookup_ipv6_with_translation(ip, selected_fields)
             .await
-            .map_err(DatabaseLookupError::QuerierLookupError)?
-        {
-            Some(x) => Ok(OptionRecord::try_from(x)
-                .map_err(DatabaseLookupError::ToRecordFailed)?
-                .0),
-            None => Ok(None),
+            .map_err(|err| DatabaseLookupError::LookupFailed(ip.into(), err))?;
+        match x {
+            Some(x) => Ok((
+                OptionRecord::try_from(x)
+                    .map_err(|err| DatabaseLookupError::ToRecordFailed(ip.into(), err))?
+                    .0,
+                translation,
+            )),
+            None => Ok((None, translation)),
         }
     }
 }
 
+//
+//
+//
+impl<S> Database<S>
+where
+    S: AsyncSeek + AsyncRead + Unpin,
+{
+    /// The records of all ranges, optionally restricted to the ones which contain or are between start and end,
+    /// see [`Querier::ranges`].
+    pub fn ranges<'a>(
+        &'a self,
+        start: Option<IpAddr>,
+        end: Option<IpAddr>,
+        selected_fields: impl Into<Option<&'a [RecordField]>>,
+    ) -> impl Stream<Item = Result<Record, DatabaseLookupError>> + 'a {
+        let selected_fields: Option<Vec<ip2location_bin_format::record_field::RecordField>> =
+            selected_fields
+                .into()
+                .map(|x| x.iter().map(Into::into).collect::<Vec<_>>());
+
+        //
+        self.inner
+            .ranges(start, end, selected_fields)
+            .map_err(|QuerierIpLookupError::LookupFailed(ip, err)| {
+                DatabaseLookupError::LookupFailed(ip, err)
+            })
+            .try_filter_map(|x| async move {
+                let ip_from = x.0;
+                Ok(OptionRecord::try_from(x)
+                    .map_err(|err| DatabaseLookupError::ToRecordFailed(ip_from, err))?
+                    .0)
+            })
+    }
+}
+
 //
 #[derive(Debug)]
 pub enum DatabaseLookupError {
-    QuerierLookupError(QuerierLookupError),
-    ToRecordFailed(Box<str>),
+    /// The lookup of the ip failed, in [`Database::ranges`] it is the ip_from of the range.
+    LookupFailed(IpAddr, QuerierLookupError),
+    /// The record of the ip is not convertible, in [`Database::ranges`] it is the ip_from of the range.
+    ToRecordFailed(IpAddr, ToRecordError),
 }
 
 impl core::fmt::Display for DatabaseLookupError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{self:?}")
+        match self {
+            Self::LookupFailed(ip, _) => write!(f, "lookup {ip} failed"),
+            Self::ToRecordFailed(ip, _) => write!(f, "convert the record of {ip} failed"),
+        }
     }
 }
 
-impl std::error::Error for DatabaseLookupError {}
+impl std::error::Error for DatabaseLookupError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::LookupFailed(_, err) => Some(err),
+            Self::ToRecordFailed(_, err) => Some(err),
+        }
+    }
+}
 
-#[cfg(feature = "tokio_fs")]
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    use std::io::ErrorKind as IoErrorKind;
+    use crate::test_helper::{bin_bytes, BRISBANE, FUZHOU, LOS_ANGELES, MELBOURNE};
 
+    #[cfg(feature = "tokio_fs")]
     #[tokio::test]
     async fn test_new_and_lookup_latest() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::ErrorKind as IoErrorKind;
+
         let path_bin_v4 = "data/ip2location-lite/latest/IP2LOCATION-LITE-DB11.BIN";
         let path_bin_v6 = "data/ip2location-lite/latest/IP2LOCATION-LITE-DB11.IPV6.BIN";
 
@@ -254,4 +607,255 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_database_ranges() -> Result<(), Box<dyn std::error::Error>> {
+        let bytes = bin_bytes(
+            11,
+            &[
+                ("1.0.0.0", "1.0.0.255", &LOS_ANGELES),
+                ("1.0.1.0", "1.0.3.255", &FUZHOU),
+                ("1.0.4.0", "1.0.7.255", &MELBOURNE),
+            ],
+        )?;
+        let db = Database::from_bytes(bytes).await?;
+
+        let records = db.ranges(None, None, None).try_collect::<Vec<_>>().await?;
+        assert_eq!(
+            records
+                .iter()
+                .map(|x| x.country_code.to_string())
+                .collect::<Vec<_>>(),
+            ["US", "CN", "AU"]
+        );
+        assert_eq!(records[0].ip_from, Ipv4Addr::new(1, 0, 0, 0));
+        assert_eq!(records[0].ip_to, Ipv4Addr::new(1, 0, 1, 0));
+
+        let selected_fields = &[RecordField::CountryCodeAndName];
+        let records = db
+            .ranges(
+                Some(Ipv4Addr::new(1, 0, 1, 255).into()),
+                Some(Ipv4Addr::new(8, 8, 8, 8).into()),
+                selected_fields.as_ref(),
+            )
+            .try_filter(|x| future::ready(x.country_code.to_string() == "AU"))
+            .try_collect::<Vec<_>>()
+            .await?;
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].city_name, None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_database_from_bytes() -> Result<(), Box<dyn std::error::Error>> {
+        let bytes = bin_bytes(11, &[("1.0.0.0", "1.0.0.255", &LOS_ANGELES)])?;
+
+        let db = Database::from_bytes(bytes).await?;
+        assert!(db.inner.bytes_querier.is_some());
+
+        let info = db.info();
+        assert_eq!(info.product, Product::IP2Location);
+        assert_eq!(info.code(), "DB11");
+        assert_eq!(info.date, (2022, 11, 1));
+        assert!(!info.has_ipv6);
+        assert!(info.lite_edition_exists);
+
+        let record = db
+            .lookup(Ipv4Addr::new(1, 0, 0, 1).into(), None)
+            .await?
+            .unwrap();
+        assert_eq!(record.country_code.to_string(), "US");
+        assert_eq!(record.time_zone, Some("-07:00".into()));
+        assert!(db
+            .lookup(Ipv4Addr::new(8, 8, 8, 8).into(), None)
+            .await?
+            .is_none());
+
+        let record_ref = db
+            .lookup_ref(Ipv4Addr::new(1, 0, 0, 1).into(), None)?
+            .unwrap();
+        assert_eq!(record_ref.city_name, Some("Los Angeles"));
+        assert_eq!(
+            format!("{:?}", record_ref.to_owned()),
+            format!("{record:?}")
+        );
+        let record_ref = db
+            .lookup_ref(
+                Ipv4Addr::new(1, 0, 0, 1).to_ipv6_mapped().into(),
+                &[RecordField::ZipCode][..],
+            )?
+            .unwrap();
+        assert_eq!(record_ref.zip_code, Some("90001"));
+        assert!(record_ref.city_name.is_none());
+        assert!(db
+            .lookup_ref(Ipv4Addr::new(8, 8, 8, 8).into(), None)?
+            .is_none());
+
+        Ok(())
+    }
+
+    #[cfg(any(unix, windows))]
+    #[tokio::test]
+    async fn test_database_open_read_at() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::Write as _;
+
+        let path = std::env::temp_dir().join(format!(
+            "ip2location_ip2location_read_at_{}.BIN",
+            std::process::id()
+        ));
+        std::fs::File::create(&path)?
+            .write_all(&bin_bytes(11, &[("1.0.0.0", "1.0.0.255", &LOS_ANGELES)])?)?;
+
+        let db = Database::open_read_at(&path).await?;
+        assert!(db.inner.read_at_querier.is_some());
+        assert_eq!(db.validate().await?, vec![]);
+
+        let record = db
+            .lookup(Ipv4Addr::new(1, 0, 0, 1).into(), None)
+            .await?
+            .unwrap();
+        assert_eq!(record.city_name, Some("Los Angeles".into()));
+        assert!(db
+            .lookup(Ipv4Addr::new(8, 8, 8, 8).into(), None)
+            .await?
+            .is_none());
+
+        std::fs::remove_file(&path)?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_database_lookup_ipv6_with_translation() -> Result<(), Box<dyn std::error::Error>>
+    {
+        let bytes = bin_bytes(11, &[("1.0.0.0", "1.0.0.255", &BRISBANE)])?;
+        let mut db = Database::from_bytes(bytes).await?;
+
+        // 2002:0100:0001::/48 and the Teredo of 1.0.0.1
+        for (ip, translation) in [
+            ("2002:100:1::1", Ipv6Translation::SixToFour),
+            (
+                "2001:0:4136:e378:8000:63bf:feff:fffe",
+                Ipv6Translation::Teredo,
+            ),
+        ] {
+            db.set_ipv6_translation_mode(Ipv6TranslationMode::Ipv4MappedOnly);
+            let (record, x) = db.lookup_ipv6_with_translation(ip.parse()?, None).await?;
+            assert!(record.is_none());
+            assert_eq!(x, Ipv6Translation::None);
+
+            db.set_ipv6_translation_mode(Ipv6TranslationMode::All);
+            let (record, x) = db.lookup_ipv6_with_translation(ip.parse()?, None).await?;
+            assert_eq!(record.unwrap().country_code.to_string(), "AU");
+            assert_eq!(x, translation);
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_database_range_cache() -> Result<(), Box<dyn std::error::Error>> {
+        let bytes = bin_bytes(11, &[("1.0.0.0", "1.0.0.255", &BRISBANE)])?;
+        let db = Database::from_bytes(bytes).await?;
+        assert_eq!(db.range_cache_stats(), None);
+
+        let db = db.with_range_cache(core::num::NonZeroUsize::new(10).unwrap());
+        for ip in [Ipv4Addr::new(1, 0, 0, 1), Ipv4Addr::new(1, 0, 0, 2)] {
+            let record = db
+                .lookup(ip.into(), &[RecordField::CityName][..])
+                .await?
+                .unwrap();
+            assert_eq!(record.city_name, Some(BRISBANE.city.into()));
+        }
+        assert_eq!(
+            db.range_cache_stats().map(|x| (x.hits, x.misses, x.len)),
+            Some((1, 1, 1))
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_database_lookup_many() -> Result<(), Box<dyn std::error::Error>> {
+        let bytes = bin_bytes(11, &[("1.0.0.0", "1.0.0.255", &BRISBANE)])?;
+        let db = Database::from_bytes(bytes).await?;
+
+        let ips: Vec<IpAddr> = vec![
+            Ipv4Addr::new(1, 0, 0, 2).into(),
+            Ipv4Addr::new(8, 8, 8, 8).into(),
+            Ipv4Addr::new(1, 0, 0, 1).to_ipv6_mapped().into(),
+        ];
+        let records = db
+            .lookup_many(
+                &ips,
+                &[RecordField::CountryCodeAndName, RecordField::CityName][..],
+            )
+            .await?;
+        assert_eq!(records.len(), 3);
+        assert_eq!(
+            records[0].as_ref().unwrap().city_name,
+            Some("Brisbane".into())
+        );
+        assert!(records[0].as_ref().unwrap().region_name.is_none());
+        assert!(records[1].is_none());
+        assert_eq!(
+            records[2].as_ref().unwrap().ip_from,
+            IpAddr::from(Ipv4Addr::new(1, 0, 0, 0).to_ipv6_mapped())
+        );
+
+        Ok(())
+    }
+
+    #[cfg(all(feature = "zip", feature = "tokio_fs"))]
+    #[tokio::test]
+    async fn test_database_from_zip() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::{Cursor as IoCursor, Write as _};
+
+        use zip::{write::SimpleFileOptions, ZipWriter};
+
+        let bytes = bin_bytes(11, &[("1.0.0.0", "1.0.0.255", &LOS_ANGELES)])?;
+
+        let archive = |entries: &[(&str, &[u8])]| -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+            let mut w = ZipWriter::new(IoCursor::new(vec![]));
+            for (name, bytes) in entries {
+                w.start_file(*name, SimpleFileOptions::default())?;
+                w.write_all(bytes)?;
+            }
+            Ok(w.finish()?.into_inner())
+        };
+        let readme_zip = archive(&[("README_LITE.TXT", b"README")])?;
+        let bin_zip = archive(&[
+            ("README_LITE.TXT", b"README"),
+            ("IP2LOCATION-LITE-DB11.BIN", &bytes),
+        ])?;
+
+        let path = std::env::temp_dir().join(format!(
+            "ip2location_ip2location_{}.BIN.ZIP",
+            std::process::id()
+        ));
+        std::fs::write(&path, &bin_zip)?;
+
+        for db in [
+            Database::from_zip(IoCursor::new(bin_zip)).await?,
+            Database::open_zip(&path).await?,
+        ] {
+            let record = db
+                .lookup(Ipv4Addr::new(1, 0, 0, 1).into(), None)
+                .await?
+                .unwrap();
+            assert_eq!(record.city_name, Some("Los Angeles".into()));
+        }
+
+        std::fs::remove_file(&path)?;
+
+        assert!(matches!(
+            Database::from_zip(IoCursor::new(readme_zip)).await,
+            Err(DatabaseNewError::ZipReadFailed(
+                zip_archive::ReadError::EntryMissing(_)
+            ))
+        ));
+
+        Ok(())
+    }
 }
@@ -0,0 +1,220 @@
+//! Blocking [`Database`] over [`std::io::Read`] + [`std::io::Seek`], e.g. [`std::fs::File`].
+
+use std::{
+    io::{Read, Seek},
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+};
+
+use futures_executor::{block_on, block_on_stream};
+use futures_util::io::AllowStdIo;
+use ip2location_bin_format::blocking::Querier;
+
+use super::{
+    ContentCacheConfig, Database as AsyncDatabase, DatabaseInfo, DatabaseLookupError,
+    DatabaseNewError, Ipv6Translation,
+};
+use crate::record::{Record, RecordField};
+
+//
+pub struct Database<S> {
+    pub inner: AsyncDatabase<AllowStdIo<S>>,
+}
+
+impl<S> core::fmt::Debug for Database<S>
+where
+    AsyncDatabase<AllowStdIo<S>>: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Database")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl Database<std::fs::File> {
+    pub fn new(
+        path: impl AsRef<std::path::Path>,
+        pool_max_size: usize,
+    ) -> Result<Self, DatabaseNewError> {
+        Self::new_with_content_cache_config(path, pool_max_size, ContentCacheConfig::default())
+    }
+
+    /// Like [`new`](Self::new), see [`ContentCacheConfig`].
+    pub fn new_with_content_cache_config(
+        path: impl AsRef<std::path::Path>,
+        pool_max_size: usize,
+        content_cache_config: ContentCacheConfig,
+    ) -> Result<Self, DatabaseNewError> {
+        let path = path.as_ref().to_owned();
+
+        let inner = Querier::new_with_content_cache_config(
+            || std::fs::File::open(&path),
+            pool_max_size,
+            content_cache_config,
+        )
+        .map_err(DatabaseNewError::QuerierNewError)?;
+
+        Self::from_querier(inner)
+    }
+}
+
+impl<S> Database<S> {
+    /// Like [`AsyncDatabase::info`].
+    pub fn info(&self) -> &DatabaseInfo {
+        self.inner.info()
+    }
+
+    /// Like [`AsyncDatabase::from_querier`].
+    pub fn from_querier(inner: Querier<S>) -> Result<Self, DatabaseNewError> {
+        Ok(Self {
+            inner: AsyncDatabase::from_querier(inner.inner)?,
+        })
+    }
+}
+
+impl<S> Database<S>
+where
+    S: Read + Seek + Send + 'static,
+{
+    /// Like [`AsyncDatabase::with_stream_factory`], e.g. over [`std::io::Cursor`].
+    pub fn with_stream_factory<F>(
+        stream_factory: F,
+        pool_max_size: usize,
+    ) -> Result<Self, DatabaseNewError>
+    where
+        F: FnMut() -> Result<S, std::io::Error>,
+    {
+        let inner = Querier::new(stream_factory, pool_max_size)
+            .map_err(DatabaseNewError::QuerierNewError)?;
+
+        Self::from_querier(inner)
+    }
+}
+
+//
+//
+//
+impl<S> Database<S>
+where
+    S: Read + Seek,
+{
+    pub fn lookup<'a>(
+        &self,
+        ip: IpAddr,
+        selected_fields: impl Into<Option<&'a [RecordField]>>,
+    ) -> Result<Option<Record>, DatabaseLookupError> {
+        block_on(self.inner.lookup(ip, selected_fields))
+    }
+
+    pub fn lookup_ipv4<'a>(
+        &self,
+        ip: Ipv4Addr,
+        selected_fields: impl Into<Option<&'a [RecordField]>>,
+    ) -> Result<Option<Record>, DatabaseLookupError> {
+        block_on(self.inner.lookup_ipv4(ip, selected_fields))
+    }
+
+    pub fn lookup_ipv6<'a>(
+        &self,
+        ip: Ipv6Addr,
+        selected_fields: impl Into<Option<&'a [RecordField]>>,
+    ) -> Result<Option<Record>, DatabaseLookupError> {
+        block_on(self.inner.lookup_ipv6(ip, selected_fields))
+    }
+
+    pub fn lookup_many<'a>(
+        &self,
+        ips: &[IpAddr],
+        selected_fields: impl Into<Option<&'a [RecordField]>>,
+    ) -> Result<Vec<Option<Record>>, DatabaseLookupError> {
+        block_on(self.inner.lookup_many(ips, selected_fields))
+    }
+
+    pub fn lookup_ipv6_with_translation<'a>(
+        &self,
+        ip: Ipv6Addr,
+        selected_fields: impl Into<Option<&'a [RecordField]>>,
+    ) -> Result<(Option<Record>, Ipv6Translation), DatabaseLookupError> {
+        block_on(self.inner.lookup_ipv6_with_translation(ip, selected_fields))
+    }
+
+    /// See [`Database::ranges`](super::Database::ranges).
+    pub fn ranges<'a>(
+        &'a self,
+        start: Option<IpAddr>,
+        end: Option<IpAddr>,
+        selected_fields: impl Into<Option<&'a [RecordField]>>,
+    ) -> impl Iterator<Item = Result<Record, DatabaseLookupError>> + 'a {
+        block_on_stream(Box::pin(self.inner.ranges(start, end, selected_fields)))
+    }
+}
+
+#[cfg(feature = "csv")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::csv_format::{csv_to_bin, CSV_HEADER_DB11};
+
+    #[test]
+    fn test_new_and_lookup() -> Result<(), Box<dyn std::error::Error>> {
+        let csv = r#""16777216","16777471","US","United States of America","California","Los Angeles","34.052860","-118.243700","90001","-07:00"
+"#;
+
+        let path = std::env::temp_dir().join(format!(
+            "ip2location_ip2location_blocking_{}.BIN",
+            std::process::id()
+        ));
+        csv_to_bin(
+            csv.as_bytes(),
+            CSV_HEADER_DB11,
+            11,
+            (22, 11, 1),
+            &mut std::fs::File::create(&path)?,
+        )?;
+
+        let db = Database::new(&path, 2)?;
+
+        let record = db.lookup(Ipv4Addr::new(1, 0, 0, 1).into(), None)?.unwrap();
+        assert_eq!(record.country_code.to_string(), "US");
+        assert!(db.lookup(Ipv4Addr::new(8, 8, 8, 8).into(), None)?.is_none());
+
+        let records = db.ranges(None, None, None).collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(records.len(), 1);
+
+        drop(db);
+        std::fs::remove_file(&path)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_with_stream_factory() -> Result<(), Box<dyn std::error::Error>> {
+        let csv = r#""16777216","16777471","US","United States of America","California","Los Angeles","34.052860","-118.243700","90001","-07:00"
+"#;
+
+        let mut bytes = vec![];
+        csv_to_bin(csv.as_bytes(), CSV_HEADER_DB11, 11, (22, 11, 1), &mut bytes)?;
+        let bytes: std::sync::Arc<[u8]> = bytes.into();
+
+        let db = Database::with_stream_factory(|| Ok(std::io::Cursor::new(bytes.clone())), 2)?;
+
+        let record = db.lookup(Ipv4Addr::new(1, 0, 0, 1).into(), None)?.unwrap();
+        assert_eq!(record.city_name, Some("Los Angeles".into()));
+        assert!(db.lookup(Ipv4Addr::new(8, 8, 8, 8).into(), None)?.is_none());
+
+        //
+        let mut header = ip2location_bin_format::header::Schema::default();
+        header.r#type = ip2location_bin_format::header::schema::SchemaType::IP2Proxy;
+        header.date = (22, 11, 1);
+        header.sub_type = ip2location_bin_format::header::schema::SchemaSubType(4);
+        let mut proxy_bytes = vec![];
+        ip2location_bin_format::Builder::new(header)?.finish(&mut proxy_bytes)?;
+        assert!(matches!(
+            Database::with_stream_factory(|| Ok(std::io::Cursor::new(proxy_bytes.clone())), 1),
+            Err(DatabaseNewError::TypeMismatch)
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,99 @@
+//! [`Database`] which swaps to a new BIN, e.g. the monthly LITE update.
+//!
+//! The lookups in flight keep the old [`Database`] until they finish.
+//! Replace the file by rename, the old [`Database`] may still read the old file.
+//! A `Database::open_mmap` one must only be replaced by rename, writing the file in place is undefined behavior.
+
+use ip2location_bin_format::{header::Schema as HeaderSchema, reloadable::DatabaseHeader};
+
+use super::{Database, DatabaseNewError};
+
+//
+/// The loader opens the [`Database`] at the path, e.g. `ReloadableDatabase::new(path, Database::open_read_at)`.
+///
+/// The lookups are on the current [`Database`], e.g. `db.database().lookup(ip, None)`.
+pub type ReloadableDatabase<S> =
+    ip2location_bin_format::reloadable::ReloadableDatabase<Database<S>, DatabaseNewError>;
+
+pub type DatabaseReloadError =
+    ip2location_bin_format::reloadable::DatabaseReloadError<DatabaseNewError>;
+
+impl<S> DatabaseHeader for Database<S> {
+    fn header(&self) -> &HeaderSchema {
+        &self.inner.header
+    }
+}
+
+#[cfg(all(test, any(unix, windows)))]
+mod tests {
+    use super::*;
+
+    use std::net::Ipv4Addr;
+
+    use crate::test_helper::{bin_bytes, BRISBANE, FUZHOU, LOS_ANGELES};
+
+    #[tokio::test]
+    async fn test_reloadable_database() -> Result<(), Box<dyn std::error::Error>> {
+        let path = std::env::temp_dir().join(format!(
+            "ip2location_ip2location_reloadable_{}.BIN",
+            std::process::id()
+        ));
+        let path_tmp = path.with_extension("BIN.tmp");
+        let write = |bytes: Vec<u8>| -> Result<(), Box<dyn std::error::Error>> {
+            std::fs::write(&path_tmp, bytes)?;
+            std::fs::rename(&path_tmp, &path)?;
+            Ok(())
+        };
+
+        write(bin_bytes(11, &[("1.0.0.0", "1.0.0.255", &BRISBANE)])?)?;
+
+        let db = ReloadableDatabase::new(&path, Database::open_read_at).await?;
+        assert!(!db.reload_if_modified().await?);
+
+        let db_old = db.database();
+
+        write(bin_bytes(11, &[("1.0.0.0", "1.0.0.255", &LOS_ANGELES)])?)?;
+        db.reload().await?;
+        assert!(!db.reload_if_modified().await?);
+
+        let ip = Ipv4Addr::new(1, 0, 0, 1).into();
+        assert_eq!(
+            db.database()
+                .lookup(ip, None)
+                .await?
+                .unwrap()
+                .country_code
+                .to_string(),
+            "US"
+        );
+        assert_eq!(
+            db_old
+                .lookup(ip, None)
+                .await?
+                .unwrap()
+                .country_code
+                .to_string(),
+            "AU"
+        );
+
+        //
+        write(bin_bytes(3, &[("1.0.0.0", "1.0.0.255", &FUZHOU)])?)?;
+        assert!(matches!(
+            db.reload().await,
+            Err(DatabaseReloadError::HeaderMismatch)
+        ));
+        assert_eq!(
+            db.database()
+                .lookup(ip, None)
+                .await?
+                .unwrap()
+                .country_code
+                .to_string(),
+            "US"
+        );
+
+        std::fs::remove_file(&path)?;
+
+        Ok(())
+    }
+}
@@ -11,3 +11,337 @@ pub const CSV_HEADER_DB11: &[&str] = &[
     "zip_code",
     "time_zone",
 ];
+
+pub const CSV_HEADER_DB26: &[&str] = &[
+    "ip_from",
+    "ip_to",
+    "country_code",
+    "country_name",
+    "region_name",
+    "city_name",
+    "latitude",
+    "longitude",
+    "zip_code",
+    "time_zone",
+    "isp",
+    "domain",
+    "net_speed",
+    "idd_code",
+    "area_code",
+    "weather_station_code",
+    "weather_station_name",
+    "mcc",
+    "mnc",
+    "mobile_brand",
+    "elevation",
+    "usage_type",
+    "address_type",
+    "category",
+    "district",
+    "asn",
+    "as_name",
+];
+
+//
+/// Converts a LITE/commercial CSV (v4 or IPV6) to BIN.
+///
+/// csv_header is the columns of the CSV, e.g. [`CSV_HEADER_DB11`],
+/// sub_type is the N of DBN.
+#[cfg(feature = "csv")]
+pub fn csv_to_bin<R: std::io::Read, W: std::io::Write>(
+    rdr: R,
+    csv_header: &[&str],
+    sub_type: u8,
+    date: (u8, u8, u8),
+    w: &mut W,
+) -> Result<ip2location_bin_format::header::Schema, CsvToBinError> {
+    use ip2location_bin_format::header::schema::{Schema, SchemaSubType, SchemaType};
+
+    let mut header = Schema::default();
+    header.r#type = SchemaType::IP2Location;
+    header.sub_type = SchemaSubType(sub_type);
+    header.date = date;
+
+    ip2location_bin_format::csv_format::csv_to_bin::<crate::record::Record, _, _>(
+        rdr, csv_header, header, w,
+    )
+}
+
+#[cfg(feature = "csv")]
+impl ip2location_bin_format::csv_format::CsvRow for crate::record::Record {
+    type ToRecordFieldContentsError = crate::record::ToRecordError;
+
+    fn ip_range(&self) -> (std::net::IpAddr, std::net::IpAddr) {
+        (self.ip_from, self.ip_to)
+    }
+
+    fn to_record_field_contents(
+        &self,
+        record_fields: &ip2location_bin_format::record_field::RecordFields,
+    ) -> Result<
+        ip2location_bin_format::record_field::RecordFieldContents,
+        Self::ToRecordFieldContentsError,
+    > {
+        self.to_record_field_contents(record_fields)
+    }
+
+    fn column_value(
+        column: &str,
+        record_field_content: &ip2location_bin_format::record_field::RecordFieldContent,
+    ) -> Option<String> {
+        use ip2location_bin_format::{
+            content::UNKNOWN_STR, csv_format::coordinate_to_string,
+            record_field::RecordFieldContent,
+        };
+
+        match (column, record_field_content) {
+            ("region_name", RecordFieldContent::REGION(_, v))
+            | ("city_name", RecordFieldContent::CITY(_, v))
+            | ("isp", RecordFieldContent::ISP(_, v))
+            | ("domain", RecordFieldContent::DOMAIN(_, v))
+            | ("zip_code", RecordFieldContent::ZIPCODE(_, v))
+            | ("time_zone", RecordFieldContent::TIMEZONE(_, v))
+            | ("net_speed", RecordFieldContent::NETSPEED(_, v))
+            | ("idd_code", RecordFieldContent::IDDCODE(_, v))
+            | ("area_code", RecordFieldContent::AREACODE(_, v))
+            | ("weather_station_code", RecordFieldContent::WEATHERSTATIONCODE(_, v))
+            | ("weather_station_name", RecordFieldContent::WEATHERSTATIONNAME(_, v))
+            | ("mcc", RecordFieldContent::MCC(_, v))
+            | ("mnc", RecordFieldContent::MNC(_, v))
+            | ("mobile_brand", RecordFieldContent::MOBILEBRAND(_, v))
+            | ("elevation", RecordFieldContent::ELEVATION(_, v))
+            | ("usage_type", RecordFieldContent::USAGETYPE(_, v))
+            | ("address_type", RecordFieldContent::ADDRESSTYPE(_, v))
+            | ("category", RecordFieldContent::CATEGORY(_, v))
+            | ("district", RecordFieldContent::DISTRICT(_, v))
+            | ("asn", RecordFieldContent::ASN(_, v))
+            | ("as_name", RecordFieldContent::AS(_, v)) => {
+                Some(v.as_deref().unwrap_or(UNKNOWN_STR).to_owned())
+            }
+            ("latitude", RecordFieldContent::LATITUDE(v))
+            | ("longitude", RecordFieldContent::LONGITUDE(v)) => Some(coordinate_to_string(*v)),
+            _ => None,
+        }
+    }
+}
+
+//
+#[cfg(feature = "csv")]
+pub type CsvToBinError =
+    ip2location_bin_format::csv_format::CsvToBinError<crate::record::ToRecordError>;
+
+//
+/// Exports BIN to CSV, the v4 CSV or the IPV6 CSV like the BIN.
+///
+/// csv_header is the columns of the CSV, e.g. [`CSV_HEADER_DB11`].
+#[cfg(feature = "csv")]
+pub async fn bin_to_csv<S, W>(
+    db: &crate::bin_format::Database<S>,
+    csv_header: &[&str],
+    w: W,
+) -> Result<(), BinToCsvError>
+where
+    S: futures_util::AsyncSeek + futures_util::AsyncRead + Unpin,
+    W: std::io::Write,
+{
+    ip2location_bin_format::csv_format::bin_to_csv::<crate::record::Record, _, _>(
+        &db.inner, csv_header, w,
+    )
+    .await
+}
+
+#[cfg(feature = "csv")]
+pub use ip2location_bin_format::csv_format::BinToCsvError;
+
+#[cfg(feature = "csv")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::{
+        net::{Ipv4Addr, Ipv6Addr},
+        sync::Arc,
+    };
+
+    use futures_util::io::Cursor;
+
+    use crate::record::RecordField;
+
+    use crate::bin_format::Database;
+
+    async fn database(
+        csv: &str,
+    ) -> Result<Database<Cursor<Arc<[u8]>>>, Box<dyn std::error::Error>> {
+        let mut bytes = vec![];
+        csv_to_bin(csv.as_bytes(), CSV_HEADER_DB11, 11, (22, 11, 1), &mut bytes)?;
+
+        Ok(Database::from_shared_bytes(bytes, 1).await?)
+    }
+
+    #[tokio::test]
+    async fn test_csv_to_bin() -> Result<(), Box<dyn std::error::Error>> {
+        let csv_v4 = r#""0","16777215","-","-","-","-","0.000000","0.000000","-","-"
+"16777216","16777471","US","United States of America","California","Los Angeles","34.052860","-118.243700","90001","-07:00"
+"16777472","16778239","CN","China","Fujian","Fuzhou","26.061390","119.306110","350004","+08:00"
+"#;
+
+        let db = database(csv_v4).await?;
+        assert!(!db.inner.header.has_v6());
+
+        let record = db
+            .lookup(Ipv4Addr::new(1, 0, 0, 1).into(), None)
+            .await?
+            .unwrap();
+        assert_eq!(record.country_code.to_string(), "US");
+        assert_eq!(record.city_name, Some("Los Angeles".into()));
+        assert_eq!(record.latitude, Some(34.052_86));
+        assert_eq!(record.time_zone, Some("-07:00".into()));
+
+        let record = db
+            .lookup(Ipv4Addr::new(1, 0, 2, 3).into(), None)
+            .await?
+            .unwrap();
+        assert_eq!(record.country_code.to_string(), "CN");
+        assert_eq!(record.zip_code, Some("350004".into()));
+
+        assert!(db
+            .lookup(Ipv4Addr::new(0, 1, 2, 3).into(), None)
+            .await?
+            .is_none());
+        assert!(db
+            .lookup(Ipv4Addr::new(8, 8, 8, 8).into(), None)
+            .await?
+            .is_none());
+
+        //
+        let csv_v6 = r#""0","281470681743359","-","-","-","-","0.000000","0.000000","-","-"
+"281470698520576","281470698520831","US","United States of America","California","Los Angeles","34.052860","-118.243700","90001","-07:00"
+"47856325177406512713633115462103465984","47856325256634675227897453055647416319","JP","Japan","Tokyo","Tokyo","35.689500","139.691710","100-0001","+09:00"
+"#;
+
+        let db = database(csv_v6).await?;
+        assert!(db.inner.header.has_v6());
+
+        let record = db
+            .lookup(Ipv4Addr::new(1, 0, 0, 1).into(), None)
+            .await?
+            .unwrap();
+        assert_eq!(record.country_code.to_string(), "US");
+
+        let record = db
+            .lookup(Ipv4Addr::new(1, 0, 0, 1).to_ipv6_mapped().into(), None)
+            .await?
+            .unwrap();
+        assert_eq!(record.country_code.to_string(), "US");
+
+        let record = db
+            .lookup("2400:cb00::1".parse::<Ipv6Addr>()?.into(), None)
+            .await?
+            .unwrap();
+        assert_eq!(record.country_code.to_string(), "JP");
+        assert_eq!(record.zip_code, Some("100-0001".into()));
+
+        assert!(db
+            .lookup("2400:cc00::1".parse::<Ipv6Addr>()?.into(), None)
+            .await?
+            .is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_bin_to_csv() -> Result<(), Box<dyn std::error::Error>> {
+        let csv_v4 = r#""0","16777215","-","-","-","-","0.000000","0.000000","-","-"
+"16777216","16777471","US","United States of America","California","Los Angeles","34.052860","-118.243700","90001","-07:00"
+"16777472","16778239","CN","China","Fujian","Fuzhou","26.061390","119.306110","350004","+08:00"
+"16778240","4294967295","-","-","-","-","0.000000","0.000000","-","-"
+"#;
+
+        let db = database(csv_v4).await?;
+
+        let mut buf = vec![];
+        bin_to_csv(&db, CSV_HEADER_DB11, &mut buf).await?;
+        assert_eq!(String::from_utf8(buf)?, csv_v4);
+
+        //
+        let mut buf = vec![];
+        bin_to_csv(&db, &["ip_from", "ip_to", "country_code"], &mut buf).await?;
+        assert_eq!(
+            String::from_utf8(buf)?.lines().nth(1),
+            Some(r#""16777216","16777471","US""#)
+        );
+
+        //
+        let csv_v6 = r#""0","281470681743359","-","-","-","-","0.000000","0.000000","-","-"
+"281470681743360","281470698520575","-","-","-","-","0.000000","0.000000","-","-"
+"281470698520576","281470698520831","US","United States of America","California","Los Angeles","34.052860","-118.243700","90001","-07:00"
+"281470698520832","281470698521599","CN","China","Fujian","Fuzhou","26.061390","119.306110","350004","+08:00"
+"281470698521600","281474976710655","-","-","-","-","0.000000","0.000000","-","-"
+"281474976710656","47856325177406512713633115462103465983","-","-","-","-","0.000000","0.000000","-","-"
+"47856325177406512713633115462103465984","47856325256634675227897453055647416319","JP","Japan","Tokyo","Tokyo","35.689500","139.691710","100-0001","+09:00"
+"47856325256634675227897453055647416320","340282366920938463463374607431768211455","-","-","-","-","0.000000","0.000000","-","-"
+"#;
+
+        let db_v6 = database(csv_v6).await?;
+
+        let mut buf = vec![];
+        bin_to_csv(&db_v6, CSV_HEADER_DB11, &mut buf).await?;
+        assert_eq!(String::from_utf8(buf)?, csv_v6);
+
+        match bin_to_csv(&db, &["ip_from", "isp"], &mut vec![]).await {
+            Err(BinToCsvError::ColumnUnknown(column)) => assert_eq!(column, "isp".into()),
+            x => panic!("{x:?}"),
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_db26() -> Result<(), Box<dyn std::error::Error>> {
+        let csv_v4 = r#""0","16777215","-","-","-","-","0.000000","0.000000","-","-","-","-","-","-","-","-","-","-","-","-","-","-","-","-","-","-","-"
+"16777216","16777471","US","United States of America","California","Los Angeles","34.052860","-118.243700","90001","-07:00","Example Inc","example.com","T1","1","213","USCA0638","Los Angeles","310","410","AT&T","93","DCH","U","IAB19-11","Los Angeles County","64496","EXAMPLE"
+"16777472","4294967295","-","-","-","-","0.000000","0.000000","-","-","-","-","-","-","-","-","-","-","-","-","-","-","-","-","-","-","-"
+"#;
+
+        let mut bytes = vec![];
+        csv_to_bin(
+            csv_v4.as_bytes(),
+            CSV_HEADER_DB26,
+            26,
+            (22, 11, 1),
+            &mut bytes,
+        )?;
+
+        let db = Database::from_bytes(bytes).await?;
+        assert_eq!(db.inner.header.record_fields().unwrap().len(), 1 + 24);
+
+        let record = db
+            .lookup(Ipv4Addr::new(1, 0, 0, 1).into(), None)
+            .await?
+            .unwrap();
+        assert_eq!(record.weather_station_code, Some("USCA0638".into()));
+        assert_eq!(record.mobile_brand, Some("AT&T".into()));
+        assert_eq!(record.elevation, Some(93.0));
+        assert_eq!(record.usage_type, Some("DCH".into()));
+        assert_eq!(record.category, Some("IAB19-11".into()));
+        assert_eq!(record.district, Some("Los Angeles County".into()));
+        assert_eq!(record.asn, Some(64496));
+        assert_eq!(record.as_name, Some("EXAMPLE".into()));
+
+        let record = db
+            .lookup(
+                Ipv4Addr::new(1, 0, 0, 1).into(),
+                &[RecordField::CountryCodeAndName, RecordField::Mcc][..],
+            )
+            .await?
+            .unwrap();
+        assert_eq!(record.mcc, Some("310".into()));
+        assert!(record.mnc.is_none());
+
+        let mut buf = vec![];
+        bin_to_csv(&db, CSV_HEADER_DB26, &mut buf).await?;
+        assert_eq!(String::from_utf8(buf)?, csv_v4);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,127 @@
+//! Joins a [`Record`] with the country information, the continent and the ISO 3166-2 subdivision.
+
+use core::ops::Deref;
+use std::collections::HashMap;
+
+use continent_code::ContinentCode;
+use country_code::{iso3166_2::SubdivisionCode, CountryCode};
+use ip2location_continent_multilingual::{Record as ContinentRecord, RECORDS as CONTINENT_RECORDS};
+use ip2location_country_information::{
+    Record as CountryInformationRecord, RECORDS_COUNTRY_CODE_MAP as COUNTRY_INFORMATION_MAP,
+};
+use ip2location_iso3166_2::RESOLVER as SUBDIVISION_RESOLVER;
+use language_code::{LanguageCode, LanguageTag};
+use once_cell::sync::Lazy;
+
+use crate::record::Record;
+
+//
+static CONTINENT_MAP: Lazy<HashMap<CountryCode, &'static ContinentRecord>> = Lazy::new(|| {
+    let lang = LanguageTag::new(LanguageCode::en, None);
+    CONTINENT_RECORDS
+        .iter()
+        .filter(|x| x.lang == lang)
+        .map(|x| (x.country_alpha2_code.to_owned(), x))
+        .collect()
+});
+
+//
+#[derive(Debug, Clone)]
+pub struct EnrichedRecord {
+    pub record: Record,
+    /// The capital, currency, IDD code, ccTLD, population, etc.
+    pub country_information: Option<&'static CountryInformationRecord>,
+    pub continent_code: Option<ContinentCode>,
+    /// In English.
+    pub continent_name: Option<&'static str>,
+    /// Of the `region_name` (DB3+).
+    pub subdivision_code: Option<SubdivisionCode>,
+}
+
+impl Deref for EnrichedRecord {
+    type Target = Record;
+
+    fn deref(&self) -> &Self::Target {
+        &self.record
+    }
+}
+
+impl From<Record> for EnrichedRecord {
+    fn from(record: Record) -> Self {
+        let country_code = &record.country_code.0;
+
+        let country_information = COUNTRY_INFORMATION_MAP.get(country_code);
+        let continent = CONTINENT_MAP.get(country_code).copied();
+        let subdivision_code = record.region_name.as_deref().and_then(|region_name| {
+            SUBDIVISION_RESOLVER
+                .resolve(country_code, region_name)
+                .cloned()
+        });
+
+        Self {
+            country_information,
+            continent_code: continent.map(|x| x.continent_code.to_owned()),
+            continent_name: continent.map(|x| x.continent.as_ref()),
+            subdivision_code,
+            record,
+        }
+    }
+}
+
+impl Record {
+    pub fn enrich(self) -> EnrichedRecord {
+        self.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::net::Ipv4Addr;
+
+    use country_code::iso3166_2::USSubdivisionCode;
+    use currency_code::CurrencyCode;
+
+    use crate::record::CountryCode as RecordCountryCode;
+
+    #[test]
+    fn test_enrich() -> Result<(), Box<dyn std::error::Error>> {
+        let ip = Ipv4Addr::new(1, 0, 0, 1).into();
+
+        let record = Record {
+            country_code: RecordCountryCode(CountryCode::US),
+            region_name: Some("California".into()),
+            ..Record::with_empty(ip, ip)
+        }
+        .enrich();
+        let country_information = record.country_information.unwrap();
+        assert_eq!(country_information.capital, "Washington, D.C.".into());
+        assert_eq!(country_information.currency_code, CurrencyCode::USD);
+        assert_eq!(country_information.idd_code, "1".into());
+        assert_eq!(country_information.cctld, "us".into());
+        assert_eq!(record.continent_code, Some(ContinentCode::NA));
+        assert_eq!(record.continent_name, Some("North America"));
+        assert_eq!(
+            record.subdivision_code,
+            Some(SubdivisionCode::US(USSubdivisionCode::CA))
+        );
+        assert_eq!(record.region_name, Some("California".into()));
+
+        let record = Record {
+            country_code: RecordCountryCode(CountryCode::FR),
+            region_name: Some("Île-de-France".into()),
+            ..Record::with_empty(ip, ip)
+        }
+        .enrich();
+        assert_eq!(record.subdivision_code, Some("FR-IDF".parse()?));
+        assert_eq!(record.continent_code, Some(ContinentCode::EU));
+
+        let record = Record::with_empty(ip, ip).enrich();
+        assert!(record.country_information.is_none());
+        assert!(record.continent_code.is_none());
+        assert!(record.subdivision_code.is_none());
+
+        Ok(())
+    }
+}
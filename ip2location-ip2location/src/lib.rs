@@ -1,8 +1,21 @@
 #![doc = include_str!("../README.md")]
 
 //
+#[cfg(feature = "enriched")]
+pub mod enriched;
+#[cfg(feature = "localized")]
+pub mod localized;
 pub mod record;
+#[cfg(feature = "chrono_tz")]
+pub mod time_zone;
 
 //
 pub mod bin_format;
 pub mod csv_format;
+
+#[cfg(feature = "zip")]
+pub use ip2location_bin_format::zip_archive;
+
+//
+#[cfg(test)]
+pub(crate) mod test_helper;
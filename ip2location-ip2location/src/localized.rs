@@ -0,0 +1,89 @@
+//! The country and continent names of a [`Record`] in the language of the UI.
+
+use core::ops::Deref;
+
+use ip2location_continent_multilingual::continent_name;
+use ip2location_country_multilingual::country_name;
+pub use language_code::LanguageTag;
+
+use crate::record::Record;
+
+//
+#[derive(Debug, Clone)]
+pub struct LocalizedRecord<'a> {
+    pub record: &'a Record,
+    /// Falls back to the `country_name` of the record, which is in English.
+    pub country_name: Option<&'a str>,
+    pub continent_name: Option<&'static str>,
+}
+
+impl Deref for LocalizedRecord<'_> {
+    type Target = Record;
+
+    fn deref(&self) -> &Self::Target {
+        self.record
+    }
+}
+
+impl Record {
+    /// With the fallbacks, e.g. `zh-TW`, then `zh`, then any `zh-*`, then `en`.
+    pub fn localize(&self, lang: &LanguageTag) -> LocalizedRecord<'_> {
+        let country_code = &self.country_code.0;
+
+        let (country_name, continent_name) = if self.country_code.is_default() {
+            (None, None)
+        } else {
+            (
+                country_name(country_code, lang),
+                continent_name(country_code, lang),
+            )
+        };
+
+        LocalizedRecord {
+            record: self,
+            country_name: country_name.or(self.country_name.as_deref()),
+            continent_name,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::net::Ipv4Addr;
+
+    use country_code::CountryCode;
+
+    use crate::record::CountryCode as RecordCountryCode;
+
+    #[test]
+    fn test_localize() -> Result<(), Box<dyn std::error::Error>> {
+        let ip = Ipv4Addr::new(1, 0, 0, 1).into();
+        let record = Record {
+            country_code: RecordCountryCode(CountryCode::JP),
+            country_name: Some("Japan".into()),
+            ..Record::with_empty(ip, ip)
+        };
+
+        let localized = record.localize(&"zh-TW".parse()?);
+        assert_eq!(localized.country_name, Some("日本"));
+        assert_eq!(localized.continent_name, Some("亞洲"));
+        assert_eq!(localized.country_code.to_string(), "JP");
+
+        let localized = record.localize(&"en-GB".parse()?);
+        assert_eq!(localized.country_name, Some("Japan"));
+        assert_eq!(localized.continent_name, Some("Asia"));
+
+        let record = Record {
+            country_code: RecordCountryCode(CountryCode::Other("ZZ".into())),
+            country_name: Some("Nowhere".into()),
+            ..Record::with_empty(ip, ip)
+        };
+        let localized = record.localize(&"zh-TW".parse()?);
+        assert_eq!(localized.country_name, Some("Nowhere"));
+        assert_eq!(localized.continent_name, None);
+
+        Ok(())
+    }
+}
@@ -4,6 +4,7 @@ use core::ops::Deref;
 use std::net::IpAddr;
 
 use country_code::CountryCode as CountryCodeInner;
+use ip2location_bin_format::record_field::RecordField as BinRecordField;
 
 //
 #[cfg_attr(feature = "serde", derive(serde::Deserialize))]
@@ -60,6 +61,76 @@ pub struct Record {
         serde(default, deserialize_with = "option_box_str_deserialize")
     )]
     pub net_speed: Option<Box<str>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, deserialize_with = "option_box_str_deserialize")
+    )]
+    pub idd_code: Option<Box<str>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, deserialize_with = "option_box_str_deserialize")
+    )]
+    pub area_code: Option<Box<str>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, deserialize_with = "option_box_str_deserialize")
+    )]
+    pub weather_station_code: Option<Box<str>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, deserialize_with = "option_box_str_deserialize")
+    )]
+    pub weather_station_name: Option<Box<str>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, deserialize_with = "option_box_str_deserialize")
+    )]
+    pub mcc: Option<Box<str>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, deserialize_with = "option_box_str_deserialize")
+    )]
+    pub mnc: Option<Box<str>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, deserialize_with = "option_box_str_deserialize")
+    )]
+    pub mobile_brand: Option<Box<str>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, deserialize_with = "option_f32_deserialize")
+    )]
+    pub elevation: Option<f32>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, deserialize_with = "option_box_str_deserialize")
+    )]
+    pub usage_type: Option<Box<str>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, deserialize_with = "option_box_str_deserialize")
+    )]
+    pub address_type: Option<Box<str>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, deserialize_with = "option_box_str_deserialize")
+    )]
+    pub category: Option<Box<str>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, deserialize_with = "option_box_str_deserialize")
+    )]
+    pub district: Option<Box<str>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, deserialize_with = "option_usize_deserialize")
+    )]
+    pub asn: Option<usize>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, deserialize_with = "option_box_str_deserialize")
+    )]
+    pub as_name: Option<Box<str>>,
 }
 
 #[cfg(feature = "serde")]
@@ -143,6 +214,44 @@ where
     }
 }
 
+#[cfg(feature = "serde")]
+fn option_f32_deserialize<'de, D>(deserializer: D) -> Result<Option<f32>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use ip2location_bin_format::content::UNKNOWN_STR;
+    use serde::Deserialize as _;
+
+    let s = Box::<str>::deserialize(deserializer)?;
+    if s == UNKNOWN_STR.into() {
+        Ok(None)
+    } else {
+        match s.parse::<f32>() {
+            Ok(v) => Ok(Some(v)),
+            Err(err) => Err(serde::de::Error::custom(err.to_string())),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+fn option_usize_deserialize<'de, D>(deserializer: D) -> Result<Option<usize>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use ip2location_bin_format::content::UNKNOWN_STR;
+    use serde::Deserialize as _;
+
+    let s = Box::<str>::deserialize(deserializer)?;
+    if s == UNKNOWN_STR.into() {
+        Ok(None)
+    } else {
+        match s.parse::<usize>() {
+            Ok(v) => Ok(Some(v)),
+            Err(err) => Err(serde::de::Error::custom(err.to_string())),
+        }
+    }
+}
+
 impl Record {
     pub(crate) fn with_empty(ip_from: IpAddr, ip_to: IpAddr) -> Self {
         Self {
@@ -159,6 +268,20 @@ impl Record {
             isp: Default::default(),
             domain: Default::default(),
             net_speed: Default::default(),
+            idd_code: Default::default(),
+            area_code: Default::default(),
+            weather_station_code: Default::default(),
+            weather_station_name: Default::default(),
+            mcc: Default::default(),
+            mnc: Default::default(),
+            mobile_brand: Default::default(),
+            elevation: Default::default(),
+            usage_type: Default::default(),
+            address_type: Default::default(),
+            category: Default::default(),
+            district: Default::default(),
+            asn: Default::default(),
+            as_name: Default::default(),
         }
     }
 }
@@ -173,7 +296,7 @@ impl
         ip2location_bin_format::record_field::RecordFieldContents,
     )> for OptionRecord
 {
-    type Error = Box<str>;
+    type Error = ToRecordError;
 
     fn try_from(
         (ip_from, ip_to, record_field_contents): (
@@ -190,10 +313,14 @@ impl
             match record_field_content {
                 RecordFieldContent::COUNTRY(_, v, v_name) => {
                     if let Some(v) = v {
-                        record.country_code = CountryCode(
-                            v.parse::<CountryCodeInner>()
-                                .map_err(|err| Box::from(err.to_string()))?,
-                        );
+                        record.country_code =
+                            CountryCode(v.parse::<CountryCodeInner>().map_err(|err| {
+                                ToRecordError::ValueInvalid(
+                                    BinRecordField::COUNTRY,
+                                    v.clone(),
+                                    Box::new(err),
+                                )
+                            })?);
                     } else {
                         return Ok(OptionRecord(None));
                     }
@@ -228,35 +355,421 @@ impl
                 RecordFieldContent::NETSPEED(_, v) => {
                     record.net_speed = v.to_owned();
                 }
+                RecordFieldContent::IDDCODE(_, v) => {
+                    record.idd_code = v.to_owned();
+                }
+                RecordFieldContent::AREACODE(_, v) => {
+                    record.area_code = v.to_owned();
+                }
+                RecordFieldContent::WEATHERSTATIONCODE(_, v) => {
+                    record.weather_station_code = v.to_owned();
+                }
+                RecordFieldContent::WEATHERSTATIONNAME(_, v) => {
+                    record.weather_station_name = v.to_owned();
+                }
+                RecordFieldContent::MCC(_, v) => {
+                    record.mcc = v.to_owned();
+                }
+                RecordFieldContent::MNC(_, v) => {
+                    record.mnc = v.to_owned();
+                }
+                RecordFieldContent::MOBILEBRAND(_, v) => {
+                    record.mobile_brand = v.to_owned();
+                }
+                RecordFieldContent::ELEVATION(_, v) => {
+                    if let Some(v) = v {
+                        let v = v.parse::<f32>().map_err(|err| {
+                            ToRecordError::ValueInvalid(
+                                BinRecordField::ELEVATION,
+                                v.clone(),
+                                Box::new(err),
+                            )
+                        })?;
+                        record.elevation = Some(v);
+                    }
+                }
+                RecordFieldContent::USAGETYPE(_, v) => {
+                    record.usage_type = v.to_owned();
+                }
+                RecordFieldContent::ADDRESSTYPE(_, v) => {
+                    record.address_type = v.to_owned();
+                }
+                RecordFieldContent::CATEGORY(_, v) => {
+                    record.category = v.to_owned();
+                }
+                RecordFieldContent::DISTRICT(_, v) => {
+                    record.district = v.to_owned();
+                }
+                RecordFieldContent::ASN(_, v) => {
+                    if let Some(v) = v {
+                        let v = v.parse::<usize>().map_err(|err| {
+                            ToRecordError::ValueInvalid(
+                                BinRecordField::ASN,
+                                v.clone(),
+                                Box::new(err),
+                            )
+                        })?;
+                        record.asn = Some(v);
+                    }
+                }
+                RecordFieldContent::AS(_, v) => {
+                    record.as_name = v.to_owned();
+                }
                 //
                 RecordFieldContent::PROXYTYPE(_, _) => {
-                    return Err("Unknown field PROXYTYPE".into());
+                    return Err(ToRecordError::FieldUnsupported(BinRecordField::PROXYTYPE));
+                }
+                RecordFieldContent::LASTSEEN(_, _) => {
+                    return Err(ToRecordError::FieldUnsupported(BinRecordField::LASTSEEN));
+                }
+                RecordFieldContent::THREAT(_, _) => {
+                    return Err(ToRecordError::FieldUnsupported(BinRecordField::THREAT));
                 }
-                RecordFieldContent::USAGETYPE(_, _) => {
-                    return Err("Unknown field USAGETYPE".into());
+                RecordFieldContent::RESIDENTIAL(_, _) => {
+                    return Err(ToRecordError::FieldUnsupported(BinRecordField::RESIDENTIAL));
                 }
-                RecordFieldContent::ASN(_, _) => {
-                    return Err("Unknown field ASN".into());
+                RecordFieldContent::PROVIDER(_, _) => {
+                    return Err(ToRecordError::FieldUnsupported(BinRecordField::PROVIDER));
                 }
-                RecordFieldContent::AS(_, _) => {
-                    return Err("Unknown field AS".into());
+                RecordFieldContent::FRAUDSCORE(_, _) => {
+                    return Err(ToRecordError::FieldUnsupported(BinRecordField::FRAUDSCORE));
+                }
+            }
+        }
+
+        Ok(OptionRecord(Some(record)))
+    }
+}
+
+//
+#[derive(Debug)]
+pub enum ToRecordError {
+    FieldUnsupported(BinRecordField),
+    /// The field, the value and the parse error.
+    ValueInvalid(
+        BinRecordField,
+        Box<str>,
+        Box<dyn std::error::Error + Send + Sync + 'static>,
+    ),
+}
+
+impl core::fmt::Display for ToRecordError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::FieldUnsupported(record_field) => {
+                write!(f, "field {record_field:?} is unsupported")
+            }
+            Self::ValueInvalid(record_field, value, _) => {
+                write!(f, "value {value:?} of field {record_field:?} is invalid")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ToRecordError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::FieldUnsupported(_) => None,
+            Self::ValueInvalid(_, _, err) => Some(err.as_ref()),
+        }
+    }
+}
+
+//
+/// A [`Record`] with the strs borrowed from the BIN bytes, see [`Database::lookup_ref`](crate::bin_format::Database::lookup_ref).
+#[derive(Debug, Clone)]
+pub struct RecordRef<'a> {
+    pub ip_from: IpAddr,
+    pub ip_to: IpAddr,
+    pub country_code: CountryCode,
+    pub country_name: Option<&'a str>,
+    pub region_name: Option<&'a str>,
+    pub city_name: Option<&'a str>,
+    pub latitude: Option<f32>,
+    pub longitude: Option<f32>,
+    pub zip_code: Option<&'a str>,
+    pub time_zone: Option<&'a str>,
+    pub isp: Option<&'a str>,
+    pub domain: Option<&'a str>,
+    pub net_speed: Option<&'a str>,
+    pub idd_code: Option<&'a str>,
+    pub area_code: Option<&'a str>,
+    pub weather_station_code: Option<&'a str>,
+    pub weather_station_name: Option<&'a str>,
+    pub mcc: Option<&'a str>,
+    pub mnc: Option<&'a str>,
+    pub mobile_brand: Option<&'a str>,
+    pub elevation: Option<f32>,
+    pub usage_type: Option<&'a str>,
+    pub address_type: Option<&'a str>,
+    pub category: Option<&'a str>,
+    pub district: Option<&'a str>,
+    pub asn: Option<usize>,
+    pub as_name: Option<&'a str>,
+}
+
+impl<'a> RecordRef<'a> {
+    pub(crate) fn with_empty(ip_from: IpAddr, ip_to: IpAddr) -> Self {
+        Self {
+            ip_from,
+            ip_to,
+            country_code: Default::default(),
+            country_name: Default::default(),
+            region_name: Default::default(),
+            city_name: Default::default(),
+            latitude: Default::default(),
+            longitude: Default::default(),
+            zip_code: Default::default(),
+            time_zone: Default::default(),
+            isp: Default::default(),
+            domain: Default::default(),
+            net_speed: Default::default(),
+            idd_code: Default::default(),
+            area_code: Default::default(),
+            weather_station_code: Default::default(),
+            weather_station_name: Default::default(),
+            mcc: Default::default(),
+            mnc: Default::default(),
+            mobile_brand: Default::default(),
+            elevation: Default::default(),
+            usage_type: Default::default(),
+            address_type: Default::default(),
+            category: Default::default(),
+            district: Default::default(),
+            asn: Default::default(),
+            as_name: Default::default(),
+        }
+    }
+
+    /// Returns false if the country is unknown, the record is skipped like [`Record`].
+    pub(crate) fn fill(
+        &mut self,
+        record_field_content: ip2location_bin_format::bytes_querier::RecordFieldContentRef<'a>,
+    ) -> Result<bool, ToRecordError> {
+        use ip2location_bin_format::bytes_querier::RecordFieldContentRef;
+
+        match record_field_content {
+            RecordFieldContentRef::COUNTRY(v, v_name) => {
+                if let Some(v) = v {
+                    self.country_code =
+                        CountryCode(v.parse::<CountryCodeInner>().map_err(|err| {
+                            ToRecordError::ValueInvalid(
+                                BinRecordField::COUNTRY,
+                                Box::from(v),
+                                Box::new(err),
+                            )
+                        })?);
+                } else {
+                    return Ok(false);
+                }
+
+                self.country_name = v_name;
+            }
+            RecordFieldContentRef::REGION(v) => self.region_name = v,
+            RecordFieldContentRef::CITY(v) => self.city_name = v,
+            RecordFieldContentRef::ISP(v) => self.isp = v,
+            RecordFieldContentRef::DOMAIN(v) => self.domain = v,
+            //
+            RecordFieldContentRef::LATITUDE(v) => self.latitude = Some(v),
+            RecordFieldContentRef::LONGITUDE(v) => self.longitude = Some(v),
+            RecordFieldContentRef::ZIPCODE(v) => self.zip_code = v,
+            RecordFieldContentRef::TIMEZONE(v) => self.time_zone = v,
+            RecordFieldContentRef::NETSPEED(v) => self.net_speed = v,
+            RecordFieldContentRef::IDDCODE(v) => self.idd_code = v,
+            RecordFieldContentRef::AREACODE(v) => self.area_code = v,
+            RecordFieldContentRef::WEATHERSTATIONCODE(v) => self.weather_station_code = v,
+            RecordFieldContentRef::WEATHERSTATIONNAME(v) => self.weather_station_name = v,
+            RecordFieldContentRef::MCC(v) => self.mcc = v,
+            RecordFieldContentRef::MNC(v) => self.mnc = v,
+            RecordFieldContentRef::MOBILEBRAND(v) => self.mobile_brand = v,
+            RecordFieldContentRef::ELEVATION(v) => {
+                if let Some(v) = v {
+                    let v = v.parse::<f32>().map_err(|err| {
+                        ToRecordError::ValueInvalid(
+                            BinRecordField::ELEVATION,
+                            Box::from(v),
+                            Box::new(err),
+                        )
+                    })?;
+                    self.elevation = Some(v);
+                }
+            }
+            RecordFieldContentRef::USAGETYPE(v) => self.usage_type = v,
+            RecordFieldContentRef::ADDRESSTYPE(v) => self.address_type = v,
+            RecordFieldContentRef::CATEGORY(v) => self.category = v,
+            RecordFieldContentRef::DISTRICT(v) => self.district = v,
+            RecordFieldContentRef::ASN(v) => {
+                if let Some(v) = v {
+                    let v = v.parse::<usize>().map_err(|err| {
+                        ToRecordError::ValueInvalid(
+                            BinRecordField::ASN,
+                            Box::from(v),
+                            Box::new(err),
+                        )
+                    })?;
+                    self.asn = Some(v);
+                }
+            }
+            RecordFieldContentRef::AS(v) => self.as_name = v,
+            //
+            x => {
+                return Err(ToRecordError::FieldUnsupported(x.record_field()));
+            }
+        }
+
+        Ok(true)
+    }
+
+    pub fn to_owned(&self) -> Record {
+        Record {
+            ip_from: self.ip_from,
+            ip_to: self.ip_to,
+            country_code: self.country_code.to_owned(),
+            country_name: self.country_name.map(Into::into),
+            region_name: self.region_name.map(Into::into),
+            city_name: self.city_name.map(Into::into),
+            latitude: self.latitude,
+            longitude: self.longitude,
+            zip_code: self.zip_code.map(Into::into),
+            time_zone: self.time_zone.map(Into::into),
+            isp: self.isp.map(Into::into),
+            domain: self.domain.map(Into::into),
+            net_speed: self.net_speed.map(Into::into),
+            idd_code: self.idd_code.map(Into::into),
+            area_code: self.area_code.map(Into::into),
+            weather_station_code: self.weather_station_code.map(Into::into),
+            weather_station_name: self.weather_station_name.map(Into::into),
+            mcc: self.mcc.map(Into::into),
+            mnc: self.mnc.map(Into::into),
+            mobile_brand: self.mobile_brand.map(Into::into),
+            elevation: self.elevation,
+            usage_type: self.usage_type.map(Into::into),
+            address_type: self.address_type.map(Into::into),
+            category: self.category.map(Into::into),
+            district: self.district.map(Into::into),
+            asn: self.asn,
+            as_name: self.as_name.map(Into::into),
+        }
+    }
+}
+
+impl From<RecordRef<'_>> for Record {
+    fn from(x: RecordRef<'_>) -> Self {
+        x.to_owned()
+    }
+}
+
+#[cfg(feature = "csv")]
+impl Record {
+    pub(crate) fn to_record_field_contents(
+        &self,
+        record_fields: &ip2location_bin_format::record_field::RecordFields,
+    ) -> Result<ip2location_bin_format::record_field::RecordFieldContents, ToRecordError> {
+        use ip2location_bin_format::record_field::RecordFieldContent;
+
+        let mut record_field_contents = record_fields.to_contents();
+
+        for record_field_content in record_field_contents.iter_mut() {
+            match record_field_content {
+                RecordFieldContent::COUNTRY(_, v, v_name) => {
+                    if !self.country_code.is_default() {
+                        *v = Some(self.country_code.to_string().into());
+                    }
+                    *v_name = self.country_name.to_owned();
+                }
+                RecordFieldContent::REGION(_, v) => {
+                    *v = self.region_name.to_owned();
+                }
+                RecordFieldContent::CITY(_, v) => {
+                    *v = self.city_name.to_owned();
+                }
+                RecordFieldContent::ISP(_, v) => {
+                    *v = self.isp.to_owned();
+                }
+                RecordFieldContent::DOMAIN(_, v) => {
+                    *v = self.domain.to_owned();
+                }
+                //
+                RecordFieldContent::LATITUDE(v) => {
+                    *v = self.latitude.unwrap_or_default();
+                }
+                RecordFieldContent::LONGITUDE(v) => {
+                    *v = self.longitude.unwrap_or_default();
+                }
+                RecordFieldContent::ZIPCODE(_, v) => {
+                    *v = self.zip_code.to_owned();
+                }
+                RecordFieldContent::TIMEZONE(_, v) => {
+                    *v = self.time_zone.to_owned();
+                }
+                RecordFieldContent::NETSPEED(_, v) => {
+                    *v = self.net_speed.to_owned();
+                }
+                RecordFieldContent::IDDCODE(_, v) => {
+                    *v = self.idd_code.to_owned();
+                }
+                RecordFieldContent::AREACODE(_, v) => {
+                    *v = self.area_code.to_owned();
+                }
+                RecordFieldContent::WEATHERSTATIONCODE(_, v) => {
+                    *v = self.weather_station_code.to_owned();
+                }
+                RecordFieldContent::WEATHERSTATIONNAME(_, v) => {
+                    *v = self.weather_station_name.to_owned();
+                }
+                RecordFieldContent::MCC(_, v) => {
+                    *v = self.mcc.to_owned();
+                }
+                RecordFieldContent::MNC(_, v) => {
+                    *v = self.mnc.to_owned();
+                }
+                RecordFieldContent::MOBILEBRAND(_, v) => {
+                    *v = self.mobile_brand.to_owned();
+                }
+                RecordFieldContent::ELEVATION(_, v) => {
+                    *v = self.elevation.map(|x| x.to_string().into());
+                }
+                RecordFieldContent::USAGETYPE(_, v) => {
+                    *v = self.usage_type.to_owned();
+                }
+                RecordFieldContent::ADDRESSTYPE(_, v) => {
+                    *v = self.address_type.to_owned();
+                }
+                RecordFieldContent::CATEGORY(_, v) => {
+                    *v = self.category.to_owned();
+                }
+                RecordFieldContent::DISTRICT(_, v) => {
+                    *v = self.district.to_owned();
+                }
+                RecordFieldContent::ASN(_, v) => {
+                    *v = self.asn.map(|x| x.to_string().into());
+                }
+                RecordFieldContent::AS(_, v) => {
+                    *v = self.as_name.to_owned();
+                }
+                //
+                RecordFieldContent::PROXYTYPE(_, _) => {
+                    return Err(ToRecordError::FieldUnsupported(BinRecordField::PROXYTYPE));
                 }
                 RecordFieldContent::LASTSEEN(_, _) => {
-                    return Err("Unknown field LASTSEEN".into());
+                    return Err(ToRecordError::FieldUnsupported(BinRecordField::LASTSEEN));
                 }
                 RecordFieldContent::THREAT(_, _) => {
-                    return Err("Unknown field THREAT".into());
+                    return Err(ToRecordError::FieldUnsupported(BinRecordField::THREAT));
                 }
                 RecordFieldContent::RESIDENTIAL(_, _) => {
-                    return Err("Unknown field RESIDENTIAL".into());
+                    return Err(ToRecordError::FieldUnsupported(BinRecordField::RESIDENTIAL));
                 }
                 RecordFieldContent::PROVIDER(_, _) => {
-                    return Err("Unknown field PROVIDER".into());
+                    return Err(ToRecordError::FieldUnsupported(BinRecordField::PROVIDER));
+                }
+                RecordFieldContent::FRAUDSCORE(_, _) => {
+                    return Err(ToRecordError::FieldUnsupported(BinRecordField::FRAUDSCORE));
                 }
             }
         }
 
-        Ok(OptionRecord(Some(record)))
+        Ok(record_field_contents)
     }
 }
 
@@ -276,6 +789,20 @@ pub enum RecordField {
     ZipCode,
     TimeZone,
     NetSpeed,
+    IddCode,
+    AreaCode,
+    WeatherStationCode,
+    WeatherStationName,
+    Mcc,
+    Mnc,
+    MobileBrand,
+    Elevation,
+    UsageType,
+    AddressType,
+    Category,
+    District,
+    Asn,
+    AsName,
 }
 
 impl From<&RecordField> for ip2location_bin_format::record_field::RecordField {
@@ -292,6 +819,20 @@ impl From<&RecordField> for ip2location_bin_format::record_field::RecordField {
             RecordField::ZipCode => Self::ZIPCODE,
             RecordField::TimeZone => Self::TIMEZONE,
             RecordField::NetSpeed => Self::NETSPEED,
+            RecordField::IddCode => Self::IDDCODE,
+            RecordField::AreaCode => Self::AREACODE,
+            RecordField::WeatherStationCode => Self::WEATHERSTATIONCODE,
+            RecordField::WeatherStationName => Self::WEATHERSTATIONNAME,
+            RecordField::Mcc => Self::MCC,
+            RecordField::Mnc => Self::MNC,
+            RecordField::MobileBrand => Self::MOBILEBRAND,
+            RecordField::Elevation => Self::ELEVATION,
+            RecordField::UsageType => Self::USAGETYPE,
+            RecordField::AddressType => Self::ADDRESSTYPE,
+            RecordField::Category => Self::CATEGORY,
+            RecordField::District => Self::DISTRICT,
+            RecordField::Asn => Self::ASN,
+            RecordField::AsName => Self::AS,
         }
     }
 }
@@ -0,0 +1,93 @@
+use ip2location_bin_format::{
+    header::schema::{Schema as HeaderSchema, SchemaSubType, SchemaType},
+    record_field::RecordFieldContent,
+    Builder,
+};
+
+//
+pub(crate) struct Location {
+    pub(crate) country: (&'static str, &'static str),
+    pub(crate) region: &'static str,
+    pub(crate) city: &'static str,
+    pub(crate) latitude: f32,
+    pub(crate) longitude: f32,
+    pub(crate) zip_code: &'static str,
+    pub(crate) time_zone: &'static str,
+}
+
+pub(crate) const LOS_ANGELES: Location = Location {
+    country: ("US", "United States of America"),
+    region: "California",
+    city: "Los Angeles",
+    latitude: 34.05286,
+    longitude: -118.2437,
+    zip_code: "90001",
+    time_zone: "-07:00",
+};
+
+pub(crate) const FUZHOU: Location = Location {
+    country: ("CN", "China"),
+    region: "Fujian",
+    city: "Fuzhou",
+    latitude: 26.06139,
+    longitude: 119.30611,
+    zip_code: "350004",
+    time_zone: "+08:00",
+};
+
+pub(crate) const BRISBANE: Location = Location {
+    country: ("AU", "Australia"),
+    region: "Queensland",
+    city: "Brisbane",
+    latitude: -27.46794,
+    longitude: 153.02809,
+    zip_code: "4000",
+    time_zone: "+10:00",
+};
+
+pub(crate) const MELBOURNE: Location = Location {
+    country: ("AU", "Australia"),
+    region: "Victoria",
+    city: "Melbourne",
+    latitude: -37.814,
+    longitude: 144.96332,
+    zip_code: "3000",
+    time_zone: "+10:00",
+};
+
+/// The DBN BIN of the ranges, the fields which are not in DBN are skipped.
+pub(crate) fn bin_bytes(
+    sub_type: u8,
+    ranges: &[(&str, &str, &Location)],
+) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    let mut header = HeaderSchema::default();
+    header.r#type = SchemaType::IP2Location;
+    header.sub_type = SchemaSubType(sub_type);
+    header.date = (22, 11, 1);
+    let mut builder = Builder::new(header)?;
+
+    for (ip_from, ip_to, location) in ranges {
+        let mut record_field_contents = builder.record_fields().to_contents();
+        for record_field_content in record_field_contents.iter_mut() {
+            match record_field_content {
+                RecordFieldContent::COUNTRY(_, v, v_name) => {
+                    *v = Some(location.country.0.into());
+                    *v_name = Some(location.country.1.into());
+                }
+                RecordFieldContent::REGION(_, v) => *v = Some(location.region.into()),
+                RecordFieldContent::CITY(_, v) => *v = Some(location.city.into()),
+                RecordFieldContent::LATITUDE(v) => *v = location.latitude,
+                RecordFieldContent::LONGITUDE(v) => *v = location.longitude,
+                RecordFieldContent::ZIPCODE(_, v) => *v = Some(location.zip_code.into()),
+                RecordFieldContent::TIMEZONE(_, v) => *v = Some(location.time_zone.into()),
+                _ => {}
+            }
+        }
+        builder.append(ip_from.parse()?, ip_to.parse()?, record_field_contents)?;
+    }
+
+    let mut bytes = vec![];
+    builder.finish(&mut bytes)?;
+
+    Ok(bytes)
+}
@@ -0,0 +1,200 @@
+//! The IANA time zone of a [`Record`], by the [Olson Time Zone Database](https://www.ip2location.com/free/olson-timezone).
+//!
+//! The bundled database only covers some regions, see [`ip2location_olson_timezone::RECORDS`].
+
+use chrono::{DateTime, Duration, FixedOffset, Offset as _, Utc};
+use chrono_tz::OffsetComponents as _;
+pub use chrono_tz::Tz;
+use country_code::CountryCode;
+use ip2location_olson_timezone::RECORDS;
+
+use crate::record::Record;
+
+//
+impl Record {
+    /// Resolved by the country, the region and the TIMEZONE (DB11+), or by the region only if the TIMEZONE is missing.
+    ///
+    /// Falls back to a fixed `Etc/GMT±N` zone without DST if only a whole hour UTC offset is known,
+    /// the UTC offset is estimated from the longitude if the TIMEZONE is missing (DB5 and DB9).
+    pub fn tz(&self) -> Option<Tz> {
+        if self.country_code.is_default() {
+            return None;
+        }
+
+        tz_from_olson(
+            &self.country_code,
+            self.region_name.as_deref(),
+            self.time_zone.as_deref(),
+        )
+        .or_else(|| {
+            self.time_zone
+                .as_deref()
+                .map(ToOwned::to_owned)
+                .or_else(|| self.longitude.and_then(time_zone_from_longitude))
+                .as_deref()
+                .and_then(tz_from_time_zone)
+        })
+    }
+
+    pub fn local_time(&self) -> Option<LocalTime> {
+        self.local_time_at(Utc::now())
+    }
+
+    pub fn local_time_at(&self, at: DateTime<Utc>) -> Option<LocalTime> {
+        self.tz().map(|tz| LocalTime::new(tz, at))
+    }
+}
+
+//
+#[derive(Debug, Clone, Copy)]
+pub struct LocalTime {
+    pub date_time: DateTime<Tz>,
+    /// Including the DST offset.
+    pub utc_offset: FixedOffset,
+    pub is_dst: bool,
+}
+
+impl LocalTime {
+    pub fn new(tz: Tz, at: DateTime<Utc>) -> Self {
+        let date_time = at.with_timezone(&tz);
+        let offset = date_time.offset();
+
+        Self {
+            utc_offset: offset.fix(),
+            is_dst: offset.dst_offset() != Duration::zero(),
+            date_time,
+        }
+    }
+
+    pub fn tz(&self) -> Tz {
+        self.date_time.timezone()
+    }
+}
+
+//
+fn tz_from_olson(
+    country_code: &CountryCode,
+    region_name: Option<&str>,
+    time_zone: Option<&str>,
+) -> Option<Tz> {
+    let record = match (region_name, time_zone) {
+        (region_name, Some(time_zone)) => {
+            RECORDS.find_by_time_zone(country_code, region_name, time_zone)
+        }
+        (Some(region_name), None) => RECORDS.find(country_code, region_name),
+        (None, None) => None,
+    }?;
+
+    record.olson_time_zone.parse().ok()
+}
+
+// e.g. -05:00 to Etc/GMT+5
+fn tz_from_time_zone(time_zone: &str) -> Option<Tz> {
+    let time_zone = time_zone.trim();
+    let (sign, hh_mm) = if let Some(x) = time_zone.strip_prefix('+') {
+        ('-', x)
+    } else {
+        ('+', time_zone.strip_prefix('-')?)
+    };
+    let (hh, mm) = hh_mm.split_once(':')?;
+    if mm.parse::<u8>().ok()? != 0 {
+        return None;
+    }
+
+    match hh.parse::<u8>().ok()? {
+        0 => Some(Tz::Etc__UTC),
+        hh => format!("Etc/GMT{sign}{hh}").parse().ok(),
+    }
+}
+
+// The nautical zone, 15 degrees per hour, e.g. 139.69 to +09:00.
+// The civil zones follow the borders, so it is often an hour or more off, and it knows nothing about DST.
+fn time_zone_from_longitude(longitude: f32) -> Option<String> {
+    if !(-180.0..=180.0).contains(&longitude) {
+        return None;
+    }
+
+    let hours = (longitude / 15.0).round() as i8;
+    let sign = if hours < 0 { '-' } else { '+' };
+    Some(format!("{sign}{:02}:00", hours.unsigned_abs()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::net::Ipv4Addr;
+
+    use chrono::TimeZone as _;
+
+    use crate::record::CountryCode as RecordCountryCode;
+
+    fn record(
+        country_code: CountryCode,
+        region_name: Option<&str>,
+        time_zone: Option<&str>,
+        longitude: Option<f32>,
+    ) -> Record {
+        let ip = Ipv4Addr::new(1, 0, 0, 1).into();
+        Record {
+            country_code: RecordCountryCode(country_code),
+            region_name: region_name.map(Into::into),
+            time_zone: time_zone.map(Into::into),
+            longitude,
+            ..Record::with_empty(ip, ip)
+        }
+    }
+
+    #[test]
+    fn test_tz() -> Result<(), Box<dyn std::error::Error>> {
+        let summer = Utc.with_ymd_and_hms(2022, 7, 1, 12, 0, 0).unwrap();
+        let winter = Utc.with_ymd_and_hms(2022, 1, 1, 12, 0, 0).unwrap();
+
+        let r = record(CountryCode::US, Some("California"), Some("-07:00"), None);
+        assert_eq!(r.tz(), Some(Tz::America__Los_Angeles));
+        let local_time = r.local_time_at(summer).unwrap();
+        assert!(local_time.is_dst);
+        assert_eq!(local_time.utc_offset.local_minus_utc(), -7 * 3600);
+        assert_eq!(
+            local_time.date_time.to_rfc3339(),
+            "2022-07-01T05:00:00-07:00"
+        );
+        let local_time = r.local_time_at(winter).unwrap();
+        assert!(!local_time.is_dst);
+        assert_eq!(local_time.utc_offset.local_minus_utc(), -8 * 3600);
+        assert_eq!(local_time.tz(), Tz::America__Los_Angeles);
+
+        let r = record(CountryCode::JP, None, Some("+09:00"), None);
+        assert_eq!(r.tz(), Some(Tz::Asia__Tokyo));
+
+        // The region without the TIMEZONE, the longitude is not used.
+        let r = record(CountryCode::US, Some("New York"), None, Some(-73.98));
+        assert_eq!(r.tz(), Some(Tz::America__New_York));
+
+        // The estimate from the longitude is only for the fallback, not for the Olson lookup.
+        let r = record(CountryCode::JP, None, None, Some(139.69));
+        assert_eq!(r.tz(), Some(Tz::Etc__GMTMinus9));
+        let r = record(CountryCode::ES, Some("Madrid"), None, Some(-3.70));
+        assert_eq!(r.tz(), Some(Tz::Etc__UTC));
+
+        // ZW is not in the records, so it falls back to the TIMEZONE.
+        let r = record(CountryCode::ZW, Some("Harare"), Some("+02:00"), None);
+        assert_eq!(r.tz(), Some(Tz::Etc__GMTMinus2));
+        assert!(!r.local_time_at(summer).unwrap().is_dst);
+
+        let r = record(CountryCode::IN, Some("Unknown"), Some("+05:30"), None);
+        assert_eq!(r.tz(), Some(Tz::Asia__Kolkata));
+
+        let r = record(CountryCode::NP, None, Some("+05:45"), None);
+        assert_eq!(r.tz(), None);
+
+        let r = Record::with_empty(
+            Ipv4Addr::new(1, 0, 0, 1).into(),
+            Ipv4Addr::new(1, 0, 0, 1).into(),
+        );
+        assert_eq!(r.tz(), None);
+        assert!(r.local_time().is_none());
+
+        Ok(())
+    }
+}
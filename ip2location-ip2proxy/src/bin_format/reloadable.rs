@@ -0,0 +1,25 @@
+//! [`Database`] which swaps to a new BIN, e.g. the monthly LITE update.
+//!
+//! The lookups in flight keep the old [`Database`] until they finish.
+//! Replace the file by rename, the old [`Database`] may still read the old file.
+//! A `Database::open_mmap` one must only be replaced by rename, writing the file in place is undefined behavior.
+
+use ip2location_bin_format::{header::Schema as HeaderSchema, reloadable::DatabaseHeader};
+
+use super::{Database, DatabaseNewError};
+
+//
+/// The loader opens the [`Database`] at the path, e.g. `ReloadableDatabase::new(path, Database::open_read_at)`.
+///
+/// The lookups are on the current [`Database`], e.g. `db.database().lookup(ip, None)`.
+pub type ReloadableDatabase<S> =
+    ip2location_bin_format::reloadable::ReloadableDatabase<Database<S>, DatabaseNewError>;
+
+pub type DatabaseReloadError =
+    ip2location_bin_format::reloadable::DatabaseReloadError<DatabaseNewError>;
+
+impl<S> DatabaseHeader for Database<S> {
+    fn header(&self) -> &HeaderSchema {
+        &self.inner.header
+    }
+}
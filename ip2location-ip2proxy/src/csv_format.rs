@@ -16,3 +16,255 @@ pub const CSV_HEADER_PX11: &[&str] = &[
     "threat",
     "provider",
 ];
+
+pub const CSV_HEADER_PX12: &[&str] = &[
+    "ip_from",
+    "ip_to",
+    "proxy_type",
+    "country_code",
+    "country_name",
+    "region_name",
+    "city_name",
+    "isp",
+    "domain",
+    "usage_type",
+    "asn",
+    "as_name",
+    "last_seen",
+    "threat",
+    "provider",
+    "fraud_score",
+];
+
+//
+/// Converts a LITE/commercial CSV (v4 or IPV6) to BIN.
+///
+/// csv_header is the columns of the CSV, e.g. [`CSV_HEADER_PX11`] or [`CSV_HEADER_PX12`],
+/// sub_type is the N of PXN.
+#[cfg(feature = "csv")]
+pub fn csv_to_bin<R: std::io::Read, W: std::io::Write>(
+    rdr: R,
+    csv_header: &[&str],
+    sub_type: u8,
+    date: (u8, u8, u8),
+    w: &mut W,
+) -> Result<ip2location_bin_format::header::Schema, CsvToBinError> {
+    use ip2location_bin_format::header::schema::{Schema, SchemaSubType, SchemaType};
+
+    let mut header = Schema::default();
+    header.r#type = SchemaType::IP2Proxy;
+    header.sub_type = SchemaSubType(sub_type);
+    header.date = date;
+
+    ip2location_bin_format::csv_format::csv_to_bin::<crate::record::Record, _, _>(
+        rdr, csv_header, header, w,
+    )
+}
+
+#[cfg(feature = "csv")]
+impl ip2location_bin_format::csv_format::CsvRow for crate::record::Record {
+    type ToRecordFieldContentsError = crate::record::ToRecordError;
+
+    fn ip_range(&self) -> (std::net::IpAddr, std::net::IpAddr) {
+        (self.ip_from, self.ip_to)
+    }
+
+    fn to_record_field_contents(
+        &self,
+        record_fields: &ip2location_bin_format::record_field::RecordFields,
+    ) -> Result<
+        ip2location_bin_format::record_field::RecordFieldContents,
+        Self::ToRecordFieldContentsError,
+    > {
+        self.to_record_field_contents(record_fields)
+    }
+
+    fn column_value(
+        column: &str,
+        record_field_content: &ip2location_bin_format::record_field::RecordFieldContent,
+    ) -> Option<String> {
+        use ip2location_bin_format::{content::UNKNOWN_STR, record_field::RecordFieldContent};
+
+        match (column, record_field_content) {
+            ("region_name", RecordFieldContent::REGION(_, v))
+            | ("city_name", RecordFieldContent::CITY(_, v))
+            | ("isp", RecordFieldContent::ISP(_, v))
+            | ("domain", RecordFieldContent::DOMAIN(_, v))
+            | ("proxy_type", RecordFieldContent::PROXYTYPE(_, v))
+            | ("usage_type", RecordFieldContent::USAGETYPE(_, v))
+            | ("asn", RecordFieldContent::ASN(_, v))
+            | ("as_name", RecordFieldContent::AS(_, v))
+            | ("last_seen", RecordFieldContent::LASTSEEN(_, v))
+            | ("threat", RecordFieldContent::THREAT(_, v))
+            | ("residential", RecordFieldContent::RESIDENTIAL(_, v))
+            | ("provider", RecordFieldContent::PROVIDER(_, v))
+            | ("fraud_score", RecordFieldContent::FRAUDSCORE(_, v)) => {
+                Some(v.as_deref().unwrap_or(UNKNOWN_STR).to_owned())
+            }
+            _ => None,
+        }
+    }
+}
+
+//
+#[cfg(feature = "csv")]
+pub type CsvToBinError =
+    ip2location_bin_format::csv_format::CsvToBinError<crate::record::ToRecordError>;
+
+//
+/// Exports BIN to CSV, the v4 CSV or the IPV6 CSV like the BIN.
+///
+/// csv_header is the columns of the CSV, e.g. [`CSV_HEADER_PX11`].
+/// The ranges which are not proxies (the country is unknown) are skipped, like the CSV.
+#[cfg(feature = "csv")]
+pub async fn bin_to_csv<S, W>(
+    db: &crate::bin_format::Database<S>,
+    csv_header: &[&str],
+    w: W,
+) -> Result<(), BinToCsvError>
+where
+    S: futures_util::AsyncSeek + futures_util::AsyncRead + Unpin,
+    W: std::io::Write,
+{
+    ip2location_bin_format::csv_format::bin_to_csv::<crate::record::Record, _, _>(
+        &db.inner, csv_header, w,
+    )
+    .await
+}
+
+#[cfg(feature = "csv")]
+pub use ip2location_bin_format::csv_format::BinToCsvError;
+
+#[cfg(feature = "csv")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::{
+        net::{Ipv4Addr, Ipv6Addr},
+        sync::Arc,
+    };
+
+    use futures_util::{future, io::Cursor};
+    use ip2location_bin_format::querier::Querier;
+
+    use crate::{
+        bin_format::Database, proxy_type::ProxyType, record::RecordField, usage_type::UsageType,
+    };
+
+    #[tokio::test]
+    async fn test_csv_to_bin() -> Result<(), Box<dyn std::error::Error>> {
+        // The CSV only contains the proxy ranges.
+        let csv = r#""281470698521600","281470698521855","PUB","AU","Australia","Queensland","Brisbane","Cloudflare Inc","cloudflare.com","CDN","13335","CLOUDFLARENET","30","SCANNER","-"
+"47856325177406512713633115462103465984","47856325256634675227897453055647416319","VPN","JP","Japan","Tokyo","Tokyo","Example Networks","example.com","DCH","64496","EXAMPLE","1","-","Example VPN"
+"#;
+
+        let mut bytes = vec![];
+        let header = csv_to_bin(csv.as_bytes(), CSV_HEADER_PX11, 11, (22, 11, 1), &mut bytes)?;
+        assert!(header.has_v6());
+
+        let bytes: Arc<[u8]> = bytes.into();
+        let db = Database {
+            inner: Querier::new(|| Box::pin(future::ok(Cursor::new(bytes.clone()))), 1).await?,
+        };
+
+        let record = db
+            .lookup(Ipv4Addr::new(1, 0, 4, 1).into(), None)
+            .await?
+            .unwrap();
+        assert_eq!(record.country_code.to_string(), "AU");
+        assert_eq!(record.proxy_type, Some(ProxyType::PUB));
+        assert_eq!(record.usage_type, Some(UsageType::CDN));
+        assert_eq!(record.asn, Some(13335));
+        assert_eq!(record.threat, Some("SCANNER".into()));
+        assert_eq!(record.provider, None);
+
+        let record = db
+            .lookup("2400:cb00::1".parse::<Ipv6Addr>()?.into(), None)
+            .await?
+            .unwrap();
+        assert_eq!(record.country_code.to_string(), "JP");
+        assert_eq!(record.proxy_type, Some(ProxyType::VPN));
+        assert_eq!(record.provider, Some("Example VPN".into()));
+
+        assert!(db
+            .lookup(Ipv4Addr::new(8, 8, 8, 8).into(), None)
+            .await?
+            .is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_bin_to_csv() -> Result<(), Box<dyn std::error::Error>> {
+        let csv = r#""16777216","16777471","PUB","AU","Australia","Queensland","Brisbane","Cloudflare Inc","cloudflare.com","CDN","13335","CLOUDFLARENET","30","SCANNER","-"
+"16778240","16778495","VPN","JP","Japan","Tokyo","Tokyo","Example Networks","example.com","DCH","64496","EXAMPLE","1","-","Example VPN"
+"#;
+
+        let mut bytes = vec![];
+        csv_to_bin(csv.as_bytes(), CSV_HEADER_PX11, 11, (22, 11, 1), &mut bytes)?;
+
+        let db = Database::from_shared_bytes(bytes, 1).await?;
+
+        let mut buf = vec![];
+        bin_to_csv(&db, CSV_HEADER_PX11, &mut buf).await?;
+        assert_eq!(String::from_utf8(buf)?, csv);
+
+        //
+        let csv_v6 = r#""281470698520576","281470698520831","PUB","AU","Australia","Queensland","Brisbane","Cloudflare Inc","cloudflare.com","CDN","13335","CLOUDFLARENET","30","SCANNER","-"
+"47856325177406512713633115462103465984","47856325256634675227897453055647416319","VPN","JP","Japan","Tokyo","Tokyo","Example Networks","example.com","DCH","64496","EXAMPLE","1","-","Example VPN"
+"#;
+
+        let mut bytes = vec![];
+        csv_to_bin(
+            csv_v6.as_bytes(),
+            CSV_HEADER_PX11,
+            11,
+            (22, 11, 1),
+            &mut bytes,
+        )?;
+
+        let db_v6 = Database::from_shared_bytes(bytes, 1).await?;
+
+        let mut buf = vec![];
+        bin_to_csv(&db_v6, CSV_HEADER_PX11, &mut buf).await?;
+        assert_eq!(String::from_utf8(buf)?, csv_v6);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_px12() -> Result<(), Box<dyn std::error::Error>> {
+        let csv = r#""16778240","16778495","PUB","AU","Australia","Victoria","Melbourne","Example Inc","example.com","DCH","64496","EXAMPLE","12","BOTNET","-","87"
+"16778496","16778751","VPN","AU","Australia","Victoria","Melbourne","Example Inc","example.com","DCH","64496","EXAMPLE","3","-","Example VPN","-"
+"#;
+
+        let mut bytes = vec![];
+        csv_to_bin(csv.as_bytes(), CSV_HEADER_PX12, 12, (22, 11, 1), &mut bytes)?;
+
+        let db = Database::from_bytes(bytes).await?;
+
+        let record = db
+            .lookup(Ipv4Addr::new(1, 0, 4, 1).into(), None)
+            .await?
+            .unwrap();
+        assert_eq!(record.fraud_score, Some(87));
+        assert_eq!(record.threat, Some("BOTNET".into()));
+        assert!(record.residential.is_none());
+
+        let record = db
+            .lookup_ref(
+                Ipv4Addr::new(1, 0, 5, 1).into(),
+                &[RecordField::CountryCodeAndName, RecordField::FraudScore][..],
+            )?
+            .unwrap();
+        assert_eq!(record.fraud_score, None);
+        assert!(record.provider.is_none());
+
+        let mut buf = vec![];
+        bin_to_csv(&db, CSV_HEADER_PX12, &mut buf).await?;
+        assert_eq!(String::from_utf8(buf)?, csv);
+
+        Ok(())
+    }
+}
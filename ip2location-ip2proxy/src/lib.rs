@@ -8,3 +8,6 @@ pub mod usage_type;
 //
 pub mod bin_format;
 pub mod csv_format;
+
+#[cfg(feature = "zip")]
+pub use ip2location_bin_format::zip_archive;
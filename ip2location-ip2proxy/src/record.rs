@@ -1,8 +1,9 @@
-//! [Ref](https://lite.ip2location.com/database/px11-ip-proxytype-country-region-city-isp-domain-usagetype-asn-lastseen-threat-residential-provider#database-fields)
+//! [Ref](https://lite.ip2location.com/database/px12-ip-proxytype-country-region-city-isp-domain-usagetype-asn-lastseen-threat-residential-provider-fraudscore#database-fields)
 
 use std::net::IpAddr;
 
 use country_code::CountryCode;
+use ip2location_bin_format::record_field::RecordField as BinRecordField;
 
 use crate::{proxy_type::ProxyType, usage_type::UsageType};
 
@@ -67,6 +68,12 @@ pub struct Record {
         serde(default, deserialize_with = "option_box_str_deserialize")
     )]
     pub provider: Option<Box<str>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, deserialize_with = "option_u8_deserialize")
+    )]
+    pub fraud_score: Option<u8>,
+    /// Only in some old CSVs, it is not in any BIN layout.
     #[cfg_attr(
         feature = "serde",
         serde(default, deserialize_with = "option_box_str_deserialize")
@@ -132,6 +139,25 @@ where
     }
 }
 
+#[cfg(feature = "serde")]
+fn option_u8_deserialize<'de, D>(deserializer: D) -> Result<Option<u8>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use ip2location_bin_format::content::UNKNOWN_STR;
+    use serde::Deserialize as _;
+
+    let s = Box::<str>::deserialize(deserializer)?;
+    if s == UNKNOWN_STR.into() {
+        Ok(None)
+    } else {
+        match s.parse::<u8>() {
+            Ok(v) => Ok(Some(v)),
+            Err(err) => Err(serde::de::Error::custom(err.to_string())),
+        }
+    }
+}
+
 impl Record {
     pub(crate) fn with_empty(ip_from: IpAddr, ip_to: IpAddr) -> Self {
         Self {
@@ -151,6 +177,7 @@ impl Record {
             threat: Default::default(),
             residential: Default::default(),
             provider: Default::default(),
+            fraud_score: Default::default(),
         }
     }
 }
@@ -165,7 +192,7 @@ impl
         ip2location_bin_format::record_field::RecordFieldContents,
     )> for OptionRecord
 {
-    type Error = Box<str>;
+    type Error = ToRecordError;
 
     fn try_from(
         (ip_from, ip_to, record_field_contents): (
@@ -182,9 +209,13 @@ impl
             match record_field_content {
                 RecordFieldContent::COUNTRY(_, v, v_name) => {
                     if let Some(v) = v {
-                        record.country_code = v
-                            .parse::<CountryCode>()
-                            .map_err(|err| Box::<str>::from(err.to_string()))?;
+                        record.country_code = v.parse::<CountryCode>().map_err(|err| {
+                            ToRecordError::ValueInvalid(
+                                BinRecordField::COUNTRY,
+                                v.clone(),
+                                Box::new(err),
+                            )
+                        })?;
                     } else {
                         return Ok(OptionRecord(None));
                     }
@@ -205,42 +236,91 @@ impl
                 }
                 //
                 RecordFieldContent::LATITUDE(_) => {
-                    return Err("Unknown field LATITUDE".into());
+                    return Err(ToRecordError::FieldUnsupported(BinRecordField::LATITUDE));
                 }
                 RecordFieldContent::LONGITUDE(_) => {
-                    return Err("Unknown field LONGITUDE".into());
+                    return Err(ToRecordError::FieldUnsupported(BinRecordField::LONGITUDE));
                 }
                 RecordFieldContent::ZIPCODE(_, _) => {
-                    return Err("Unknown field ZIPCODE".into());
+                    return Err(ToRecordError::FieldUnsupported(BinRecordField::ZIPCODE));
                 }
                 RecordFieldContent::TIMEZONE(_, _) => {
-                    return Err("Unknown field TIMEZONE".into());
+                    return Err(ToRecordError::FieldUnsupported(BinRecordField::TIMEZONE));
                 }
                 RecordFieldContent::NETSPEED(_, _) => {
-                    return Err("Unknown field NETSPEED".into());
+                    return Err(ToRecordError::FieldUnsupported(BinRecordField::NETSPEED));
+                }
+                RecordFieldContent::IDDCODE(_, _) => {
+                    return Err(ToRecordError::FieldUnsupported(BinRecordField::IDDCODE));
+                }
+                RecordFieldContent::AREACODE(_, _) => {
+                    return Err(ToRecordError::FieldUnsupported(BinRecordField::AREACODE));
+                }
+                RecordFieldContent::WEATHERSTATIONCODE(_, _) => {
+                    return Err(ToRecordError::FieldUnsupported(
+                        BinRecordField::WEATHERSTATIONCODE,
+                    ));
+                }
+                RecordFieldContent::WEATHERSTATIONNAME(_, _) => {
+                    return Err(ToRecordError::FieldUnsupported(
+                        BinRecordField::WEATHERSTATIONNAME,
+                    ));
+                }
+                RecordFieldContent::MCC(_, _) => {
+                    return Err(ToRecordError::FieldUnsupported(BinRecordField::MCC));
+                }
+                RecordFieldContent::MNC(_, _) => {
+                    return Err(ToRecordError::FieldUnsupported(BinRecordField::MNC));
+                }
+                RecordFieldContent::MOBILEBRAND(_, _) => {
+                    return Err(ToRecordError::FieldUnsupported(BinRecordField::MOBILEBRAND));
+                }
+                RecordFieldContent::ELEVATION(_, _) => {
+                    return Err(ToRecordError::FieldUnsupported(BinRecordField::ELEVATION));
+                }
+                RecordFieldContent::ADDRESSTYPE(_, _) => {
+                    return Err(ToRecordError::FieldUnsupported(BinRecordField::ADDRESSTYPE));
+                }
+                RecordFieldContent::CATEGORY(_, _) => {
+                    return Err(ToRecordError::FieldUnsupported(BinRecordField::CATEGORY));
+                }
+                RecordFieldContent::DISTRICT(_, _) => {
+                    return Err(ToRecordError::FieldUnsupported(BinRecordField::DISTRICT));
                 }
                 //
                 RecordFieldContent::PROXYTYPE(_, v) => {
                     if let Some(v) = v {
-                        let v = v
-                            .parse::<ProxyType>()
-                            .map_err(|err| Box::<str>::from(err.to_string()))?;
+                        let v = v.parse::<ProxyType>().map_err(|err| {
+                            ToRecordError::ValueInvalid(
+                                BinRecordField::PROXYTYPE,
+                                v.clone(),
+                                Box::new(err),
+                            )
+                        })?;
                         record.proxy_type = Some(v);
                     }
                 }
                 RecordFieldContent::USAGETYPE(_, v) => {
                     if let Some(v) = v {
-                        let v = v
-                            .parse::<UsageType>()
-                            .map_err(|err| Box::<str>::from(err.to_string()))?;
+                        let v = v.parse::<UsageType>().map_err(|err| {
+                            ToRecordError::ValueInvalid(
+                                BinRecordField::USAGETYPE,
+                                v.clone(),
+                                Box::new(err),
+                            )
+                        })?;
                         record.usage_type = Some(v);
                     }
                 }
                 RecordFieldContent::ASN(_, v) => {
                     if let Some(v) = v {
-                        let v = v
-                            .parse::<usize>()
-                            .map_err(|err| Box::<str>::from(err.to_string()))?;
+                        let v = v.parse::<usize>().map_err(|err| {
+                            ToRecordError::ValueInvalid(
+                                BinRecordField::ASN,
+                                v.clone(),
+                                Box::new(err),
+                            )
+                        })?;
                         record.asn = Some(v);
                     }
                 }
@@ -259,6 +339,18 @@ impl
                 RecordFieldContent::PROVIDER(_, v) => {
                     record.provider = v.to_owned();
                 }
+                RecordFieldContent::FRAUDSCORE(_, v) => {
+                    if let Some(v) = v {
+                        let v = v.parse::<u8>().map_err(|err| {
+                            ToRecordError::ValueInvalid(
+                                BinRecordField::FRAUDSCORE,
+                                v.clone(),
+                                Box::new(err),
+                            )
+                        })?;
+                        record.fraud_score = Some(v);
+                    }
+                }
             }
         }
 
@@ -266,6 +358,321 @@ impl
     }
 }
 
+//
+#[derive(Debug)]
+pub enum ToRecordError {
+    FieldUnsupported(BinRecordField),
+    /// The field, the value and the parse error.
+    ValueInvalid(
+        BinRecordField,
+        Box<str>,
+        Box<dyn std::error::Error + Send + Sync + 'static>,
+    ),
+}
+
+impl core::fmt::Display for ToRecordError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::FieldUnsupported(record_field) => {
+                write!(f, "field {record_field:?} is unsupported")
+            }
+            Self::ValueInvalid(record_field, value, _) => {
+                write!(f, "value {value:?} of field {record_field:?} is invalid")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ToRecordError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::FieldUnsupported(_) => None,
+            Self::ValueInvalid(_, _, err) => Some(err.as_ref()),
+        }
+    }
+}
+
+//
+/// A [`Record`] with the strs borrowed from the BIN bytes, see [`Database::lookup_ref`](crate::bin_format::Database::lookup_ref).
+#[derive(Debug, Clone)]
+pub struct RecordRef<'a> {
+    pub ip_from: IpAddr,
+    pub ip_to: IpAddr,
+    pub proxy_type: Option<ProxyType>,
+    pub country_code: CountryCode,
+    pub country_name: Option<&'a str>,
+    pub region_name: Option<&'a str>,
+    pub city_name: Option<&'a str>,
+    pub isp: Option<&'a str>,
+    pub domain: Option<&'a str>,
+    pub usage_type: Option<UsageType>,
+    pub asn: Option<usize>,
+    pub as_name: Option<&'a str>,
+    pub last_seen: Option<&'a str>,
+    pub threat: Option<&'a str>,
+    pub provider: Option<&'a str>,
+    pub fraud_score: Option<u8>,
+    pub residential: Option<&'a str>,
+}
+
+impl<'a> RecordRef<'a> {
+    pub(crate) fn with_empty(ip_from: IpAddr, ip_to: IpAddr) -> Self {
+        Self {
+            ip_from,
+            ip_to,
+            proxy_type: Default::default(),
+            country_code: Default::default(),
+            country_name: Default::default(),
+            region_name: Default::default(),
+            city_name: Default::default(),
+            isp: Default::default(),
+            domain: Default::default(),
+            usage_type: Default::default(),
+            asn: Default::default(),
+            as_name: Default::default(),
+            last_seen: Default::default(),
+            threat: Default::default(),
+            provider: Default::default(),
+            fraud_score: Default::default(),
+            residential: Default::default(),
+        }
+    }
+
+    /// Returns false if the country is unknown, the record is skipped like [`Record`].
+    pub(crate) fn fill(
+        &mut self,
+        record_field_content: ip2location_bin_format::bytes_querier::RecordFieldContentRef<'a>,
+    ) -> Result<bool, ToRecordError> {
+        use ip2location_bin_format::bytes_querier::RecordFieldContentRef;
+
+        match record_field_content {
+            RecordFieldContentRef::COUNTRY(v, v_name) => {
+                if let Some(v) = v {
+                    self.country_code = v.parse::<CountryCode>().map_err(|err| {
+                        ToRecordError::ValueInvalid(
+                            BinRecordField::COUNTRY,
+                            Box::from(v),
+                            Box::new(err),
+                        )
+                    })?;
+                } else {
+                    return Ok(false);
+                }
+
+                self.country_name = v_name;
+            }
+            RecordFieldContentRef::REGION(v) => self.region_name = v,
+            RecordFieldContentRef::CITY(v) => self.city_name = v,
+            RecordFieldContentRef::ISP(v) => self.isp = v,
+            RecordFieldContentRef::DOMAIN(v) => self.domain = v,
+            //
+            RecordFieldContentRef::PROXYTYPE(v) => {
+                if let Some(v) = v {
+                    let v = v.parse::<ProxyType>().map_err(|err| {
+                        ToRecordError::ValueInvalid(
+                            BinRecordField::PROXYTYPE,
+                            Box::from(v),
+                            Box::new(err),
+                        )
+                    })?;
+                    self.proxy_type = Some(v);
+                }
+            }
+            RecordFieldContentRef::USAGETYPE(v) => {
+                if let Some(v) = v {
+                    let v = v.parse::<UsageType>().map_err(|err| {
+                        ToRecordError::ValueInvalid(
+                            BinRecordField::USAGETYPE,
+                            Box::from(v),
+                            Box::new(err),
+                        )
+                    })?;
+                    self.usage_type = Some(v);
+                }
+            }
+            RecordFieldContentRef::ASN(v) => {
+                if let Some(v) = v {
+                    let v = v.parse::<usize>().map_err(|err| {
+                        ToRecordError::ValueInvalid(
+                            BinRecordField::ASN,
+                            Box::from(v),
+                            Box::new(err),
+                        )
+                    })?;
+                    self.asn = Some(v);
+                }
+            }
+            RecordFieldContentRef::AS(v) => self.as_name = v,
+            RecordFieldContentRef::LASTSEEN(v) => self.last_seen = v,
+            RecordFieldContentRef::THREAT(v) => self.threat = v,
+            RecordFieldContentRef::RESIDENTIAL(v) => self.residential = v,
+            RecordFieldContentRef::PROVIDER(v) => self.provider = v,
+            RecordFieldContentRef::FRAUDSCORE(v) => {
+                if let Some(v) = v {
+                    let v = v.parse::<u8>().map_err(|err| {
+                        ToRecordError::ValueInvalid(
+                            BinRecordField::FRAUDSCORE,
+                            Box::from(v),
+                            Box::new(err),
+                        )
+                    })?;
+                    self.fraud_score = Some(v);
+                }
+            }
+            //
+            x => {
+                return Err(ToRecordError::FieldUnsupported(x.record_field()));
+            }
+        }
+
+        Ok(true)
+    }
+
+    pub fn to_owned(&self) -> Record {
+        Record {
+            ip_from: self.ip_from,
+            ip_to: self.ip_to,
+            proxy_type: self.proxy_type.to_owned(),
+            country_code: self.country_code.to_owned(),
+            country_name: self.country_name.map(Into::into),
+            region_name: self.region_name.map(Into::into),
+            city_name: self.city_name.map(Into::into),
+            isp: self.isp.map(Into::into),
+            domain: self.domain.map(Into::into),
+            usage_type: self.usage_type.to_owned(),
+            asn: self.asn,
+            as_name: self.as_name.map(Into::into),
+            last_seen: self.last_seen.map(Into::into),
+            threat: self.threat.map(Into::into),
+            provider: self.provider.map(Into::into),
+            fraud_score: self.fraud_score,
+            residential: self.residential.map(Into::into),
+        }
+    }
+}
+
+impl From<RecordRef<'_>> for Record {
+    fn from(x: RecordRef<'_>) -> Self {
+        x.to_owned()
+    }
+}
+
+#[cfg(feature = "csv")]
+impl Record {
+    pub(crate) fn to_record_field_contents(
+        &self,
+        record_fields: &ip2location_bin_format::record_field::RecordFields,
+    ) -> Result<ip2location_bin_format::record_field::RecordFieldContents, ToRecordError> {
+        use ip2location_bin_format::record_field::RecordFieldContent;
+
+        let mut record_field_contents = record_fields.to_contents();
+
+        for record_field_content in record_field_contents.iter_mut() {
+            match record_field_content {
+                RecordFieldContent::COUNTRY(_, v, v_name) => {
+                    *v = Some(self.country_code.to_string().into());
+                    *v_name = self.country_name.to_owned();
+                }
+                RecordFieldContent::REGION(_, v) => {
+                    *v = self.region_name.to_owned();
+                }
+                RecordFieldContent::CITY(_, v) => {
+                    *v = self.city_name.to_owned();
+                }
+                RecordFieldContent::ISP(_, v) => {
+                    *v = self.isp.to_owned();
+                }
+                RecordFieldContent::DOMAIN(_, v) => {
+                    *v = self.domain.to_owned();
+                }
+                //
+                RecordFieldContent::LATITUDE(_) => {
+                    return Err(ToRecordError::FieldUnsupported(BinRecordField::LATITUDE));
+                }
+                RecordFieldContent::LONGITUDE(_) => {
+                    return Err(ToRecordError::FieldUnsupported(BinRecordField::LONGITUDE));
+                }
+                RecordFieldContent::ZIPCODE(_, _) => {
+                    return Err(ToRecordError::FieldUnsupported(BinRecordField::ZIPCODE));
+                }
+                RecordFieldContent::TIMEZONE(_, _) => {
+                    return Err(ToRecordError::FieldUnsupported(BinRecordField::TIMEZONE));
+                }
+                RecordFieldContent::NETSPEED(_, _) => {
+                    return Err(ToRecordError::FieldUnsupported(BinRecordField::NETSPEED));
+                }
+                RecordFieldContent::IDDCODE(_, _) => {
+                    return Err(ToRecordError::FieldUnsupported(BinRecordField::IDDCODE));
+                }
+                RecordFieldContent::AREACODE(_, _) => {
+                    return Err(ToRecordError::FieldUnsupported(BinRecordField::AREACODE));
+                }
+                RecordFieldContent::WEATHERSTATIONCODE(_, _) => {
+                    return Err(ToRecordError::FieldUnsupported(
+                        BinRecordField::WEATHERSTATIONCODE,
+                    ));
+                }
+                RecordFieldContent::WEATHERSTATIONNAME(_, _) => {
+                    return Err(ToRecordError::FieldUnsupported(
+                        BinRecordField::WEATHERSTATIONNAME,
+                    ));
+                }
+                RecordFieldContent::MCC(_, _) => {
+                    return Err(ToRecordError::FieldUnsupported(BinRecordField::MCC));
+                }
+                RecordFieldContent::MNC(_, _) => {
+                    return Err(ToRecordError::FieldUnsupported(BinRecordField::MNC));
+                }
+                RecordFieldContent::MOBILEBRAND(_, _) => {
+                    return Err(ToRecordError::FieldUnsupported(BinRecordField::MOBILEBRAND));
+                }
+                RecordFieldContent::ELEVATION(_, _) => {
+                    return Err(ToRecordError::FieldUnsupported(BinRecordField::ELEVATION));
+                }
+                RecordFieldContent::ADDRESSTYPE(_, _) => {
+                    return Err(ToRecordError::FieldUnsupported(BinRecordField::ADDRESSTYPE));
+                }
+                RecordFieldContent::CATEGORY(_, _) => {
+                    return Err(ToRecordError::FieldUnsupported(BinRecordField::CATEGORY));
+                }
+                RecordFieldContent::DISTRICT(_, _) => {
+                    return Err(ToRecordError::FieldUnsupported(BinRecordField::DISTRICT));
+                }
+                //
+                RecordFieldContent::PROXYTYPE(_, v) => {
+                    *v = self.proxy_type.as_ref().map(|x| x.to_string().into());
+                }
+                RecordFieldContent::USAGETYPE(_, v) => {
+                    *v = self.usage_type.as_ref().map(|x| x.to_string().into());
+                }
+                RecordFieldContent::ASN(_, v) => {
+                    *v = self.asn.as_ref().map(|x| x.to_string().into());
+                }
+                RecordFieldContent::AS(_, v) => {
+                    *v = self.as_name.to_owned();
+                }
+                RecordFieldContent::LASTSEEN(_, v) => {
+                    *v = self.last_seen.to_owned();
+                }
+                RecordFieldContent::THREAT(_, v) => {
+                    *v = self.threat.to_owned();
+                }
+                RecordFieldContent::RESIDENTIAL(_, v) => {
+                    *v = self.residential.to_owned();
+                }
+                RecordFieldContent::PROVIDER(_, v) => {
+                    *v = self.provider.to_owned();
+                }
+                RecordFieldContent::FRAUDSCORE(_, v) => {
+                    *v = self.fraud_score.map(|x| x.to_string().into());
+                }
+            }
+        }
+
+        Ok(record_field_contents)
+    }
+}
+
 //
 //
 //
@@ -284,6 +691,7 @@ pub enum RecordField {
     LastSeen,
     Threat,
     Provider,
+    FraudScore,
     Residential,
 }
 
@@ -303,6 +711,7 @@ impl From<&RecordField> for ip2location_bin_format::record_field::RecordField {
             RecordField::LastSeen => Self::LASTSEEN,
             RecordField::Threat => Self::THREAT,
             RecordField::Provider => Self::PROVIDER,
+            RecordField::FraudScore => Self::FRAUDSCORE,
             RecordField::Residential => Self::RESIDENTIAL,
         }
     }
@@ -1,8 +1,12 @@
 //
 pub mod csv_format;
 pub mod record;
+pub mod resolver;
 
 pub use csv_format::Records;
 #[cfg(feature = "once_cell")]
 pub use csv_format::{RECORDS, RECORDS_CODE_MAP};
 pub use record::Record;
+pub use resolver::Resolver;
+#[cfg(feature = "once_cell")]
+pub use resolver::RESOLVER;
@@ -0,0 +1,212 @@
+//! Resolves the `region_name` of the IP2Location™ DB3+ to the ISO 3166-2 subdivision code.
+
+use std::collections::HashMap;
+
+use country_code::{iso3166_2::SubdivisionCode, CountryCode};
+use unicode_normalization::{char::is_combining_mark, UnicodeNormalization as _};
+
+use crate::csv_format::Records;
+
+//
+#[cfg(feature = "once_cell")]
+pub static RESOLVER: once_cell::sync::Lazy<Resolver> =
+    once_cell::sync::Lazy::new(|| Resolver::new(&crate::csv_format::RECORDS));
+
+// Common names which differ from the subdivision_name.
+const ALIASES: &[(CountryCode, &str, &str)] = &[
+    (CountryCode::CN, "CN-GX", "Guangxi"),
+    (CountryCode::CN, "CN-GX", "Guangxi Zhuangzu Zizhiqu"),
+    (CountryCode::CN, "CN-NM", "Inner Mongolia"),
+    (CountryCode::CN, "CN-NM", "Nei Mongol Zizhiqu"),
+    (CountryCode::CN, "CN-NX", "Ningxia"),
+    (CountryCode::CN, "CN-NX", "Ningxia Huizu Zizhiqu"),
+    (CountryCode::CN, "CN-XJ", "Xinjiang"),
+    (CountryCode::CN, "CN-XJ", "Xinjiang Uygur Zizhiqu"),
+    (CountryCode::CN, "CN-XZ", "Tibet"),
+    (CountryCode::CN, "CN-XZ", "Xizang Zizhiqu"),
+    (CountryCode::DE, "DE-BY", "Bavaria"),
+    (
+        CountryCode::IN,
+        "IN-DL",
+        "National Capital Territory of Delhi",
+    ),
+    (CountryCode::MX, "MX-CMX", "Mexico City"),
+    (CountryCode::RU, "RU-MOS", "Moscow Oblast"),
+    (CountryCode::RU, "RU-MOW", "Moscow"),
+    (CountryCode::US, "US-DC", "Washington, D.C."),
+];
+
+//
+#[derive(Debug, Clone, Default)]
+pub struct Resolver {
+    map: HashMap<(CountryCode, Box<str>), SubdivisionCode>,
+}
+
+impl Resolver {
+    /// Indexes the subdivision names and the built-in aliases,
+    /// the first record wins if a name is shared by several subdivisions of a country.
+    pub fn new(records: &Records) -> Self {
+        let mut this = Self::default();
+
+        for record in records.iter() {
+            this.map
+                .entry((
+                    record.country_code.to_owned(),
+                    normalize(&record.subdivision_name),
+                ))
+                .or_insert_with(|| record.code.to_owned());
+        }
+
+        for (country_code, code, alias) in ALIASES {
+            let code = code
+                .parse::<SubdivisionCode>()
+                .expect("the code of ALIASES is invalid");
+            this.add_alias(country_code.to_owned(), alias, code);
+        }
+
+        this
+    }
+
+    /// Overrides the indexed name if it is the same after normalization.
+    pub fn add_alias(&mut self, country_code: CountryCode, alias: &str, code: SubdivisionCode) {
+        self.map.insert((country_code, normalize(alias)), code);
+    }
+
+    /// Case, diacritic and punctuation insensitive, e.g. `Île-de-France` matches `Ile-de-France`
+    /// and `Washington, D.C.` matches `Washington DC`.
+    pub fn resolve(
+        &self,
+        country_code: &CountryCode,
+        subdivision_name: &str,
+    ) -> Option<&SubdivisionCode> {
+        self.map
+            .get(&(country_code.to_owned(), normalize(subdivision_name)))
+    }
+}
+
+//
+/// Lowercase, without diacritics and apostrophes, other punctuation to a single space.
+pub fn normalize(name: &str) -> Box<str> {
+    let mut s = String::with_capacity(name.len());
+    let mut pending_space = false;
+
+    for c in name.nfd().filter(|c| !is_combining_mark(*c)) {
+        let folded = match c {
+            'ß' => "ss",
+            'æ' | 'Æ' => "ae",
+            'œ' | 'Œ' => "oe",
+            'ø' | 'Ø' => "o",
+            'đ' | 'Đ' => "d",
+            'ł' | 'Ł' => "l",
+            'ı' => "i",
+            '\'' | '’' | '‘' | '`' => continue,
+            c if c.is_alphanumeric() => {
+                if pending_space && !s.is_empty() {
+                    s.push(' ');
+                }
+                pending_space = false;
+                s.extend(c.to_lowercase());
+                continue;
+            }
+            _ => {
+                pending_space = true;
+                continue;
+            }
+        };
+        if pending_space && !s.is_empty() {
+            s.push(' ');
+        }
+        pending_space = false;
+        s.push_str(folded);
+    }
+
+    // The initials are one token, e.g. `D.C.` is `dc` like `DC`.
+    let mut normalized = String::with_capacity(s.len());
+    let mut is_initial_previous = false;
+    for token in s.split(' ') {
+        let is_initial = token.chars().count() == 1;
+        if !(normalized.is_empty() || is_initial && is_initial_previous) {
+            normalized.push(' ');
+        }
+        normalized.push_str(token);
+        is_initial_previous = is_initial;
+    }
+
+    normalized.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use country_code::iso3166_2::{CNSubdivisionCode, USSubdivisionCode};
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize("Île-de-France").as_ref(), "ile de france");
+        assert_eq!(
+            normalize("  Moskovskaya  oblast' ").as_ref(),
+            "moskovskaya oblast"
+        );
+        assert_eq!(normalize("Washington, D.C.").as_ref(), "washington dc");
+        assert_eq!(normalize("A Coruña").as_ref(), "a coruna");
+        assert_eq!(
+            normalize("U.S. Virgin Islands").as_ref(),
+            "us virgin islands"
+        );
+        assert_eq!(
+            normalize("Schleswig-Holstein").as_ref(),
+            "schleswig holstein"
+        );
+        assert_eq!(normalize("Thüringen").as_ref(), "thuringen");
+        assert_eq!(normalize("Groß").as_ref(), "gross");
+    }
+
+    #[test]
+    fn test_resolve() -> Result<(), Box<dyn std::error::Error>> {
+        let csv = r#""country_code","subdivision_name","code"
+"US","California","US-CA"
+"US","District of Columbia","US-DC"
+"CN","Xinjiang Uygur","CN-XJ"
+"#;
+        let records = Records::from_csv(csv.as_bytes())?;
+        let mut resolver = Resolver::new(&records);
+
+        let us_ca = SubdivisionCode::US(USSubdivisionCode::CA);
+        assert_eq!(
+            resolver.resolve(&CountryCode::US, "CALIFORNIA"),
+            Some(&us_ca)
+        );
+        assert_eq!(resolver.resolve(&CountryCode::MX, "California"), None);
+        for name in ["Washington, D.C.", "Washington DC", "washington d.c"] {
+            assert_eq!(
+                resolver.resolve(&CountryCode::US, name),
+                Some(&SubdivisionCode::US(USSubdivisionCode::DC))
+            );
+        }
+        assert_eq!(
+            resolver.resolve(&CountryCode::CN, "Xinjiang"),
+            Some(&SubdivisionCode::CN(CNSubdivisionCode::XJ))
+        );
+
+        resolver.add_alias(CountryCode::US, "Calif.", us_ca.clone());
+        assert_eq!(resolver.resolve(&CountryCode::US, "calif"), Some(&us_ca));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_aliases() -> Result<(), Box<dyn std::error::Error>> {
+        let resolver = Resolver::new(&Records(vec![]));
+
+        for (country_code, code, alias) in ALIASES {
+            assert_eq!(
+                resolver.resolve(country_code, alias),
+                Some(&code.parse::<SubdivisionCode>()?),
+                "{alias}"
+            );
+        }
+
+        Ok(())
+    }
+}
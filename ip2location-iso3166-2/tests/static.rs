@@ -29,3 +29,22 @@ fn test_static() {
     println!("{record:?}");
     assert_eq!(record.subdivision_name, "Anguilla".into());
 }
+
+#[test]
+fn test_resolver() {
+    use ip2location_iso3166_2::RESOLVER;
+
+    assert_eq!(
+        RESOLVER.resolve(&CountryCode::US, "New York"),
+        Some(&SubdivisionCode::US(USSubdivisionCode::NY))
+    );
+    assert_eq!(
+        RESOLVER.resolve(&CountryCode::FR, "Île-de-France"),
+        Some(&"FR-IDF".parse::<SubdivisionCode>().unwrap())
+    );
+    assert_eq!(
+        RESOLVER.resolve(&CountryCode::CN, "Inner Mongolia"),
+        Some(&SubdivisionCode::CN(CNSubdivisionCode::NM))
+    );
+    assert_eq!(RESOLVER.resolve(&CountryCode::US, "Atlantis"), None);
+}
@@ -0,0 +1,146 @@
+use core::ops::Deref;
+use std::io::Read;
+
+use country_code::CountryCode;
+use csv::{Error as CsvError, Reader};
+
+use crate::record::Record;
+
+//
+#[cfg(feature = "once_cell")]
+pub static RECORDS: once_cell::sync::Lazy<Records> = once_cell::sync::Lazy::new(|| {
+    let csv = include_str!("../data/IP2LOCATION-OLSON-TIMEZONE.CSV");
+    Records::from_csv(csv.as_bytes()).unwrap()
+});
+
+//
+#[derive(Debug, Clone)]
+pub struct Records(pub Vec<Record>);
+
+impl Deref for Records {
+    type Target = Vec<Record>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+//
+impl Records {
+    pub fn from_csv<R: Read>(rdr: R) -> Result<Self, RecordsFromCsvError> {
+        let mut rdr = Reader::from_reader(rdr);
+
+        let mut inner = vec![];
+
+        for record in rdr.records() {
+            let record = record.map_err(RecordsFromCsvError::CsvParseFailed)?;
+
+            let row: Record = record
+                .deserialize(None)
+                .map_err(RecordsFromCsvError::RecordDeFailed)?;
+            inner.push(row);
+        }
+
+        Ok(Self(inner))
+    }
+
+    /// The first time zone of the region, the region is case insensitive.
+    pub fn find(&self, country_code: &CountryCode, region: &str) -> Option<&Record> {
+        self.iter()
+            .find(|x| &x.country_code == country_code && x.region.eq_ignore_ascii_case(region))
+    }
+
+    /// `time_zone` is the TIMEZONE of DB11, e.g. `-05:00` or `UTC-05:00`.
+    ///
+    /// Tries the region with the UTC offset, then any region of the country with the UTC offset,
+    /// `None` if no record of the country has the UTC offset, see [`find`](Self::find) for the region only.
+    pub fn find_by_time_zone(
+        &self,
+        country_code: &CountryCode,
+        region: Option<&str>,
+        time_zone: &str,
+    ) -> Option<&Record> {
+        let time_zone = time_zone.trim();
+        let time_zone = time_zone
+            .strip_prefix("UTC")
+            .or_else(|| time_zone.strip_prefix("GMT"))
+            .unwrap_or(time_zone);
+
+        let mut country_records = self
+            .iter()
+            .filter(|x| &x.country_code == country_code && x.time_zone.as_ref() == time_zone);
+
+        if let Some(region) = region {
+            if let Some(record) = country_records
+                .clone()
+                .find(|x| x.region.eq_ignore_ascii_case(region))
+            {
+                return Some(record);
+            }
+        }
+
+        country_records.next()
+    }
+}
+
+//
+#[derive(Debug)]
+pub enum RecordsFromCsvError {
+    CsvParseFailed(CsvError),
+    RecordDeFailed(CsvError),
+}
+
+impl core::fmt::Display for RecordsFromCsvError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::CsvParseFailed(_) => write!(f, "parse CSV failed"),
+            Self::RecordDeFailed(_) => write!(f, "deserialize record failed"),
+        }
+    }
+}
+
+impl std::error::Error for RecordsFromCsvError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::CsvParseFailed(err) | Self::RecordDeFailed(err) => Some(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_by_time_zone() -> Result<(), Box<dyn std::error::Error>> {
+        let csv = r#""country_code","region","time_zone","olson_time_zone"
+"US","Florida","-05:00","America/New_York"
+"US","Florida","-06:00","America/Chicago"
+"US","Texas","-06:00","America/Chicago"
+"US","Texas","-07:00","America/Denver"
+"#;
+        let records = Records::from_csv(csv.as_bytes())?;
+
+        let find = |region, time_zone| {
+            records
+                .find_by_time_zone(&CountryCode::US, region, time_zone)
+                .map(|x| x.olson_time_zone.as_ref())
+        };
+        assert_eq!(find(Some("florida"), "-06:00"), Some("America/Chicago"));
+        assert_eq!(find(Some("Florida"), "UTC-05:00"), Some("America/New_York"));
+        assert_eq!(find(Some("Ohio"), "-07:00"), Some("America/Denver"));
+        assert_eq!(find(None, "-06:00"), Some("America/Chicago"));
+        assert_eq!(find(Some("Texas"), "+08:00"), None);
+        assert_eq!(find(None, "+08:00"), None);
+
+        assert_eq!(
+            records
+                .find(&CountryCode::US, "TEXAS")
+                .map(|x| x.olson_time_zone.as_ref()),
+            Some("America/Chicago")
+        );
+        assert!(records.find(&CountryCode::CA, "Texas").is_none());
+
+        Ok(())
+    }
+}
@@ -1 +1,8 @@
+//
+pub mod csv_format;
+pub mod record;
 
+pub use csv_format::Records;
+#[cfg(feature = "once_cell")]
+pub use csv_format::RECORDS;
+pub use record::Record;
@@ -0,0 +1,13 @@
+use country_code::CountryCode;
+use serde::Deserialize;
+
+//
+#[derive(Deserialize, Debug, Clone)]
+pub struct Record {
+    pub country_code: CountryCode,
+    pub region: Box<str>,
+    /// The UTC offset, as the TIMEZONE of DB11, e.g. `-05:00`.
+    pub time_zone: Box<str>,
+    /// The IANA time zone name, e.g. `America/New_York`.
+    pub olson_time_zone: Box<str>,
+}
@@ -0,0 +1,19 @@
+#![cfg(feature = "once_cell")]
+
+use country_code::CountryCode;
+use ip2location_olson_timezone::RECORDS;
+
+#[test]
+fn test_static() {
+    //
+    let record = RECORDS
+        .find_by_time_zone(&CountryCode::US, Some("California"), "-07:00")
+        .unwrap();
+    println!("{record:?}");
+    assert_eq!(record.olson_time_zone, "America/Los_Angeles".into());
+
+    //
+    let record = RECORDS.find(&CountryCode::JP, "Tokyo").unwrap();
+    println!("{record:?}");
+    assert_eq!(record.olson_time_zone, "Asia/Tokyo".into());
+}